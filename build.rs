@@ -0,0 +1,322 @@
+//! 根据 programs/*.json 中的 IDL 生成各协议的 discriminator、指令账户下标与 borsh 结构体，
+//! 生成结果写入 $OUT_DIR/idl_<protocol>.rs，由 src/streaming/events/protocols/idl.rs 引入
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::{env, fs};
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+/// emit_cpi 事件指令的固定前缀（sha256("anchor:event")[..8] 的小端序）
+const EVENT_IX_TAG: &str = "e445a52e51cb9a1d";
+
+/// IDL 对应的程序类型
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Spec {
+    /// Anchor 程序：8 字节 discriminator（缺省时按 Anchor 规则计算）
+    Anchor,
+    /// 原生程序：1 字节指令标签，取值为指令在 IDL 中的序号
+    Native,
+}
+
+const PROGRAMS: &[(&str, Spec)] = &[
+    ("bonk", Spec::Anchor),
+    ("pumpfun", Spec::Anchor),
+    ("pumpswap", Spec::Anchor),
+    ("raydiumamm", Spec::Native),
+    ("raydiumclmm", Spec::Anchor),
+    ("raydiumcpmm", Spec::Anchor),
+];
+
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
+    for (name, spec) in PROGRAMS {
+        let path = format!("programs/{}.json", name);
+        println!("cargo:rerun-if-changed={}", path);
+
+        let raw = fs::read_to_string(&path).unwrap_or_else(|e| panic!("read {}: {}", path, e));
+        let idl: Value =
+            serde_json::from_str(&raw).unwrap_or_else(|e| panic!("parse {}: {}", path, e));
+        let code = generate(name, *spec, &idl);
+        fs::write(Path::new(&out_dir).join(format!("idl_{}.rs", name)), code)
+            .unwrap_or_else(|e| panic!("write idl_{}.rs: {}", name, e));
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+}
+
+/// 生成单个协议的模块内容
+fn generate(program: &str, spec: Spec, idl: &Value) -> String {
+    let mut out = String::new();
+    writeln!(out, "// 由 build.rs 根据 programs/{}.json 生成，请勿手动修改", program).unwrap();
+
+    let address = idl["address"].as_str().or_else(|| idl["metadata"]["address"].as_str());
+    if let Some(address) = address {
+        writeln!(out, "\n/// 程序地址\npub const PROGRAM_ID: &str = \"{}\";", address).unwrap();
+    }
+
+    let instructions = array(idl, "instructions");
+    let accounts = array(idl, "accounts");
+    let events = array(idl, "events");
+
+    // 指令 discriminator
+    out.push_str("\n/// 指令 discriminator\npub mod instructions {\n");
+    for (index, ix) in instructions.iter().enumerate() {
+        let name = str_field(ix, "name");
+        let bytes = match spec {
+            Spec::Anchor => discriminator(ix, "global", &to_snake(name)),
+            Spec::Native => vec![index as u8],
+        };
+        writeln!(out, "    pub const {}: &[u8] = &{:?};", to_screaming(name), bytes).unwrap();
+    }
+    out.push_str("}\n");
+
+    if spec == Spec::Anchor {
+        // 账户 discriminator
+        out.push_str("\n/// 账户 discriminator\npub mod accounts {\n");
+        for account in accounts {
+            let name = str_field(account, "name");
+            let bytes = discriminator(account, "account", name);
+            writeln!(out, "    pub const {}: &[u8] = &{:?};", to_screaming(name), bytes).unwrap();
+        }
+        out.push_str("}\n");
+
+        // 事件 discriminator 与 emit_cpi 内部指令数据前缀
+        out.push_str("\n/// 事件 discriminator\npub mod events {\n");
+        for event in events {
+            let name = str_field(event, "name");
+            let bytes = discriminator(event, "event", name);
+            writeln!(out, "    pub const {}: &[u8] = &{:?};", to_screaming(name), bytes).unwrap();
+        }
+        out.push_str("}\n");
+
+        out.push_str(
+            "\n/// emit_cpi 事件的内部指令数据前缀（0x + 事件指令标签 + 事件 discriminator）\npub mod cpi_events {\n",
+        );
+        for event in events {
+            let name = str_field(event, "name");
+            let hex: String = discriminator(event, "event", name)
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect();
+            writeln!(out, "    pub const {}: &str = \"0x{}{}\";", to_screaming(name), EVENT_IX_TAG, hex)
+                .unwrap();
+        }
+        out.push_str("}\n");
+    }
+
+    // 指令账户下标
+    out.push_str("\n/// 指令账户在 instruction.accounts 中的下标，LEN 为 IDL 声明的账户总数\npub mod ix_accounts {\n");
+    for ix in instructions {
+        let mut names = Vec::new();
+        flatten_accounts(&ix["accounts"], &mut names);
+        writeln!(out, "    pub mod {} {{", to_snake(str_field(ix, "name"))).unwrap();
+        let mut seen: BTreeMap<String, usize> = BTreeMap::new();
+        for (index, name) in names.iter().enumerate() {
+            let mut konst = to_screaming(name);
+            let count = seen.entry(konst.clone()).or_insert(0);
+            *count += 1;
+            if *count > 1 {
+                konst = format!("{}_{}", konst, count);
+            }
+            writeln!(out, "        pub const {}: usize = {};", konst, index).unwrap();
+        }
+        writeln!(out, "        pub const LEN: usize = {};\n    }}", names.len()).unwrap();
+    }
+    out.push_str("}\n");
+
+    // 指令参数
+    out.push_str("\n/// 指令参数（不含 discriminator）\npub mod args {\n");
+    for ix in instructions {
+        let name = to_pascal(str_field(ix, "name"));
+        let fields = struct_fields(array(ix, "args"), "super::types::");
+        write_struct(&mut out, &name, &fields);
+        writeln!(
+            out,
+            "    impl {} {{\n        /// 从指令数据（已去除 discriminator）按前缀解码，忽略尾部新增的参数\n        pub fn decode(data: &[u8]) -> Option<Self> {{\n            borsh::BorshDeserialize::deserialize(&mut &data[..]).ok()\n        }}\n    }}\n",
+            name
+        )
+        .unwrap();
+    }
+    out.push_str("}\n");
+
+    // 自定义类型：types，以及旧版 IDL 中内联定义在 accounts / events 里的结构
+    let mut types: BTreeMap<String, &Value> = BTreeMap::new();
+    for ty in array(idl, "types") {
+        types.insert(str_field(ty, "name").to_string(), &ty["type"]);
+    }
+    for account in accounts.iter().filter(|a| a.get("type").is_some()) {
+        types.entry(str_field(account, "name").to_string()).or_insert(&account["type"]);
+    }
+    let mut inline_events = Vec::new();
+    for event in events.iter().filter(|e| e.get("fields").is_some()) {
+        inline_events.push((str_field(event, "name").to_string(), event));
+    }
+
+    out.push_str("\n/// IDL 自定义类型、账户与事件结构\npub mod types {\n");
+    for (name, ty) in &types {
+        match str_field(ty, "kind") {
+            "struct" => write_struct(&mut out, name, &struct_fields(array(ty, "fields"), "")),
+            "enum" => write_enum(&mut out, name, array(ty, "variants")),
+            kind => panic!("{}: unsupported type kind {} for {}", program, kind, name),
+        }
+    }
+    for (name, event) in inline_events {
+        if !types.contains_key(&name) {
+            write_struct(&mut out, &name, &struct_fields(array(event, "fields"), ""));
+        }
+    }
+    out.push_str("}\n");
+
+    out
+}
+
+/// 读取 discriminator 字段，缺省时按 Anchor 规则计算 sha256("<namespace>:<name>")[..8]
+fn discriminator(item: &Value, namespace: &str, name: &str) -> Vec<u8> {
+    if let Some(bytes) = item["discriminator"].as_array() {
+        return bytes.iter().map(|b| b.as_u64().expect("discriminator byte") as u8).collect();
+    }
+    let hash = Sha256::digest(format!("{}:{}", namespace, name).as_bytes());
+    hash[..8].to_vec()
+}
+
+/// 展开指令账户（旧版 IDL 允许嵌套账户组）
+fn flatten_accounts(accounts: &Value, names: &mut Vec<String>) {
+    for account in accounts.as_array().into_iter().flatten() {
+        if account.get("accounts").is_some() {
+            flatten_accounts(&account["accounts"], names);
+        } else {
+            names.push(str_field(account, "name").to_string());
+        }
+    }
+}
+
+fn struct_fields(fields: &[Value], prefix: &str) -> Vec<(String, String)> {
+    fields
+        .iter()
+        .map(|f| (field_ident(str_field(f, "name")), rust_type(&f["type"], prefix)))
+        .collect()
+}
+
+fn write_struct(out: &mut String, name: &str, fields: &[(String, String)]) {
+    out.push_str("    #[derive(Clone, Debug, PartialEq, borsh::BorshDeserialize, borsh::BorshSerialize)]\n");
+    if fields.is_empty() {
+        writeln!(out, "    pub struct {};\n", name).unwrap();
+        return;
+    }
+    writeln!(out, "    pub struct {} {{", name).unwrap();
+    for (field, ty) in fields {
+        writeln!(out, "        pub {}: {},", field, ty).unwrap();
+    }
+    out.push_str("    }\n\n");
+}
+
+fn write_enum(out: &mut String, name: &str, variants: &[Value]) {
+    out.push_str("    #[derive(Clone, Debug, PartialEq, borsh::BorshDeserialize, borsh::BorshSerialize)]\n");
+    writeln!(out, "    pub enum {} {{", name).unwrap();
+    for variant in variants {
+        let variant_name = str_field(variant, "name");
+        let fields = array(variant, "fields");
+        if fields.is_empty() {
+            writeln!(out, "        {},", variant_name).unwrap();
+        } else if fields[0].get("name").is_some() {
+            let named: Vec<String> = struct_fields(fields, "")
+                .into_iter()
+                .map(|(field, ty)| format!("{}: {}", field, ty))
+                .collect();
+            writeln!(out, "        {} {{ {} }},", variant_name, named.join(", ")).unwrap();
+        } else {
+            let tuple: Vec<String> = fields.iter().map(|t| rust_type(t, "")).collect();
+            writeln!(out, "        {}({}),", variant_name, tuple.join(", ")).unwrap();
+        }
+    }
+    out.push_str("    }\n\n");
+}
+
+/// IDL 类型到 Rust 类型的映射，prefix 为自定义类型所在模块的路径前缀
+fn rust_type(ty: &Value, prefix: &str) -> String {
+    if let Some(primitive) = ty.as_str() {
+        return match primitive {
+            "bool" | "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "u128"
+            | "i128" | "f32" | "f64" => primitive.to_string(),
+            "string" => "String".to_string(),
+            "bytes" => "Vec<u8>".to_string(),
+            "pubkey" | "publicKey" => "solana_sdk::pubkey::Pubkey".to_string(),
+            other => panic!("unsupported IDL type {}", other),
+        };
+    }
+    if let Some(inner) = ty.get("vec") {
+        return format!("Vec<{}>", rust_type(inner, prefix));
+    }
+    if let Some(inner) = ty.get("option") {
+        return format!("Option<{}>", rust_type(inner, prefix));
+    }
+    if let Some(array) = ty.get("array").and_then(Value::as_array) {
+        let len = array[1].as_u64().expect("array length");
+        return format!("[{}; {}]", rust_type(&array[0], prefix), len);
+    }
+    if let Some(defined) = ty.get("defined") {
+        let name = defined.as_str().or_else(|| defined["name"].as_str()).expect("defined name");
+        return format!("{}{}", prefix, name);
+    }
+    panic!("unsupported IDL type {}", ty);
+}
+
+fn array<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value[key].as_array().map(Vec::as_slice).unwrap_or(&[])
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> &'a str {
+    value[key].as_str().unwrap_or_else(|| panic!("missing {} in {}", key, value))
+}
+
+/// camelCase / PascalCase / snake_case 转 snake_case
+fn to_snake(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next_lower)
+            {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+    out
+}
+
+fn to_screaming(name: &str) -> String {
+    to_snake(name).to_ascii_uppercase()
+}
+
+fn to_pascal(name: &str) -> String {
+    to_snake(name)
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn field_ident(name: &str) -> String {
+    let snake = to_snake(name);
+    if RUST_KEYWORDS.contains(&snake.as_str()) { format!("r#{}", snake) } else { snake }
+}
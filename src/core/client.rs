@@ -5,8 +5,12 @@ use log::{debug, error, info};
 use reqwest::Client as AsyncClient;
 use serde_json::{json, Value};
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature;
 use solana_commitment_config::CommitmentConfig;
-use solana_sdk::{pubkey::Pubkey};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, UiTransactionEncoding};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Semaphore;
@@ -317,4 +321,56 @@ impl RPCClient {
         // ─── return 'Result' ───
         Ok(balance)
     }
-}
\ No newline at end of file
+
+    // ─── fn 'getsignaturesforaddress' ───
+    /// fn description
+    pub async fn getsignaturesforaddress(&self, address: &Pubkey, before: Option<Signature>, until: Option<Signature>,
+        limit: usize) -> Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+
+        // ─── define '_permit' ───
+        let _permit = self.limit.acquire().await?;
+
+        // ─── define 'config' ───
+        let config = GetConfirmedSignaturesForAddress2Config {
+            before,
+            until,
+            limit: Some(limit),
+            commitment: Some(CommitmentConfig::confirmed()),
+        };
+
+        // ─── define 'signatures' ───
+        let signatures = self
+            .callrpc()
+            .get_signatures_for_address_with_config(address, config)
+            .await
+            .with_context(|| format!("Failed to get signatures for address {}", address))?;
+
+        // ─── return 'Result' ───
+        Ok(signatures)
+    }
+
+    // ─── fn 'gettransaction' ───
+    /// fn description
+    pub async fn gettransaction(&self, signature: &Signature) -> Result<EncodedConfirmedTransactionWithStatusMeta> {
+
+        // ─── define '_permit' ───
+        let _permit = self.limit.acquire().await?;
+
+        // ─── define 'config' ───
+        let config = RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        };
+
+        // ─── define 'transaction' ───
+        let transaction = self
+            .callrpc()
+            .get_transaction_with_config(signature, config)
+            .await
+            .with_context(|| format!("Failed to get transaction {}", signature))?;
+
+        // ─── return 'Result' ───
+        Ok(transaction)
+    }
+}
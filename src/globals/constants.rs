@@ -30,6 +30,18 @@ pub const BONKVIRTUALBASE: usize = BONKDISC + 29;
 /// const description
 pub const BONKVIRTUALQUOTE: usize = BONKDISC + 37;
 
+// ─── const 'BUSLAGWARNEVERY' ───
+/// const description
+pub const BUSLAGWARNEVERY: u64 = 1_000;
+
+// ─── const 'DEFBACKFILLCONCURRENCY' ───
+/// const description
+pub const DEFBACKFILLCONCURRENCY: usize = 8;
+
+// ─── const 'DEFBACKFILLPAGESIZE' ───
+/// const description
+pub const DEFBACKFILLPAGESIZE: usize = 1000;
+
 // ─── const 'DEFBATCHSIZE' ───
 /// const description
 pub const DEFBATCHSIZE: usize = 500;
@@ -38,10 +50,26 @@ pub const DEFBATCHSIZE: usize = 500;
 /// const description
 pub const DEFBATCHTIMEOUT: u64 = 5;
 
+// ─── const 'DEFCAPTURELEVEL' ───
+/// const description
+pub const DEFCAPTURELEVEL: i32 = 3;
+
+// ─── const 'DEFCAPTUREQUEUE' ───
+/// const description
+pub const DEFCAPTUREQUEUE: usize = 65_536;
+
 // ─── const 'DEFCHANNELSIZE' ───
 /// const description
 pub const DEFCHANNELSIZE: usize = 100_000;
 
+// ─── const 'DEFCOMMITTRACKSLOTS' ───
+/// const description
+pub const DEFCOMMITTRACKSLOTS: usize = 512;
+
+// ─── const 'DEFDEDUPCAPACITY' ───
+/// const description
+pub const DEFDEDUPCAPACITY: usize = 50_000;
+
 // ─── const 'DEFHPCHANNELSIZE' ───
 /// const description
 pub const DEFHPCHANNELSIZE: usize = 100_000;
@@ -66,6 +94,26 @@ pub const DEFMETRICSPRINTINT: u64 = 10;
 /// const description
 pub const DEFMETRICSWINSEC: u64 = 5;
 
+// ─── const 'DEFPRIORITYINGRESSSIZE' ───
+/// const description
+pub const DEFPRIORITYINGRESSSIZE: usize = 1_024;
+
+// ─── const 'DEFRECONNECTINITMS' ───
+/// const description
+pub const DEFRECONNECTINITMS: u64 = 250;
+
+// ─── const 'DEFRECONNECTMAXMS' ───
+/// const description
+pub const DEFRECONNECTMAXMS: u64 = 30_000;
+
+// ─── const 'DEFRECONNECTMULTIPLIER' ───
+/// const description
+pub const DEFRECONNECTMULTIPLIER: f64 = 2.0;
+
+// ─── const 'DEFRECONNECTRESUMEATTEMPTS' ───
+/// const description
+pub const DEFRECONNECTRESUMEATTEMPTS: usize = 3;
+
 // ─── const 'DEFRETRYATTEMPTS' ───
 /// const description
 pub const DEFRETRYATTEMPTS: usize = 3;
@@ -74,6 +122,14 @@ pub const DEFRETRYATTEMPTS: usize = 3;
 /// const description
 pub const DEFRETRYWAITMS: u64 = 1;
 
+// ─── const 'DEFSHARDCHANNELSIZE' ───
+/// const description
+pub const DEFSHARDCHANNELSIZE: usize = 1_024;
+
+// ─── const 'DEFSHUTDOWNDEADLINEMS' ───
+/// const description
+pub const DEFSHUTDOWNDEADLINEMS: u64 = 5_000;
+
 // ─── const 'DEFTIMEOUTCONNECT' ───
 /// const description
 pub const DEFTIMEOUTCONNECT: u64 = 5;
@@ -82,6 +138,14 @@ pub const DEFTIMEOUTCONNECT: u64 = 5;
 /// const description
 pub const DEFTIMEOUTREQUEST: u64 = 60;
 
+// ─── const 'DEFWATCHDOGIDLEMS' ───
+/// const description
+pub const DEFWATCHDOGIDLEMS: u64 = 30_000;
+
+// ─── const 'DEFWATCHDOGPINGMS' ───
+/// const description
+pub const DEFWATCHDOGPINGMS: u64 = 10_000;
+
 // ─── const 'LAMPORTSPERSOL' ───
 /// const description
 pub const LAMPORTSPERSOL: f64 = 1_000_000_000.0;
@@ -154,6 +218,10 @@ pub const POSTGRESTOKENSMAXBATCH: usize = 8_000;
 /// const description
 pub const PROCMAXCONCURRENCYCAP: usize = 256;
 
+// ─── const 'PROMETHEUSMAXREQUEST' ───
+/// const description
+pub const PROMETHEUSMAXREQUEST: usize = 8_192;
+
 // ─── const 'RAYDIUMCLMMMINLEN' ───
 /// const description
 pub const RAYDIUMCLMMMINLEN: usize = 1536;
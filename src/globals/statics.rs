@@ -10,6 +10,7 @@ use tokio::sync::{broadcast, Semaphore};
 use crate::globals::pubkeys::{bonk_pubkeys, pumpfun_pubkeys, pumpswap_pubkeys, raydiumamm_pubkeys, raydiumclmm_pubkeys, raydiumcpmm_pubkeys};
 use crate::streaming::events::core::eventparser::AccountEventParseConfig;
 use crate::streaming::events::Protocol;
+use crate::streaming::grpc::control::SubscriptionController;
 use crate::trading::monitor::CloseCmd;
 use crate::utils::storage::TokenRow;
 
@@ -21,6 +22,14 @@ pub static OPENFLIGHT: OnceCell<Arc<Semaphore>> = OnceCell::new();
 /// const description
 pub static MONITORBUS: OnceCell<broadcast::Sender<CloseCmd>> = OnceCell::new();
 
+// ─── const 'MONITORCONTROL' ───
+/// const description
+pub static MONITORCONTROL: OnceCell<SubscriptionController> = OnceCell::new();
+
+// ─── const 'MONITORWATCH' ───
+/// const description
+pub static MONITORWATCH: Lazy<DashMap<String, Vec<String>>> = Lazy::new(DashMap::new);
+
 // ─── const 'POSTGRESLASTENRICH' ───
 /// const description
 pub static POSTGRESLASTENRICH: Lazy<DashMap<(String, String), (i64, i64)>> = Lazy::new(|| DashMap::new());
@@ -1,7 +1,8 @@
 // ─── imports packages ───
 use anyhow::{Result, anyhow};
 use env_logger::{Builder, Env};
-use log::{error, info};
+use futures::future::BoxFuture;
+use log::{error, info, warn};
 use std::sync::Arc;
 use tokio::time::{sleep, Duration};
 
@@ -9,8 +10,9 @@ use tokio::time::{sleep, Duration};
 use ghostreaver::core::client::RPCClient;
 use ghostreaver::globals::constants::*;
 use ghostreaver::{
-    eventsmatch,
+    eventsmatchasync,
     streaming::{
+        common::PrometheusText,
         events::{
             common::{filter::EventTypeFilter, EventType},
             protocols::{
@@ -47,7 +49,7 @@ use ghostreaver::{
         },
         grpc::ClientConfig,
         yellowstone::{AccountFilter, TransactionFilter},
-        YellowstoneGrpc
+        Backfill, BackfillRange, YellowstoneGrpc
     },
 };
 use ghostreaver::utils::loader::{ServerConfig, TradeConfig, WalletConfig};
@@ -58,7 +60,9 @@ use ghostreaver::trading::monitor::TradeMonitor;
 /// struct description
 struct GhostReaver {
     grpc: YellowstoneGrpc,
-    storage: Arc<Storage>
+    rpc: Arc<RPCClient>,
+    storage: Arc<Storage>,
+    backfill: u64
 }
 
 // ─── impl 'GhostReaver' ───
@@ -103,9 +107,16 @@ impl GhostReaver {
         let mut confgrpc = ClientConfig::high_performance();
         confgrpc.enable_metrics = false;
 
+        // ─── define 'prometheus' ───
+        let prometheus = bot.bot.monitoring.prometheus.clone().filter(|bind| !bind.is_empty());
+        confgrpc.prometheus.bind = prometheus.clone();
+
+        // ─── compare 'confserv.endpoint.transport' ───
+        confserv.endpoint.transport.applyconnection(&mut confgrpc.connection).map_err(|e| anyhow!("loading transport config: {e}"))?;
+
         // ─── define 'grpc' ───
-        let grpc = YellowstoneGrpc::new_with_config(confserv.endpoint.geyser.clone(), 
-            Some(confserv.endpoint.xtoken.clone()), confgrpc).map_err(|e| anyhow!("creating Yellowstone gRPC client: {e}"))?;
+        let grpc = YellowstoneGrpc::new_redundant_with_config(confserv.endpoint.geyserendpoints(), confgrpc)
+            .map_err(|e| anyhow!("creating Yellowstone gRPC client: {e}"))?;
 
         // ─── define 'storage' ───
         let storage = Arc::new(Storage::new().await.map_err(|e| anyhow!("creating Storage: {e}"))?);
@@ -131,11 +142,80 @@ impl GhostReaver {
             error!("Failed to update wallet: {e}");
         }
 
+        // ─── compare 'prometheus' ───
+        if let Some(bind) = prometheus {
+
+            // ─── compare 'GhostReaver::spawnprometheus()' ───
+            if let Err(e) = GhostReaver::spawnprometheus(&grpc, Arc::clone(&storage), &bind).await {
+                error!("Failed to start Prometheus endpoint on {bind}: {e}");
+            }
+        }
+
         // ─── callback 'storage.walletupdate()' ───
         GhostReaver::spawnexit(Arc::clone(&storage), bot.bot.main.maxtrades as u64);
 
         // ─── return 'Result' ───
-        Ok(GhostReaver { grpc, storage })
+        Ok(GhostReaver { grpc, rpc, storage, backfill: bot.bot.monitoring.backfill })
+    }
+
+    // ─── fn 'spawnprometheus' ───
+    /// fn description
+    async fn spawnprometheus(grpc: &YellowstoneGrpc, storage: Arc<Storage>, bind: &str) -> Result<()> {
+
+        // ─── define 'exporter' ───
+        let exporter = grpc.prometheus_exporter().with_collector(move || {
+
+            // ─── define 'storage' ───
+            let storage = Arc::clone(&storage);
+            async move {
+
+                // ─── define 'text' ───
+                let mut text = PrometheusText::new();
+
+                // ─── define '(tickdepth, tickcap)' ───
+                let (tickdepth, tickcap) = storage.tickqueue();
+
+                // ─── define '(tokendepth, tokencap)' ───
+                let (tokendepth, tokencap) = storage.tokenqueue();
+                text.metric("ghostreaver_storage_queue_depth", "gauge", "Updates waiting for a storage writer", &[
+                        (PrometheusText::label("queue", "tick"), tickdepth as f64),
+                        (PrometheusText::label("queue", "token"), tokendepth as f64),
+                    ])
+                    .metric("ghostreaver_storage_queue_capacity", "gauge", "Capacity of the storage writer queues", &[
+                        (PrometheusText::label("queue", "tick"), tickcap as f64),
+                        (PrometheusText::label("queue", "token"), tokencap as f64),
+                    ]);
+
+                // ─── compare 'storage.tradescount()' ───
+                if let Ok(open) = storage.tradescount().await {
+                    text.gauge("ghostreaver_trades_open", "Trades currently open", open as f64);
+                }
+
+                // ─── compare 'storage.tradestotal()' ───
+                if let Ok(closed) = storage.tradestotal().await {
+                    text.counter("ghostreaver_trades_closed_total", "Trades closed", closed as f64);
+                }
+
+                // ─── compare 'storage.tradespnl()' ───
+                if let Ok(pnl) = storage.tradespnl().await {
+                    text.gauge("ghostreaver_realized_pnl_sol", "Realized PnL of closed trades in SOL", pnl);
+                }
+
+                // ─── compare 'storage.walletbalance()' ───
+                if let Ok(Some(balance)) = storage.walletbalance().await {
+                    text.gauge("ghostreaver_wallet_balance_sol", "Wallet balance in SOL", balance);
+                }
+
+                // ─── return 'String' ───
+                text.finish()
+            }
+        });
+
+        // ─── callback 'exporter.serve()' ───
+        exporter.serve(bind).await?;
+
+        // ─── return 'Result' ───
+        Ok(())
     }
 
     // ─── fn 'spawnexit' ───
@@ -196,200 +276,150 @@ impl GhostReaver {
         }
     }
 
+    // ─── fn 'eventkey' ───
+    /// fn description
+    fn eventkey(event: &dyn UnifiedEvent) -> Option<String> {
+
+        // ─── define 'any' ───
+        let any = event.as_any();
+
+        // ─── compare 'any.downcast_ref()' ───
+        if let Some(e) = any.downcast_ref::<BonkPoolCreateEvent>() {
+            Some(e.base_mint.to_string())
+        } else if let Some(e) = any.downcast_ref::<BonkTradeEvent>() {
+            Some(e.base_token_mint.to_string())
+        } else if let Some(e) = any.downcast_ref::<PumpFunCreateTokenEvent>() {
+            Some(e.mint.to_string())
+        } else if let Some(e) = any.downcast_ref::<PumpFunTradeEvent>() {
+            Some(e.mint.to_string())
+        } else if let Some(e) = any.downcast_ref::<PumpSwapCreatePoolEvent>() {
+            Some(e.quote_mint.to_string())
+        } else if let Some(e) = any.downcast_ref::<PumpSwapBuyEvent>() {
+            Some(e.quote_mint.to_string())
+        } else if let Some(e) = any.downcast_ref::<PumpSwapSellEvent>() {
+            Some(e.quote_mint.to_string())
+        } else if let Some(e) = any.downcast_ref::<PumpSwapWithdrawEvent>() {
+            Some(e.quote_mint.to_string())
+        } else if let Some(e) = any.downcast_ref::<RaydiumAmmV4Initialize2Event>() {
+            Some(e.amm.to_string())
+        } else if let Some(e) = any.downcast_ref::<RaydiumAmmV4AmmInfoAccountEvent>() {
+            Some(e.pubkey.clone())
+        } else if let Some(e) = any.downcast_ref::<RaydiumClmmCreatePoolEvent>() {
+            Some(e.pool_state.to_string())
+        } else if let Some(e) = any.downcast_ref::<RaydiumClmmPoolStateAccountEvent>() {
+            Some(e.pubkey.clone())
+        } else if let Some(e) = any.downcast_ref::<RaydiumCpmmInitializeEvent>() {
+            Some(e.token1_mint.to_string())
+        } else {
+            any.downcast_ref::<RaydiumCpmmSwapEvent>().map(|e| e.input_token_mint.to_string())
+        }
+    }
+
     // ─── fn 'eventcallback' ───
     /// fn description─
-    fn eventcallback(storage: Arc<Storage>) -> impl Fn(Box<dyn UnifiedEvent>) + Send + Sync + 'static {
+    fn eventcallback(storage: Arc<Storage>) -> impl Fn(Box<dyn UnifiedEvent>) -> BoxFuture<'static, ()> + Send + Sync + 'static {
         move |event: Box<dyn UnifiedEvent>| {
 
             // ─── define 'storage' ───
             let storage = Arc::clone(&storage);
-            eventsmatch!(event, {
-                BonkPoolCreateEvent => |e: BonkPoolCreateEvent| {
-
-                    // ─── define 'dbstore' ───
-                    let dbstore = Arc::clone(&storage);
-
-                    // ─── proceed 'tokio' ───
-                    tokio::spawn(async move {
+            Box::pin(async move {
+                eventsmatchasync!(event, {
+                    BonkPoolCreateEvent => |e: BonkPoolCreateEvent| async move {
 
                         // ─── compare 'Storage::tokeninsertbonk()' ───
-                        if let Err(err) = Storage::tokeninsertbonk(&dbstore, &e).await {
+                        if let Err(err) = Storage::tokeninsertbonk(&storage, &e).await {
                             error!("storage write failed: {err}");
                         }
-                    });
-                },
-                PumpFunCreateTokenEvent => |e: PumpFunCreateTokenEvent| {
-
-                    // ─── define 'dbstore' ───
-                    let dbstore = Arc::clone(&storage);
-
-                    // ─── proceed 'tokio' ───
-                    tokio::spawn(async move {
+                    },
+                    PumpFunCreateTokenEvent => |e: PumpFunCreateTokenEvent| async move {
 
                         // ─── compare 'Storage::tokeninsertpumpfun()' ───
-                        if let Err(err) = Storage::tokeninsertpumpfun(&dbstore, &e).await {
+                        if let Err(err) = Storage::tokeninsertpumpfun(&storage, &e).await {
                             error!("storage write failed: {err}");
                         }
-                    });
-                },
-                PumpSwapCreatePoolEvent => |e: PumpSwapCreatePoolEvent| {
-
-                    // ─── define 'dbstore' ───
-                    let dbstore = Arc::clone(&storage);
-
-                    // ─── proceed 'tokio' ───
-                    tokio::spawn(async move {
+                    },
+                    PumpSwapCreatePoolEvent => |e: PumpSwapCreatePoolEvent| async move {
 
                         // ─── compare 'Storage::tokeninsertpumpswap()' ───
-                        if let Err(err) = Storage::tokeninsertpumpswap(&dbstore, &e).await {
+                        if let Err(err) = Storage::tokeninsertpumpswap(&storage, &e).await {
                             error!("storage write failed: {err}");
                         }
-                    });
-                },
-                RaydiumAmmV4Initialize2Event => |e: RaydiumAmmV4Initialize2Event| {
-
-                    // ─── define 'dbstore' ───
-                    let dbstore = Arc::clone(&storage);
-
-                    // ─── proceed 'tokio' ───
-                    tokio::spawn(async move {
+                    },
+                    RaydiumAmmV4Initialize2Event => |e: RaydiumAmmV4Initialize2Event| async move {
 
                         // ─── compare 'Storage::tokeninsertraydiumamm()' ───
-                        if let Err(err) = Storage::tokeninsertraydiumamm(&dbstore, &e).await {
+                        if let Err(err) = Storage::tokeninsertraydiumamm(&storage, &e).await {
                             error!("storage write failed: {err}");
                         }
-                    });
-                },
-                RaydiumClmmCreatePoolEvent => |e: RaydiumClmmCreatePoolEvent| {
-
-                    // ─── define 'dbstore' ───
-                    let dbstore = Arc::clone(&storage);
-
-                    // ─── proceed 'tokio' ───
-                    tokio::spawn(async move {
+                    },
+                    RaydiumClmmCreatePoolEvent => |e: RaydiumClmmCreatePoolEvent| async move {
 
                         // ─── compare 'Storage::tokeninsertraydiumclmm()' ───
-                        if let Err(err) = Storage::tokeninsertraydiumclmm(&dbstore, &e).await {
+                        if let Err(err) = Storage::tokeninsertraydiumclmm(&storage, &e).await {
                             error!("storage write failed: {err}");
                         }
-                    });
-                },
-                RaydiumCpmmInitializeEvent => |e: RaydiumCpmmInitializeEvent| {
-
-                    // ─── define 'dbstore' ───
-                    let dbstore = Arc::clone(&storage);
-
-                    // ─── proceed 'tokio' ───
-                    tokio::spawn(async move {
+                    },
+                    RaydiumCpmmInitializeEvent => |e: RaydiumCpmmInitializeEvent| async move {
 
                         // ─── compare 'Storage::tokeninsertraydiumcpmm()' ───
-                        if let Err(err) = Storage::tokeninsertraydiumcpmm(&dbstore, &e).await {
+                        if let Err(err) = Storage::tokeninsertraydiumcpmm(&storage, &e).await {
                             error!("storage write failed: {err}");
                         }
-                    });
-                },
-                BonkTradeEvent => |e: BonkTradeEvent| {
-
-                    // ─── define 'dbstore' ───
-                    let dbstore = Arc::clone(&storage);
-
-                    // ─── proceed 'tokio' ───
-                    tokio::spawn(async move {
+                    },
+                    BonkTradeEvent => |e: BonkTradeEvent| async move {
 
                         // ─── compare 'Storage::tokenupdatebonk()' ───
-                        if let Err(err) = Storage::tokenupdatebonk(&dbstore, &e).await {
+                        if let Err(err) = Storage::tokenupdatebonk(&storage, &e).await {
                             error!("update write failed: {err}");
                         }
-                    });
-                },
-                PumpFunTradeEvent => |e: PumpFunTradeEvent| {
-
-                    // ─── define 'dbstore' ───
-                    let dbstore = Arc::clone(&storage);
-
-                    // ─── proceed 'tokio' ───
-                    tokio::spawn(async move {
+                    },
+                    PumpFunTradeEvent => |e: PumpFunTradeEvent| async move {
 
                         // ─── compare 'Storage::tokenupdatepumpfun()' ───
-                        if let Err(err) = Storage::tokenupdatepumpfun(&dbstore, &e).await {
+                        if let Err(err) = Storage::tokenupdatepumpfun(&storage, &e).await {
                             error!("update write failed: {err}");
                         }
-                    });
-                },
-                PumpSwapBuyEvent => |e: PumpSwapBuyEvent| {
-
-                    // ─── define 'dbstore' ───
-                    let dbstore = Arc::clone(&storage);
-
-                    // ─── proceed 'tokio' ───
-                    tokio::spawn(async move {
+                    },
+                    PumpSwapBuyEvent => |e: PumpSwapBuyEvent| async move {
 
                         // ─── compare 'Storage::tokenupdatepumpswapbuy()' ───
-                        if let Err(err) = Storage::tokenupdatepumpswapbuy(&dbstore, &e).await {
+                        if let Err(err) = Storage::tokenupdatepumpswapbuy(&storage, &e).await {
                             error!("update write failed: {err}");
                         }
-                    });
-                },
-                PumpSwapSellEvent => |e: PumpSwapSellEvent| {
-
-                    // ─── define 'dbstore' ───
-                    let dbstore = Arc::clone(&storage);
-
-                    // ─── proceed 'tokio' ───
-                    tokio::spawn(async move {
+                    },
+                    PumpSwapSellEvent => |e: PumpSwapSellEvent| async move {
 
                         // ─── compare 'Storage::tokenupdatepumpswapsell()' ───
-                        if let Err(err) = Storage::tokenupdatepumpswapsell(&dbstore, &e).await {
+                        if let Err(err) = Storage::tokenupdatepumpswapsell(&storage, &e).await {
                             error!("update write failed: {err}");
                         }
-                    });
-                },
-                RaydiumAmmV4AmmInfoAccountEvent => |e: RaydiumAmmV4AmmInfoAccountEvent| {
-
-                    // ─── define 'dbstore' ───
-                    let dbstore = Arc::clone(&storage);
-
-                    // ─── proceed 'tokio' ───
-                    tokio::spawn(async move {
+                    },
+                    RaydiumAmmV4AmmInfoAccountEvent => |e: RaydiumAmmV4AmmInfoAccountEvent| async move {
 
                         // ─── compare 'Storage::tokenupdateraydiumamm()' ───
-                        if let Err(err) = Storage::tokenupdateraydiumamm(&dbstore, &e).await {
+                        if let Err(err) = Storage::tokenupdateraydiumamm(&storage, &e).await {
                             error!("update write failed: {err}");
                         }
-                    });
-                },
-                RaydiumClmmPoolStateAccountEvent => |e: RaydiumClmmPoolStateAccountEvent| {
-
-                    // ─── define 'dbstore' ───
-                    let dbstore = Arc::clone(&storage);
-
-                    // ─── proceed 'tokio' ───
-                    tokio::spawn(async move {
+                    },
+                    RaydiumClmmPoolStateAccountEvent => |e: RaydiumClmmPoolStateAccountEvent| async move {
 
                         // ─── compare 'Storage::tokenupdateraydiumclmm()' ───
-                        if let Err(err) = Storage::tokenupdateraydiumclmm(&dbstore, &e).await {
+                        if let Err(err) = Storage::tokenupdateraydiumclmm(&storage, &e).await {
                             error!("update write failed: {err}");
                         }
-                    });
-                },
-                RaydiumCpmmSwapEvent => |e: RaydiumCpmmSwapEvent| {
-
-                    // ─── define 'dbstore' ───
-                    let dbstore = Arc::clone(&storage);
-
-                    // ─── proceed 'tokio' ───
-                    tokio::spawn(async move {
+                    },
+                    RaydiumCpmmSwapEvent => |e: RaydiumCpmmSwapEvent| async move {
 
                         // ─── compare 'Storage::tokenupdateraydiumcpmm()' ───
-                        if let Err(err) = Storage::tokenupdateraydiumcpmm(&dbstore, &e).await {
+                        if let Err(err) = Storage::tokenupdateraydiumcpmm(&storage, &e).await {
                             error!("update write failed: {err}");
                         }
-                    });
-                },
-                PumpSwapWithdrawEvent => |e: PumpSwapWithdrawEvent| {
-                    TradeMonitor::signalclose(e.quote_mint, "Withdraw");
-                }
-            });
+                    },
+                    PumpSwapWithdrawEvent => |e: PumpSwapWithdrawEvent| async move {
+                        TradeMonitor::signalclose(e.quote_mint, "Withdraw");
+                    }
+                });
+            })
         }
     }
 
@@ -421,12 +451,14 @@ impl GhostReaver {
             account_include: account_include.clone(),
             account_exclude: vec![],
             account_required: vec![],
+            ..Default::default()
         };
 
         // ─── define 'account_filter' ───
         let account_filter = AccountFilter {
             account: vec![],
             owner: account_include.clone(),
+            ..Default::default()
         };
 
         // ─── define 'event_type_filter' ───
@@ -475,13 +507,62 @@ impl GhostReaver {
         // ─── define 'callback' ───
         let callback = Self::eventcallback(storage);
 
-        // ─── callback 'self.grpc.subscribe_events_immediate()' ───
-        self.grpc.subscribe_events_immediate(protocols, None, transaction_filter, account_filter, event_type_filter, None, callback)
+        // ─── callback 'self.grpc.subscribe_events_immediate_async()' ───
+        self.grpc.subscribe_events_immediate_async(protocols.clone(), None, transaction_filter, account_filter, event_type_filter.clone(), None,
+            Self::eventkey, callback)
             .await
             .map_err(|e| anyhow!("subscribing to Yellowstone stream: {e}"))?;
 
+        // ─── compare 'self.grpc.subscription_controller()' ───
+        if let Some(controller) = self.grpc.subscription_controller().await {
+
+            // ─── callback 'TradeMonitor::registercontrol()' ───
+            TradeMonitor::registercontrol(controller);
+        }
+
+        // ─── compare 'self.backfill' ───
+        if self.backfill > 0 {
+
+            // ─── define 'programs' ───
+            let programs = vec![
+                PUMPFUN_PROGRAM_ID,
+                PUMPSWAP_PROGRAM_ID,
+                BONK_PROGRAM_ID,
+                RAYDIUM_CPMM_PROGRAM_ID,
+                RAYDIUM_CLMM_PROGRAM_ID,
+                RAYDIUM_AMM_V4_PROGRAM_ID,
+            ];
+
+            // ─── define 'backfill' ───
+            let backfill = Backfill::new(Arc::clone(&self.rpc), programs, protocols, event_type_filter);
+
+            // ─── define 'callback' ───
+            let callback = Self::eventcallback(Arc::clone(&self.storage));
+
+            // ─── define 'range' ───
+            let range = BackfillRange::recent(self.backfill);
+            info!("[Backfill] Replaying the last {}s of history from RPC", self.backfill);
+
+            // ─── proceed 'tokio' ───
+            tokio::spawn(async move {
+
+                // ─── compare 'backfill.run_async()' ───
+                if let Err(e) = backfill.run_async(range, &callback).await {
+                    error!("[Backfill] Failed: {e}");
+                }
+            });
+        }
+
         info!("Waiting for Ctrl+C to stop...");
         tokio::signal::ctrl_c().await.map_err(|e| anyhow!("awaiting Ctrl+C: {e}"))?;
+        info!("[Shutdown] Ctrl+C received — draining in-flight events…");
+
+        // ─── compare 'self.grpc.shutdown()' ───
+        if self.grpc.shutdown(Duration::from_millis(DEFSHUTDOWNDEADLINEMS)).await {
+            info!("[Shutdown] Stream stopped, all queued events processed");
+        } else {
+            warn!("[Shutdown] Deadline of {}ms reached, remaining events dropped", DEFSHUTDOWNDEADLINEMS);
+        }
         Ok(())
     }
 
@@ -500,7 +581,9 @@ impl GhostReaver {
                 std::process::exit(1);
             }
         }
-        std::process::exit(0);
+
+        // ─── return 'Result' ───
+        Ok(())
     }
 }
 
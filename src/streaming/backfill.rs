@@ -0,0 +1,295 @@
+use std::collections::HashSet;
+use std::future::Future;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use log::{info, warn};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+use crate::common::AnyResult;
+use crate::core::client::RPCClient;
+use crate::globals::constants::*;
+use crate::streaming::events::common::filter::EventTypeFilter;
+use crate::streaming::events::{
+    core::traits::UnifiedEvent, protocols::mutil::parser::MutilEventParser, EventParser, Protocol,
+};
+use crate::streaming::grpc::types::{TransactionPayload, TransactionPretty};
+
+/// 回填范围（均为闭区间；end 为 None 表示到最新）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackfillRange {
+    Slots { start: u64, end: Option<u64> },
+    /// Unix 秒
+    Time { start: i64, end: Option<i64> },
+}
+
+impl BackfillRange {
+    /// 最近 seconds 秒
+    pub fn recent(seconds: u64) -> Self {
+        let now = chrono::Utc::now().timestamp();
+        Self::Time { start: now.saturating_sub(seconds as i64), end: None }
+    }
+
+    /// 是否已早于范围起点（签名按时间倒序返回，可据此停止翻页）；
+    /// 时间范围下无法得知 block_time 的签名视为超出范围，避免一直翻页
+    fn is_before_start(&self, slot: u64, block_time: Option<i64>) -> bool {
+        match *self {
+            Self::Slots { start, .. } => slot < start,
+            Self::Time { start, .. } => block_time.is_none_or(|t| t < start),
+        }
+    }
+
+    /// 是否晚于范围终点
+    fn is_after_end(&self, slot: u64, block_time: Option<i64>) -> bool {
+        match *self {
+            Self::Slots { end, .. } => end.is_some_and(|end| slot > end),
+            Self::Time { end, .. } => end.is_some_and(|end| block_time.is_some_and(|t| t > end)),
+        }
+    }
+}
+
+/// 回填统计
+#[derive(Debug, Clone, Default)]
+pub struct BackfillStats {
+    pub signatures: usize,
+    pub transactions: usize,
+    pub events: usize,
+    pub failed_fetches: usize,
+}
+
+/// 历史回填：按程序分页 getSignaturesForAddress，再 getTransaction 取完整交易，
+/// 包装为 TransactionPretty 后经 MutilEventParser 解析，事件交给与实时订阅相同的回调
+pub struct Backfill {
+    rpc: Arc<RPCClient>,
+    parser: Arc<dyn EventParser>,
+    programs: Vec<Pubkey>,
+    include_failed: bool,
+    page_size: usize,
+    concurrency: usize,
+}
+
+impl Backfill {
+    pub fn new(
+        rpc: Arc<RPCClient>,
+        programs: Vec<Pubkey>,
+        protocols: Vec<Protocol>,
+        event_type_filter: Option<EventTypeFilter>,
+    ) -> Self {
+        Self {
+            rpc,
+            parser: Arc::new(MutilEventParser::new(protocols, event_type_filter)),
+            programs,
+            include_failed: false,
+            page_size: DEFBACKFILLPAGESIZE,
+            concurrency: DEFBACKFILLCONCURRENCY,
+        }
+    }
+
+    /// 是否包含执行失败的交易（默认与实时订阅一致：不包含）
+    pub fn with_failed(mut self, include_failed: bool) -> Self {
+        self.include_failed = include_failed;
+        self
+    }
+
+    /// getSignaturesForAddress 每页数量（RPC 上限 1000）
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.clamp(1, 1000);
+        self
+    }
+
+    /// 并发 getTransaction 数
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// 执行回填；事件按 slot 从旧到新交给回调，保证建池 / 建币事件先于交易事件
+    pub async fn run<F>(&self, range: BackfillRange, callback: &F) -> AnyResult<BackfillStats>
+    where
+        F: Fn(Box<dyn UnifiedEvent>) + Send + Sync,
+    {
+        self.run_async(range, &|event| {
+            callback(event);
+            std::future::ready(())
+        })
+        .await
+    }
+
+    /// 异步回调版本：每个事件的回调完成后才处理下一个
+    pub async fn run_async<F, Fut>(&self, range: BackfillRange, callback: &F) -> AnyResult<BackfillStats>
+    where
+        F: Fn(Box<dyn UnifiedEvent>) -> Fut + Send + Sync,
+        Fut: Future<Output = ()> + Send,
+    {
+        let mut stats = BackfillStats::default();
+
+        let mut signatures: Vec<(u64, Signature)> = Vec::new();
+        let mut seen: HashSet<Signature> = HashSet::new();
+        for program in &self.programs {
+            for (slot, signature) in self.collect_signatures(program, range).await? {
+                // 同一交易可能涉及多个程序
+                if seen.insert(signature) {
+                    signatures.push((slot, signature));
+                }
+            }
+        }
+        // 稳定排序：同一 slot 内保持各程序签名的链上顺序
+        signatures.sort_by_key(|(slot, _)| *slot);
+        stats.signatures = signatures.len();
+        info!("Backfilling {} transactions for {} programs", signatures.len(), self.programs.len());
+
+        // buffered 保持输入顺序，同时并发请求
+        let mut fetched = futures::stream::iter(signatures)
+            .map(|(_, signature)| {
+                let rpc = Arc::clone(&self.rpc);
+                async move {
+                    let result = rpc.gettransaction(&signature).await;
+                    (signature, result)
+                }
+            })
+            .buffered(self.concurrency);
+
+        while let Some((signature, result)) = fetched.next().await {
+            let confirmed = match result {
+                Ok(confirmed) => confirmed,
+                Err(e) => {
+                    warn!("Backfill skipped {signature}: {e:?}");
+                    stats.failed_fetches += 1;
+                    continue;
+                }
+            };
+            stats.transactions += 1;
+
+            let transaction_pretty = TransactionPretty::from_rpc(signature, confirmed);
+            let events = self.parse(&transaction_pretty).await;
+            stats.events += events.len();
+            for event in events {
+                callback(event).await;
+            }
+        }
+
+        info!(
+            "Backfill finished: {} transactions, {} events, {} failed fetches",
+            stats.transactions, stats.events, stats.failed_fetches
+        );
+        Ok(stats)
+    }
+
+    /// 向前翻页直到早于范围起点
+    async fn collect_signatures(
+        &self,
+        program: &Pubkey,
+        range: BackfillRange,
+    ) -> AnyResult<Vec<(u64, Signature)>> {
+        let mut collected = Vec::new();
+        let mut before: Option<Signature> = None;
+        // 签名按时间倒序返回：缺失 block_time 的签名不晚于上一条已知时间
+        let mut last_block_time: Option<i64> = None;
+
+        loop {
+            let page = self
+                .rpc
+                .getsignaturesforaddress(program, before, None, self.page_size)
+                .await?;
+            let Some(last) = page.last() else {
+                break;
+            };
+            let page_len = page.len();
+            before = last.signature.parse().ok();
+
+            let mut reached_start = false;
+            for status in page {
+                let block_time = status.block_time.or(last_block_time);
+                last_block_time = block_time;
+                if range.is_before_start(status.slot, block_time) {
+                    reached_start = true;
+                    break;
+                }
+                if range.is_after_end(status.slot, block_time) {
+                    continue;
+                }
+                if status.err.is_some() && !self.include_failed {
+                    continue;
+                }
+                if let Ok(signature) = status.signature.parse::<Signature>() {
+                    collected.push((status.slot, signature));
+                }
+            }
+
+            if reached_start || before.is_none() || page_len < self.page_size {
+                break;
+            }
+        }
+
+        // 翻页结果从新到旧，反转后同一 slot 内也按链上顺序排列
+        collected.reverse();
+        info!("Backfill found {} signatures for program {program}", collected.len());
+        Ok(collected)
+    }
+
+    async fn parse(&self, transaction_pretty: &TransactionPretty) -> Vec<Box<dyn UnifiedEvent>> {
+        let TransactionPayload::Encoded(tx) = &transaction_pretty.tx else {
+            return Vec::new();
+        };
+        let block_time = transaction_pretty.block_time.map(|ts| prost_types::Timestamp {
+            seconds: ts.seconds,
+            nanos: ts.nanos,
+        });
+        let signature = transaction_pretty.signature.to_string();
+        self.parser
+            .parse_transaction(
+                tx,
+                &signature,
+                Some(transaction_pretty.slot),
+                block_time,
+                chrono::Utc::now().timestamp_millis(),
+                None,
+            )
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Backfill failed to parse {signature}: {e:?}");
+                Vec::new()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slot_range_bounds_are_inclusive() {
+        let range = BackfillRange::Slots { start: 100, end: Some(200) };
+        assert!(range.is_before_start(99, None));
+        assert!(!range.is_before_start(100, None));
+        assert!(!range.is_after_end(200, None));
+        assert!(range.is_after_end(201, None));
+
+        // 未指定终点时到最新
+        let open = BackfillRange::Slots { start: 100, end: None };
+        assert!(!open.is_after_end(u64::MAX, None));
+    }
+
+    #[test]
+    fn time_range_uses_block_time() {
+        let range = BackfillRange::Time { start: 1_000, end: Some(2_000) };
+        assert!(range.is_before_start(0, Some(999)));
+        assert!(!range.is_before_start(0, Some(1_000)));
+        assert!(!range.is_after_end(0, Some(2_000)));
+        assert!(range.is_after_end(0, Some(2_001)));
+
+        // 缺失 block_time 视为早于起点，停止翻页；但不会被当作晚于终点而跳过
+        assert!(range.is_before_start(0, None));
+        assert!(!range.is_after_end(0, None));
+    }
+
+    #[test]
+    fn recent_range_ends_now() {
+        let before = chrono::Utc::now().timestamp();
+        let BackfillRange::Time { start, end } = BackfillRange::recent(60) else {
+            panic!("recent() must build a time range");
+        };
+        assert_eq!(end, None);
+        assert!(start >= before - 60 && start <= chrono::Utc::now().timestamp() - 60);
+    }
+}
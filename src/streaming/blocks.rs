@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::mpsc;
+use yellowstone_grpc_proto::geyser::CommitmentLevel;
+
+use crate::{
+    common::AnyResult,
+    streaming::{
+        common::{BackpressureStrategy, SubscriptionHandle},
+        events::{
+            common::{filter::EventTypeFilter, EventTimings},
+            Protocol, UnifiedEvent,
+        },
+        grpc::{control::SubscriptionController, types::EventPretty},
+        yellowstone::YellowstoneGrpc,
+    },
+};
+
+/// 完整区块体积较大，通道只需容纳少量区块
+const BLOCK_CHANNEL_SIZE: usize = 64;
+
+/// 一个区块内解析出的全部事件，保持区块内的交易顺序与指令顺序
+#[derive(Debug)]
+pub struct BlockEvents {
+    pub slot: u64,
+    pub parent_slot: u64,
+    pub block_hash: String,
+    pub parent_block_hash: String,
+    /// 区块时间（Unix 秒）
+    pub block_time: Option<i64>,
+    pub block_height: Option<u64>,
+    /// 区块内交易数（含投票交易）
+    pub transaction_count: usize,
+    pub events: Vec<Box<dyn UnifiedEvent>>,
+}
+
+impl YellowstoneGrpc {
+    /// 订阅完整区块：每个区块内成功执行的交易按区块内顺序经 MutilEventParser 解析，
+    /// 以 BlockEvents 整块回调（适用于分析、三明治检测与回测等需要精确块内顺序的场景）。
+    /// account_include 非空时只推送涉及这些账户的区块；区块按到达顺序依次回调，
+    /// 读取器使用阻塞背压，不丢弃区块。订阅在后台运行，通过 stop / shutdown 结束
+    pub async fn subscribe_blocks<F>(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        account_include: Vec<String>,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+        callback: F,
+    ) -> AnyResult<()>
+    where
+        F: Fn(BlockEvents) + Send + Sync + 'static,
+    {
+        self.stop().await;
+
+        let mut metrics_handle = None;
+        if self.config.enable_metrics {
+            metrics_handle = self.metrics_manager.start_auto_monitoring().await;
+        }
+
+        let subscribe_request =
+            self.subscription_manager.build_block_request(account_include, commitment);
+
+        // 不经 PriorityGate：区块必须完整、有序地送达处理器
+        let (tx, mut rx) = mpsc::channel::<EventPretty>(BLOCK_CHANNEL_SIZE);
+        let controller = SubscriptionController::new(subscribe_request);
+        let stream_handle =
+            self.spawn_stream_reader(&controller, tx, BackpressureStrategy::Block).await?;
+
+        // 单个消费者按到达顺序处理区块
+        let event_processor = Arc::clone(&self.event_processor);
+        let event_handle = tokio::spawn(async move {
+            while let Some(event_pretty) = rx.recv().await {
+                let EventPretty::Block(block_pretty) = event_pretty else {
+                    continue;
+                };
+                let mut timings = block_pretty.timings;
+                timings.dequeued_us = EventTimings::now_us();
+
+                let events = event_processor
+                    .parse_block(
+                        &block_pretty,
+                        timings,
+                        &block_pretty.filters,
+                        bot_wallet,
+                        protocols.clone(),
+                        event_type_filter.clone(),
+                    )
+                    .await;
+
+                callback(BlockEvents {
+                    slot: block_pretty.slot,
+                    parent_slot: block_pretty.parent_slot,
+                    block_hash: block_pretty.block_hash,
+                    parent_block_hash: block_pretty.parent_block_hash,
+                    block_time: block_pretty.block_time.map(|ts| ts.seconds),
+                    block_height: block_pretty.block_height,
+                    transaction_count: block_pretty.transactions.len(),
+                    events,
+                });
+            }
+        });
+
+        let subscription_handle =
+            SubscriptionHandle::new(stream_handle, event_handle, metrics_handle)
+                .with_controller(controller);
+        *self.subscription_handle.lock().await = Some(subscription_handle);
+
+        Ok(())
+    }
+}
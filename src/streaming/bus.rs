@@ -0,0 +1,365 @@
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::task::{Context, Poll};
+
+use tokio::sync::mpsc::{self, error::TrySendError};
+
+use crate::globals::constants::*;
+use crate::streaming::events::common::{EventType, ProtocolType};
+use crate::streaming::events::UnifiedEvent;
+
+/// 单个订阅者的投递结果
+enum Delivery {
+    /// 不匹配该订阅者
+    Skipped,
+    Delivered,
+    /// 通道已满，事件对该订阅者丢弃
+    Lagged,
+    /// 订阅已释放
+    Closed,
+}
+
+type Deliver = Box<dyn Fn(&Arc<dyn UnifiedEvent>) -> Delivery + Send + Sync>;
+
+struct Subscriber {
+    name: String,
+    deliver: Deliver,
+    delivered: AtomicU64,
+    lagged: Arc<AtomicU64>,
+    closed: AtomicBool,
+}
+
+/// 订阅者统计
+#[derive(Debug, Clone)]
+pub struct SubscriberStats {
+    pub name: String,
+    pub delivered: u64,
+    pub lagged: u64,
+}
+
+/// 进程内事件总线：位于 EventProcessor 与各消费者之间。
+/// 每个订阅者拥有独立的有界通道；发布永不阻塞，订阅者跟不上时事件只对它丢弃并计入 lag，
+/// 不影响其他订阅者
+#[derive(Default)]
+pub struct EventBus {
+    subscribers: RwLock<Vec<Subscriber>>,
+    published: AtomicU64,
+    /// 没有任何订阅者匹配的事件数
+    unrouted: AtomicU64,
+}
+
+impl EventBus {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// 作为订阅回调使用：`grpc.subscribe_events_immediate(..., bus.callback())`
+    pub fn callback(self: &Arc<Self>) -> impl Fn(Box<dyn UnifiedEvent>) + Send + Sync + 'static {
+        let bus = Arc::clone(self);
+        move |event| bus.publish(event)
+    }
+
+    /// 按具体事件类型订阅（如 PumpFunTradeEvent），收到的是该类型的副本
+    pub fn subscribe<T>(&self, name: impl Into<String>, capacity: usize) -> BusSubscription<T>
+    where
+        T: UnifiedEvent + Clone + 'static,
+    {
+        self.register(name.into(), capacity, |event| event.as_any().downcast_ref::<T>().cloned())
+    }
+
+    /// 按协议订阅
+    pub fn subscribe_protocol(
+        &self,
+        name: impl Into<String>,
+        protocol: ProtocolType,
+        capacity: usize,
+    ) -> BusSubscription<Arc<dyn UnifiedEvent>> {
+        self.subscribe_filter(name, capacity, move |event| event.protocol() == protocol)
+    }
+
+    /// 按 EventType 订阅
+    pub fn subscribe_event_types(
+        &self,
+        name: impl Into<String>,
+        event_types: Vec<EventType>,
+        capacity: usize,
+    ) -> BusSubscription<Arc<dyn UnifiedEvent>> {
+        self.subscribe_filter(name, capacity, move |event| event_types.contains(&event.event_type()))
+    }
+
+    /// 按任意条件订阅
+    pub fn subscribe_filter<P>(
+        &self,
+        name: impl Into<String>,
+        capacity: usize,
+        predicate: P,
+    ) -> BusSubscription<Arc<dyn UnifiedEvent>>
+    where
+        P: Fn(&dyn UnifiedEvent) -> bool + Send + Sync + 'static,
+    {
+        self.register(name.into(), capacity, move |event| {
+            predicate(event.as_ref()).then(|| Arc::clone(event))
+        })
+    }
+
+    /// 发布事件（非阻塞）；已释放的订阅在此顺带移除
+    pub fn publish(&self, event: Box<dyn UnifiedEvent>) {
+        self.published.fetch_add(1, Ordering::Relaxed);
+        let event: Arc<dyn UnifiedEvent> = Arc::from(event);
+
+        let mut matched = false;
+        let mut closed = false;
+        {
+            let subscribers = match self.subscribers.read() {
+                Ok(guard) => guard,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            for subscriber in subscribers.iter() {
+                match (subscriber.deliver)(&event) {
+                    Delivery::Skipped => {}
+                    Delivery::Delivered => {
+                        matched = true;
+                        subscriber.delivered.fetch_add(1, Ordering::Relaxed);
+                    }
+                    Delivery::Lagged => {
+                        matched = true;
+                        let lagged = subscriber.lagged.fetch_add(1, Ordering::Relaxed) + 1;
+                        if lagged % BUSLAGWARNEVERY == 1 {
+                            log::warn!(
+                                "Event bus subscriber '{}' is lagging ({} events dropped)",
+                                subscriber.name,
+                                lagged
+                            );
+                        }
+                    }
+                    Delivery::Closed => {
+                        subscriber.closed.store(true, Ordering::Relaxed);
+                        closed = true;
+                    }
+                }
+            }
+        }
+
+        if !matched {
+            self.unrouted.fetch_add(1, Ordering::Relaxed);
+        }
+        if closed {
+            self.prune();
+        }
+    }
+
+    /// 各订阅者统计
+    pub fn stats(&self) -> Vec<SubscriberStats> {
+        let subscribers = match self.subscribers.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        subscribers
+            .iter()
+            .map(|subscriber| SubscriberStats {
+                name: subscriber.name.clone(),
+                delivered: subscriber.delivered.load(Ordering::Relaxed),
+                lagged: subscriber.lagged.load(Ordering::Relaxed),
+            })
+            .collect()
+    }
+
+    /// 已发布事件数
+    pub fn published(&self) -> u64 {
+        self.published.load(Ordering::Relaxed)
+    }
+
+    /// 没有订阅者匹配的事件数
+    pub fn unrouted(&self) -> u64 {
+        self.unrouted.load(Ordering::Relaxed)
+    }
+
+    /// 当前订阅者数量
+    pub fn subscriber_count(&self) -> usize {
+        self.subscribers.read().map(|guard| guard.len()).unwrap_or(0)
+    }
+
+    fn register<T, M>(&self, name: String, capacity: usize, matcher: M) -> BusSubscription<T>
+    where
+        T: Send + 'static,
+        M: Fn(&Arc<dyn UnifiedEvent>) -> Option<T> + Send + Sync + 'static,
+    {
+        let (tx, rx) = mpsc::channel::<T>(capacity.max(1));
+        let lagged = Arc::new(AtomicU64::new(0));
+        let deliver: Deliver = Box::new(move |event| {
+            if tx.is_closed() {
+                return Delivery::Closed;
+            }
+            let Some(item) = matcher(event) else {
+                return Delivery::Skipped;
+            };
+            match tx.try_send(item) {
+                Ok(()) => Delivery::Delivered,
+                Err(TrySendError::Full(_)) => Delivery::Lagged,
+                Err(TrySendError::Closed(_)) => Delivery::Closed,
+            }
+        });
+
+        let subscriber = Subscriber {
+            name: name.clone(),
+            deliver,
+            delivered: AtomicU64::new(0),
+            lagged: Arc::clone(&lagged),
+            closed: AtomicBool::new(false),
+        };
+        match self.subscribers.write() {
+            Ok(mut guard) => guard.push(subscriber),
+            Err(poisoned) => poisoned.into_inner().push(subscriber),
+        }
+
+        BusSubscription { name, receiver: rx, lagged }
+    }
+
+    fn prune(&self) {
+        let mut subscribers = match self.subscribers.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        subscribers.retain(|subscriber| {
+            let closed = subscriber.closed.load(Ordering::Relaxed);
+            if closed {
+                log::info!("Event bus subscriber '{}' unsubscribed", subscriber.name);
+            }
+            !closed
+        });
+    }
+}
+
+/// 总线订阅：独立的有界接收端与 lag 计数；释放即退订
+pub struct BusSubscription<T> {
+    name: String,
+    receiver: mpsc::Receiver<T>,
+    lagged: Arc<AtomicU64>,
+}
+
+impl<T> BusSubscription<T> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub async fn recv(&mut self) -> Option<T> {
+        self.receiver.recv().await
+    }
+
+    pub fn try_recv(&mut self) -> Option<T> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// 因通道已满而错过的事件数
+    pub fn lagged(&self) -> u64 {
+        self.lagged.load(Ordering::Relaxed)
+    }
+
+    /// 当前排队中的事件数
+    pub fn pending(&self) -> usize {
+        self.receiver.len()
+    }
+}
+
+impl<T> futures::Stream for BusSubscription<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::events::common::EventMetadata;
+    use crate::streaming::events::protocols::block::slot::{SlotEvent, SlotStatus};
+    use crate::streaming::events::protocols::pumpfun::PumpFunTradeEvent;
+    use solana_sdk::pubkey::Pubkey;
+
+    fn trade(id: &str) -> Box<dyn UnifiedEvent> {
+        Box::new(PumpFunTradeEvent {
+            metadata: EventMetadata::new(
+                id.to_string(),
+                id.to_string(),
+                1,
+                0,
+                0,
+                ProtocolType::PumpFun,
+                EventType::PumpFunBuy,
+                Pubkey::default(),
+                "0".to_string(),
+                0,
+            ),
+            ..Default::default()
+        })
+    }
+
+    fn slot(slot: u64) -> Box<dyn UnifiedEvent> {
+        Box::new(SlotEvent::new(slot, None, SlotStatus::Processed, None, 0))
+    }
+
+    #[test]
+    fn subscribers_receive_matching_events_only() {
+        let bus = EventBus::new();
+        let mut trades = bus.subscribe::<PumpFunTradeEvent>("trades", 8);
+        let mut pumpfun = bus.subscribe_protocol("pumpfun", ProtocolType::PumpFun, 8);
+        let mut slots = bus.subscribe_event_types("slots", vec![EventType::Slot], 8);
+
+        bus.publish(trade("a"));
+        bus.publish(slot(7));
+
+        assert_eq!(trades.try_recv().map(|event| event.metadata.id), Some("a".to_string()));
+        assert!(trades.try_recv().is_none());
+        assert_eq!(pumpfun.try_recv().map(|event| event.id().to_string()), Some("a".to_string()));
+        assert!(pumpfun.try_recv().is_none());
+        assert_eq!(slots.try_recv().map(|event| event.slot()), Some(7));
+        assert_eq!(bus.published(), 2);
+        assert_eq!(bus.unrouted(), 0);
+    }
+
+    #[test]
+    fn slow_subscriber_lags_without_affecting_others() {
+        let bus = EventBus::new();
+        let slow = bus.subscribe::<PumpFunTradeEvent>("slow", 1);
+        let mut fast = bus.subscribe::<PumpFunTradeEvent>("fast", 8);
+
+        for id in ["a", "b", "c"] {
+            bus.publish(trade(id));
+        }
+
+        assert_eq!(slow.lagged(), 2);
+        assert_eq!(slow.pending(), 1);
+        assert_eq!(fast.pending(), 3);
+        assert_eq!(fast.try_recv().map(|event| event.metadata.id), Some("a".to_string()));
+
+        let stats = bus.stats();
+        assert_eq!((stats[0].delivered, stats[0].lagged), (1, 2));
+        assert_eq!((stats[1].delivered, stats[1].lagged), (3, 0));
+    }
+
+    #[test]
+    fn dropped_subscription_is_pruned_on_publish() {
+        let bus = EventBus::new();
+        let subscription = bus.subscribe::<PumpFunTradeEvent>("gone", 8);
+        assert_eq!(bus.subscriber_count(), 1);
+
+        drop(subscription);
+        bus.publish(trade("a"));
+
+        assert_eq!(bus.subscriber_count(), 0);
+        // 没有订阅者匹配的事件计入 unrouted
+        assert_eq!(bus.unrouted(), 1);
+    }
+
+    #[tokio::test]
+    async fn callback_publishes_into_the_bus() {
+        let bus = EventBus::new();
+        let mut subscription = bus.subscribe::<SlotEvent>("slots", 8);
+        let callback = bus.callback();
+
+        callback(slot(3));
+        assert_eq!(subscription.recv().await.map(|event| event.slot), Some(3));
+    }
+}
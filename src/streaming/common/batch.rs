@@ -1,4 +1,6 @@
 // ─── import crates ───
+use crate::streaming::common::metrics::MetricsManager;
+use crate::streaming::events::common::types::EventTimings;
 use crate::streaming::events::UnifiedEvent;
 
 // ─── struct 'EventBatchProcessor' ───
@@ -9,6 +11,7 @@ pub struct EventBatchProcessor<F> where F: FnMut(Vec<Box<dyn UnifiedEvent>>) + S
     batch_size: usize,
     timeout_ms: u64,
     last_flush_time: std::time::Instant,
+    metrics_manager: Option<MetricsManager>,
 }
 
 // ─── impl 'EventBatchProcessor' ───
@@ -20,7 +23,17 @@ impl<F> EventBatchProcessor<F> where F: FnMut(Vec<Box<dyn UnifiedEvent>>) + Send
     pub fn new(callback: F, batch_size: usize, timeout_ms: u64) -> Self {
 
         // ─── return 'Self' ───
-        Self { callback, batch: Vec::with_capacity(batch_size), batch_size, timeout_ms, last_flush_time: std::time::Instant::now() }
+        Self { callback, batch: Vec::with_capacity(batch_size), batch_size, timeout_ms, last_flush_time: std::time::Instant::now(),
+            metrics_manager: None }
+    }
+
+    // ─── fn 'with_metrics' ───
+    /// fn description
+    pub fn with_metrics(mut self, metrics_manager: MetricsManager) -> Self {
+        self.metrics_manager = Some(metrics_manager);
+
+        // ─── return 'Self' ───
+        self
     }
 
     // ─── fn 'add_event' ───
@@ -44,7 +57,24 @@ impl<F> EventBatchProcessor<F> where F: FnMut(Vec<Box<dyn UnifiedEvent>>) + Send
         if !self.batch.is_empty() {
 
             // ─── define 'events' ───
-            let events = std::mem::replace(&mut self.batch, Vec::with_capacity(self.batch_size));
+            let mut events = std::mem::replace(&mut self.batch, Vec::with_capacity(self.batch_size));
+
+            // ─── define 'callback_us' ───
+            let callback_us = EventTimings::now_us();
+
+            // ─── proceed 'for' ───
+            for event in events.iter_mut() {
+
+                // ─── define 'timings' ───
+                let mut timings = event.timings();
+                timings.callback_us = callback_us;
+                event.set_timings(timings);
+
+                // ─── compare 'self.metrics_manager' ───
+                if let Some(metrics_manager) = &self.metrics_manager {
+                    metrics_manager.record_latency(&timings);
+                }
+            }
             log::debug!("Flushing {} events from batch processor", events.len());
 
             // ─── compare 'log::log_enabled!()' ───
@@ -1,5 +1,10 @@
+// ─── import packages ───
+use std::str::FromStr;
+use tonic::codec::CompressionEncoding;
+
 // ─── import crates ───
 use crate::globals::constants::*;
+use crate::streaming::events::common::types::{EventType, CRITICAL_EVENT_TYPES};
 
 // ─── enum 'BackpressureStrategy' ───
 /// enum description
@@ -8,6 +13,7 @@ pub enum BackpressureStrategy {
     Block,
     Drop,
     Retry { max_attempts: usize, wait_ms: u64 },
+    Priority,
 }
 
 // ─── impl 'Default for BackpressureStrategy' ───
@@ -58,6 +64,7 @@ impl Default for BatchConfig {
 pub struct BackpressureConfig {
     pub channel_size: usize,
     pub strategy: BackpressureStrategy,
+    pub critical_event_types: Vec<EventType>,
 }
 
 // ─── impl 'Default for BackpressureConfig' ───
@@ -72,6 +79,7 @@ impl Default for BackpressureConfig {
         Self {
             channel_size: DEFCHANNELSIZE,
             strategy: BackpressureStrategy::default(),
+            critical_event_types: CRITICAL_EVENT_TYPES.to_vec(),
         }
     }
 }
@@ -82,7 +90,11 @@ impl Default for BackpressureConfig {
 pub struct ConnectionConfig {
     pub connect_timeout: u64,
     pub request_timeout: u64,
-    pub max_decoding_message_size: usize
+    pub max_decoding_message_size: usize,
+    pub share_connection: bool,
+    pub tls: TlsConfig,
+    pub proxy: Option<ProxyConfig>,
+    pub compression: GrpcCompression
 }
 
 // ─── impl 'Default for ConnectionConfig' ───
@@ -97,11 +109,262 @@ impl Default for ConnectionConfig {
         Self {
             connect_timeout: DEFTIMEOUTCONNECT,
             request_timeout: DEFTIMEOUTREQUEST,
-            max_decoding_message_size: DEFMAXDECODINGSIZE
+            max_decoding_message_size: DEFMAXDECODINGSIZE,
+            share_connection: false,
+            tls: TlsConfig::default(),
+            proxy: None,
+            compression: GrpcCompression::default()
+        }
+    }
+}
+
+// ─── struct 'TlsConfig' ───
+/// struct description
+#[derive(Debug, Clone)]
+pub struct TlsConfig {
+    pub native_roots: bool,
+    pub ca_cert_path: Option<String>,
+    pub client_cert_path: Option<String>,
+    pub client_key_path: Option<String>,
+    pub domain_name: Option<String>
+}
+
+// ─── impl 'Default for TlsConfig' ───
+/// impl description
+impl Default for TlsConfig {
+
+    // ─── fn 'default' ───
+    /// fn description
+    fn default() -> Self {
+
+        // ─── return 'Self' ───
+        Self {
+            native_roots: true,
+            ca_cert_path: None,
+            client_cert_path: None,
+            client_key_path: None,
+            domain_name: None
+        }
+    }
+}
+
+// ─── struct 'ProxyConfig' ───
+/// struct description
+#[derive(Debug, Clone)]
+pub struct ProxyConfig {
+    pub url: String,
+    pub username: Option<String>,
+    pub password: Option<String>
+}
+
+// ─── impl 'ProxyConfig' ───
+/// impl description
+impl ProxyConfig {
+
+    // ─── fn 'new' ───
+    /// fn description
+    pub fn new(url: impl Into<String>) -> Self {
+
+        // ─── return 'Self' ───
+        Self { url: url.into(), username: None, password: None }
+    }
+
+    // ─── fn 'with_auth' ───
+    /// fn description
+    pub fn with_auth(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self.password = Some(password.into());
+
+        // ─── return 'Self' ───
+        self
+    }
+}
+
+// ─── enum 'GrpcCompression' ───
+/// enum description
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GrpcCompression {
+    #[default]
+    None,
+    Gzip,
+    Zstd
+}
+
+// ─── impl 'GrpcCompression' ───
+/// impl description
+impl GrpcCompression {
+
+    // ─── fn 'encoding' ───
+    /// fn description
+    pub fn encoding(self) -> Option<CompressionEncoding> {
+
+        // ─── match 'self' ───
+        match self {
+            Self::None => None,
+            Self::Gzip => Some(CompressionEncoding::Gzip),
+            Self::Zstd => Some(CompressionEncoding::Zstd)
+        }
+    }
+}
+
+// ─── impl 'FromStr for GrpcCompression' ───
+/// impl description
+impl FromStr for GrpcCompression {
+    type Err = String;
+
+    // ─── fn 'from_str' ───
+    /// fn description
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+
+        // ─── match 'value' ───
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "none" => Ok(Self::None),
+            "gzip" => Ok(Self::Gzip),
+            "zstd" => Ok(Self::Zstd),
+            other => Err(format!("unsupported gRPC compression '{other}' (expected none, gzip or zstd)"))
+        }
+    }
+}
+
+// ─── struct 'ReconnectConfig' ───
+/// struct description
+#[derive(Debug, Clone)]
+pub struct ReconnectConfig {
+    pub enabled: bool,
+    pub initial_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    pub backoff_multiplier: f64,
+    pub max_attempts: Option<usize>,
+    pub resume_from_slot: bool,
+    /// 连续多少次重连后仍未收到任何更新即放弃 from_slot 续订（例如 from_slot 已超出服务端保留范围）
+    pub max_resume_attempts: usize,
+    pub dedup_capacity: usize
+}
+
+// ─── impl 'Default for ReconnectConfig' ───
+/// impl description
+impl Default for ReconnectConfig {
+
+    // ─── fn 'default' ───
+    /// fn description
+    fn default() -> Self {
+
+        // ─── return 'Self' ───
+        Self {
+            enabled: true,
+            initial_backoff_ms: DEFRECONNECTINITMS,
+            max_backoff_ms: DEFRECONNECTMAXMS,
+            backoff_multiplier: DEFRECONNECTMULTIPLIER,
+            max_attempts: None,
+            resume_from_slot: true,
+            max_resume_attempts: DEFRECONNECTRESUMEATTEMPTS,
+            dedup_capacity: DEFDEDUPCAPACITY
+        }
+    }
+}
+
+// ─── impl 'ReconnectConfig' ───
+/// impl description
+impl ReconnectConfig {
+
+    // ─── fn 'next_backoff_ms' ───
+    /// fn description
+    pub fn next_backoff_ms(&self, current_ms: u64) -> u64 {
+
+        // ─── define 'next' ───
+        let next = (current_ms.max(1) as f64 * self.backoff_multiplier.max(1.0)) as u64;
+
+        // ─── return 'u64' ───
+        next.min(self.max_backoff_ms)
+    }
+}
+
+// ─── struct 'WatchdogConfig' ───
+/// struct description
+#[derive(Debug, Clone)]
+pub struct WatchdogConfig {
+    pub enabled: bool,
+    pub ping_interval_ms: u64,
+    /// 既无更新也无 pong 的最长时间，应明显大于 ping_interval_ms
+    pub idle_timeout_ms: u64
+}
+
+// ─── impl 'Default for WatchdogConfig' ───
+/// impl description
+impl Default for WatchdogConfig {
+
+    // ─── fn 'default' ───
+    /// fn description
+    fn default() -> Self {
+
+        // ─── return 'Self' ───
+        Self {
+            enabled: true,
+            ping_interval_ms: DEFWATCHDOGPINGMS,
+            idle_timeout_ms: DEFWATCHDOGIDLEMS
+        }
+    }
+}
+
+// ─── struct 'CaptureConfig' ───
+/// struct description
+#[derive(Debug, Clone)]
+pub struct CaptureConfig {
+    pub path: Option<String>,
+    pub compression_level: i32,
+    pub queue_size: usize
+}
+
+// ─── impl 'Default for CaptureConfig' ───
+/// impl description
+impl Default for CaptureConfig {
+
+    // ─── fn 'default' ───
+    /// fn description
+    fn default() -> Self {
+
+        // ─── return 'Self' ───
+        Self {
+            path: None,
+            compression_level: DEFCAPTURELEVEL,
+            queue_size: DEFCAPTUREQUEUE
+        }
+    }
+}
+
+// ─── struct 'CommitmentTrackingConfig' ───
+/// struct description
+#[derive(Debug, Clone)]
+pub struct CommitmentTrackingConfig {
+    pub enabled: bool,
+    pub max_tracked_slots: usize,
+    pub rollback_orphaned_on_finalized: bool
+}
+
+// ─── impl 'Default for CommitmentTrackingConfig' ───
+/// impl description
+impl Default for CommitmentTrackingConfig {
+
+    // ─── fn 'default' ───
+    /// fn description
+    fn default() -> Self {
+
+        // ─── return 'Self' ───
+        Self {
+            enabled: false,
+            max_tracked_slots: DEFCOMMITTRACKSLOTS,
+            rollback_orphaned_on_finalized: true
         }
     }
 }
 
+// ─── struct 'PrometheusConfig' ───
+/// struct description
+#[derive(Debug, Clone, Default)]
+pub struct PrometheusConfig {
+    pub bind: Option<String>
+}
+
 // ─── struct 'StreamClientConfig' ───
 /// struct description
 #[derive(Debug, Clone)]
@@ -109,6 +372,11 @@ pub struct StreamClientConfig {
     pub connection: ConnectionConfig,
     pub batch: BatchConfig,
     pub backpressure: BackpressureConfig,
+    pub reconnect: ReconnectConfig,
+    pub watchdog: WatchdogConfig,
+    pub commitment_tracking: CommitmentTrackingConfig,
+    pub capture: CaptureConfig,
+    pub prometheus: PrometheusConfig,
     pub enable_metrics: bool,
     pub processor_concurrency: Option<usize>
 }
@@ -126,6 +394,11 @@ impl Default for StreamClientConfig {
             connection: ConnectionConfig::default(),
             batch: BatchConfig::default(),
             backpressure: BackpressureConfig::default(),
+            reconnect: ReconnectConfig::default(),
+            watchdog: WatchdogConfig::default(),
+            commitment_tracking: CommitmentTrackingConfig::default(),
+            capture: CaptureConfig::default(),
+            prometheus: PrometheusConfig::default(),
             enable_metrics: false,
             processor_concurrency: None
         }
@@ -154,7 +427,13 @@ impl StreamClientConfig {
                     max_attempts: DEFRETRYATTEMPTS,
                     wait_ms: DEFRETRYWAITMS,
                 },
+                critical_event_types: CRITICAL_EVENT_TYPES.to_vec(),
             },
+            reconnect: ReconnectConfig::default(),
+            watchdog: WatchdogConfig::default(),
+            commitment_tracking: CommitmentTrackingConfig::default(),
+            capture: CaptureConfig::default(),
+            prometheus: PrometheusConfig::default(),
             enable_metrics: false,
             processor_concurrency: None
         }
@@ -178,7 +457,13 @@ impl StreamClientConfig {
                     max_attempts: DEFRETRYATTEMPTS,
                     wait_ms: DEFRETRYWAITMS,
                 },
+                critical_event_types: CRITICAL_EVENT_TYPES.to_vec(),
             },
+            reconnect: ReconnectConfig::default(),
+            watchdog: WatchdogConfig::default(),
+            commitment_tracking: CommitmentTrackingConfig::default(),
+            capture: CaptureConfig::default(),
+            prometheus: PrometheusConfig::default(),
             enable_metrics: false,
             processor_concurrency: None
         }
@@ -195,7 +480,13 @@ impl StreamClientConfig {
             backpressure: BackpressureConfig {
                 channel_size: DEFLBCHANNELSIZE,
                 strategy: BackpressureStrategy::Block,
+                critical_event_types: CRITICAL_EVENT_TYPES.to_vec(),
             },
+            reconnect: ReconnectConfig::default(),
+            watchdog: WatchdogConfig::default(),
+            commitment_tracking: CommitmentTrackingConfig::default(),
+            capture: CaptureConfig::default(),
+            prometheus: PrometheusConfig::default(),
             enable_metrics: false,
             processor_concurrency: None
         }
@@ -0,0 +1,176 @@
+// ─── import packages ───
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+// ─── struct 'StreamHealth' ───
+/// struct description
+#[derive(Debug, Clone, Copy)]
+pub struct StreamHealth {
+    pub healthy: bool,
+    pub connected: bool,
+    pub last_message_age_ms: Option<u64>,
+    pub last_pong_rtt_ms: Option<f64>,
+    pub pings_sent: u64,
+    pub pongs_received: u64,
+    pub stalls: u64
+}
+
+// ─── struct 'StreamLiveness' ───
+/// struct description
+pub struct StreamLiveness {
+    started: Instant,
+    connected_readers: AtomicUsize,
+    last_message_us: AtomicU64,
+    last_pong_us: AtomicU64,
+    last_pong_rtt_us: AtomicU64,
+    pings_sent: AtomicU64,
+    pongs_received: AtomicU64,
+    stalls: AtomicU64
+}
+
+// ─── impl 'Default for StreamLiveness' ───
+/// impl description
+impl Default for StreamLiveness {
+
+    // ─── fn 'default' ───
+    /// fn description
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ─── impl 'StreamLiveness' ───
+/// impl description
+impl StreamLiveness {
+
+    // ─── fn 'new' ───
+    /// fn description
+    pub fn new() -> Self {
+
+        // ─── return 'Self' ───
+        Self {
+            started: Instant::now(),
+            connected_readers: AtomicUsize::new(0),
+            last_message_us: AtomicU64::new(0),
+            last_pong_us: AtomicU64::new(0),
+            last_pong_rtt_us: AtomicU64::new(0),
+            pings_sent: AtomicU64::new(0),
+            pongs_received: AtomicU64::new(0),
+            stalls: AtomicU64::new(0)
+        }
+    }
+
+    // ─── fn 'reader_connected' ───
+    /// fn description
+    pub fn reader_connected(&self) {
+        self.connected_readers.fetch_add(1, Ordering::Relaxed);
+    }
+
+    // ─── fn 'reader_disconnected' ───
+    /// fn description
+    pub fn reader_disconnected(&self) {
+        let _ = self.connected_readers.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1));
+    }
+
+    // ─── fn 'record_message' ───
+    /// fn description
+    pub fn record_message(&self) {
+        self.last_message_us.store(self.elapsed_us().max(1), Ordering::Relaxed);
+    }
+
+    // ─── fn 'record_ping' ───
+    /// fn description
+    pub fn record_ping(&self) {
+        self.pings_sent.fetch_add(1, Ordering::Relaxed);
+    }
+
+    // ─── fn 'record_pong' ───
+    /// fn description
+    pub fn record_pong(&self, rtt: Duration) {
+        self.pongs_received.fetch_add(1, Ordering::Relaxed);
+        self.last_pong_us.store(self.elapsed_us().max(1), Ordering::Relaxed);
+        self.last_pong_rtt_us.store((rtt.as_micros() as u64).max(1), Ordering::Relaxed);
+    }
+
+    // ─── fn 'record_stall' ───
+    /// fn description
+    pub fn record_stall(&self) {
+        self.stalls.fetch_add(1, Ordering::Relaxed);
+    }
+
+    // ─── fn 'last_message_age' ───
+    /// fn description
+    pub fn last_message_age(&self) -> Option<Duration> {
+
+        // ─── define 'last' ───
+        let last = self.last_message_us.load(Ordering::Relaxed);
+
+        // ─── compare 'last' ───
+        if last == 0 {
+            return None;
+        }
+
+        // ─── return 'Option' ───
+        Some(Duration::from_micros(self.elapsed_us().saturating_sub(last)))
+    }
+
+    // ─── fn 'last_pong_age' ───
+    /// fn description
+    pub fn last_pong_age(&self) -> Option<Duration> {
+
+        // ─── define 'last' ───
+        let last = self.last_pong_us.load(Ordering::Relaxed);
+
+        // ─── return 'Option' ───
+        (last > 0).then(|| Duration::from_micros(self.elapsed_us().saturating_sub(last)))
+    }
+
+    // ─── fn 'last_pong_rtt' ───
+    /// fn description
+    pub fn last_pong_rtt(&self) -> Option<Duration> {
+
+        // ─── define 'rtt' ───
+        let rtt = self.last_pong_rtt_us.load(Ordering::Relaxed);
+
+        // ─── return 'Option' ───
+        (rtt > 0).then(|| Duration::from_micros(rtt))
+    }
+
+    // ─── fn 'health' ───
+    /// fn description
+    pub fn health(&self, idle_timeout: Duration) -> StreamHealth {
+
+        // ─── define 'age' ───
+        let age = self.last_message_age();
+
+        // ─── define 'connected' ───
+        let connected = self.connected_readers.load(Ordering::Relaxed) > 0;
+
+        // ─── define 'alive' ───
+        // 过滤条件稀疏时可能长时间没有更新，应答的 ping 同样说明连接存活
+        let alive = [age, self.last_pong_age()]
+            .into_iter()
+            .flatten()
+            .min()
+            .unwrap_or_else(|| self.started.elapsed()) < idle_timeout;
+
+        // ─── return 'StreamHealth' ───
+        StreamHealth {
+            healthy: connected && alive,
+            connected,
+            last_message_age_ms: age.map(|age| age.as_millis() as u64),
+            last_pong_rtt_ms: self.last_pong_rtt().map(|rtt| rtt.as_secs_f64() * 1_000.0),
+            pings_sent: self.pings_sent.load(Ordering::Relaxed),
+            pongs_received: self.pongs_received.load(Ordering::Relaxed),
+            stalls: self.stalls.load(Ordering::Relaxed)
+        }
+    }
+
+    // ─── fn 'elapsed_us' ───
+    /// fn description
+    fn elapsed_us(&self) -> u64 {
+
+        // ─── return 'u64' ───
+        self.started.elapsed().as_micros() as u64
+    }
+}
@@ -0,0 +1,283 @@
+// ─── import packages ───
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// ─── import crates ───
+use crate::streaming::events::common::types::EventTimings;
+
+// ─── const 'LINEAR_BUCKETS' ───
+/// const description
+const LINEAR_BUCKETS: u64 = 16;
+
+// ─── const 'SUB_BUCKET_BITS' ───
+/// const description
+const SUB_BUCKET_BITS: u32 = 3;
+
+// ─── const 'BUCKET_COUNT' ───
+/// const description
+const BUCKET_COUNT: usize = LINEAR_BUCKETS as usize + (64 - 4) * (1 << SUB_BUCKET_BITS);
+
+// ─── enum 'LatencyStage' ───
+/// enum description
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LatencyStage {
+    Provider,
+    Channel,
+    Parse,
+    Dispatch,
+    EndToEnd
+}
+
+// ─── impl 'LatencyStage' ───
+/// impl description
+impl LatencyStage {
+
+    // ─── const 'ALL' ───
+    pub const ALL: [LatencyStage; 5] = [
+        LatencyStage::Provider,
+        LatencyStage::Channel,
+        LatencyStage::Parse,
+        LatencyStage::Dispatch,
+        LatencyStage::EndToEnd
+    ];
+
+    // ─── fn 'name' ───
+    /// fn description
+    pub fn name(&self) -> &'static str {
+
+        // ─── match 'self' ───
+        match self {
+            LatencyStage::Provider => "provider",
+            LatencyStage::Channel => "channel",
+            LatencyStage::Parse => "parse",
+            LatencyStage::Dispatch => "dispatch",
+            LatencyStage::EndToEnd => "end_to_end"
+        }
+    }
+
+    // ─── fn 'measure' ───
+    /// fn description
+    pub fn measure(&self, timings: &EventTimings) -> Option<u64> {
+
+        // ─── match 'self' ───
+        match self {
+            LatencyStage::Provider => EventTimings::between(timings.created_at_us, timings.received_us),
+            LatencyStage::Channel => EventTimings::between(timings.received_us, timings.dequeued_us),
+            LatencyStage::Parse => EventTimings::between(timings.dequeued_us, timings.parsed_us),
+            LatencyStage::Dispatch => EventTimings::between(timings.parsed_us, timings.callback_us),
+            LatencyStage::EndToEnd => EventTimings::between(timings.created_at_us, timings.callback_us)
+        }
+    }
+
+    // ─── fn 'as_index' ───
+    /// fn description
+    fn as_index(&self) -> usize {
+
+        // ─── return 'usize' ───
+        *self as usize
+    }
+}
+
+// ─── struct 'LatencySummary' ───
+/// struct description
+#[derive(Debug, Clone, Copy)]
+pub struct LatencySummary {
+    pub stage: LatencyStage,
+    pub count: u64,
+    pub sum_us: u64,
+    pub p50_us: u64,
+    pub p90_us: u64,
+    pub p99_us: u64,
+    pub max_us: u64
+}
+
+// ─── impl 'LatencySummary' ───
+/// impl description
+impl LatencySummary {
+
+    // ─── fn 'mean_us' ───
+    /// fn description
+    pub fn mean_us(&self) -> f64 {
+
+        // ─── compare 'self.count' ───
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        // ─── return 'f64' ───
+        self.sum_us as f64 / self.count as f64
+    }
+}
+
+// ─── struct 'LatencyHistogram' ───
+/// struct description
+pub struct LatencyHistogram {
+    buckets: Box<[AtomicU64]>,
+    count: AtomicU64,
+    sum: AtomicU64,
+    max: AtomicU64
+}
+
+// ─── impl 'Default for LatencyHistogram' ───
+/// impl description
+impl Default for LatencyHistogram {
+
+    // ─── fn 'default' ───
+    /// fn description
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ─── impl 'LatencyHistogram' ───
+/// impl description
+impl LatencyHistogram {
+
+    // ─── fn 'new' ───
+    /// fn description
+    pub fn new() -> Self {
+
+        // ─── return 'Self' ───
+        Self { buckets: (0..BUCKET_COUNT).map(|_| AtomicU64::new(0)).collect(), count: AtomicU64::new(0),
+            sum: AtomicU64::new(0), max: AtomicU64::new(0) }
+    }
+
+    // ─── fn 'record' ───
+    /// fn description
+    pub fn record(&self, value_us: u64) {
+        self.buckets[Self::bucket_index(value_us)].fetch_add(1, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum.fetch_add(value_us, Ordering::Relaxed);
+        self.max.fetch_max(value_us, Ordering::Relaxed);
+    }
+
+    // ─── fn 'quantile' ───
+    /// fn description
+    pub fn quantile(&self, quantile: f64) -> u64 {
+
+        // ─── define 'count' ───
+        let count = self.count.load(Ordering::Relaxed);
+
+        // ─── compare 'count' ───
+        if count == 0 {
+            return 0;
+        }
+
+        // ─── define 'target' ───
+        let target = ((quantile.clamp(0.0, 1.0) * count as f64).ceil() as u64).max(1);
+
+        // ─── define 'seen' ───
+        let mut seen = 0u64;
+
+        // ─── proceed 'for' ───
+        for (index, bucket) in self.buckets.iter().enumerate() {
+            seen += bucket.load(Ordering::Relaxed);
+
+            // ─── compare 'seen' ───
+            if seen >= target {
+                return Self::bucket_upper(index).min(self.max.load(Ordering::Relaxed));
+            }
+        }
+
+        // ─── return 'u64' ───
+        self.max.load(Ordering::Relaxed)
+    }
+
+    // ─── fn 'summary' ───
+    /// fn description
+    pub fn summary(&self, stage: LatencyStage) -> LatencySummary {
+
+        // ─── return 'LatencySummary' ───
+        LatencySummary {
+            stage,
+            count: self.count.load(Ordering::Relaxed),
+            sum_us: self.sum.load(Ordering::Relaxed),
+            p50_us: self.quantile(0.50),
+            p90_us: self.quantile(0.90),
+            p99_us: self.quantile(0.99),
+            max_us: self.max.load(Ordering::Relaxed)
+        }
+    }
+
+    // ─── fn 'bucket_index' ───
+    /// fn description
+    fn bucket_index(value: u64) -> usize {
+
+        // ─── compare 'value' ───
+        if value < LINEAR_BUCKETS {
+            return value as usize;
+        }
+
+        // ─── define 'exponent' ───
+        let exponent = 63 - value.leading_zeros();
+
+        // ─── define 'mantissa' ───
+        let mantissa = (value >> (exponent - SUB_BUCKET_BITS)) & ((1 << SUB_BUCKET_BITS) - 1);
+
+        // ─── return 'usize' ───
+        LINEAR_BUCKETS as usize + ((exponent - 4) << SUB_BUCKET_BITS) as usize + mantissa as usize
+    }
+
+    // ─── fn 'bucket_upper' ───
+    /// fn description
+    fn bucket_upper(index: usize) -> u64 {
+
+        // ─── compare 'index' ───
+        if (index as u64) < LINEAR_BUCKETS {
+            return index as u64;
+        }
+
+        // ─── define 'offset' ───
+        let offset = index - LINEAR_BUCKETS as usize;
+
+        // ─── define 'exponent' ───
+        let exponent = (offset >> SUB_BUCKET_BITS) as u32 + 4;
+
+        // ─── define 'mantissa' ───
+        let mantissa = (offset & ((1 << SUB_BUCKET_BITS) - 1)) as u64;
+
+        // ─── return 'u64' ───
+        ((1u64 << SUB_BUCKET_BITS) + mantissa + 1).saturating_mul(1u64 << (exponent - SUB_BUCKET_BITS)).saturating_sub(1)
+    }
+}
+
+// ─── struct 'LatencyHistograms' ───
+/// struct description
+#[derive(Default)]
+pub struct LatencyHistograms {
+    stages: [LatencyHistogram; 5]
+}
+
+// ─── impl 'LatencyHistograms' ───
+/// impl description
+impl LatencyHistograms {
+
+    // ─── fn 'record' ───
+    /// fn description
+    pub fn record(&self, timings: &EventTimings) {
+
+        // ─── proceed 'for' ───
+        for stage in LatencyStage::ALL {
+
+            // ─── compare 'stage.measure()' ───
+            if let Some(value_us) = stage.measure(timings) {
+                self.stages[stage.as_index()].record(value_us);
+            }
+        }
+    }
+
+    // ─── fn 'summary' ───
+    /// fn description
+    pub fn summary(&self, stage: LatencyStage) -> LatencySummary {
+
+        // ─── return 'LatencySummary' ───
+        self.stages[stage.as_index()].summary(stage)
+    }
+
+    // ─── fn 'summaries' ───
+    /// fn description
+    pub fn summaries(&self) -> Vec<LatencySummary> {
+
+        // ─── return 'Vec' ───
+        LatencyStage::ALL.iter().map(|stage| self.summary(*stage)).collect()
+    }
+}
@@ -1,10 +1,18 @@
 // ─── import packages ───
-use std::sync::Arc;
+use dashmap::DashMap;
+use std::sync::{Arc, RwLock};
 use tokio::sync::Mutex;
 
 // ─── import crates ───
 use crate::globals::constants::*;
 use crate::streaming::common::config::StreamClientConfig;
+use crate::streaming::common::health::{StreamHealth, StreamLiveness};
+use crate::streaming::common::latency::{LatencyHistograms, LatencySummary};
+use crate::streaming::events::common::types::{EventTimings, EventType};
+
+// ─── type 'ChannelProbe' ───
+/// type description
+pub type ChannelProbe = Arc<dyn Fn() -> (usize, usize) + Send + Sync>;
 
 // ─── struct 'EventMetrics' ───
 /// struct description
@@ -30,15 +38,59 @@ impl EventMetrics {
     }
 }
 
+// ─── struct 'ConnectionMetrics' ───
+/// struct description
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionMetrics {
+    pub reconnect_count: u64,
+    pub last_gap_slots: u64,
+    pub total_gap_slots: u64,
+    pub max_gap_slots: u64,
+    pub last_downtime_ms: u64,
+    pub total_downtime_ms: u64,
+    pub duplicates_dropped: u64
+}
+
+// ─── struct 'BackpressureMetrics' ───
+/// struct description
+#[derive(Debug, Clone, Default)]
+pub struct BackpressureMetrics {
+    pub dropped: [u64; 4],
+    pub coalesced_accounts: u64
+}
+
+// ─── impl 'BackpressureMetrics' ───
+/// impl description
+impl BackpressureMetrics {
+
+    // ─── fn 'dropped' ───
+    /// fn description
+    pub fn dropped(&self, event_type: MetricsEventType) -> u64 {
+
+        // ─── return 'u64' ───
+        self.dropped[event_type.as_index()]
+    }
+
+    // ─── fn 'total_dropped' ───
+    /// fn description
+    pub fn total_dropped(&self) -> u64 {
+
+        // ─── return 'u64' ───
+        self.dropped.iter().sum()
+    }
+}
+
 // ─── struct 'PerformanceMetrics' ───
 /// struct description
 #[derive(Debug, Clone)]
 pub struct PerformanceMetrics {
     pub start_time: std::time::Instant,
-    pub event_metrics: [EventMetrics; 3],
+    pub event_metrics: [EventMetrics; 4],
     pub average_processing_time_ms: f64,
     pub min_processing_time_ms: f64,
     pub max_processing_time_ms: f64,
+    pub connection: ConnectionMetrics,
+    pub backpressure: BackpressureMetrics,
     pub last_update_time: std::time::Instant
 }
 
@@ -59,7 +111,8 @@ impl Default for PerformanceMetrics {
 pub enum MetricsEventType {
     Tx,
     Account,
-    BlockMeta
+    BlockMeta,
+    Slot
 }
 
 // ─── impl 'MetricsEventType' ───
@@ -74,15 +127,17 @@ impl MetricsEventType {
         match self {
             MetricsEventType::Tx => 0,
             MetricsEventType::Account => 1,
-            MetricsEventType::BlockMeta => 2
+            MetricsEventType::BlockMeta => 2,
+            MetricsEventType::Slot => 3
         }
     }
 
     // ─── const 'ALL' ───
-    const ALL: [MetricsEventType; 3] = [
+    const ALL: [MetricsEventType; 4] = [
         MetricsEventType::Tx,
         MetricsEventType::Account,
-        MetricsEventType::BlockMeta
+        MetricsEventType::BlockMeta,
+        MetricsEventType::Slot
     ];
 }
 
@@ -98,8 +153,9 @@ impl PerformanceMetrics {
         let now = std::time::Instant::now();
 
         // ─── return 'Self' ───
-        Self { start_time: now, event_metrics: [EventMetrics::new(now), EventMetrics::new(now), EventMetrics::new(now)],
-            average_processing_time_ms: 0.0, min_processing_time_ms: 0.0, max_processing_time_ms: 0.0, last_update_time: now }
+        Self { start_time: now, event_metrics: [EventMetrics::new(now), EventMetrics::new(now), EventMetrics::new(now), EventMetrics::new(now)],
+            average_processing_time_ms: 0.0, min_processing_time_ms: 0.0, max_processing_time_ms: 0.0,
+            connection: ConnectionMetrics::default(), backpressure: BackpressureMetrics::default(), last_update_time: now }
     }
 
     // ─── fn 'update_window_metrics' ───
@@ -169,7 +225,12 @@ enum MetricsMsg {
         event_type: MetricsEventType,
         events_processed: u64,
         processing_time_ms: f64
-    }
+    },
+    Reconnect { downtime_ms: u64 },
+    SlotGap { gap_slots: u64 },
+    Duplicate,
+    PriorityDrop { event_type: MetricsEventType },
+    Coalesced
 }
 
 // ─── struct 'MetricsManager' ───
@@ -178,7 +239,11 @@ pub struct MetricsManager {
     metrics: Arc<Mutex<PerformanceMetrics>>,
     config: Arc<StreamClientConfig>,
     stream_name: String,
-    tx: tokio::sync::mpsc::Sender<MetricsMsg>
+    tx: tokio::sync::mpsc::Sender<MetricsMsg>,
+    parsed_events: Arc<DashMap<EventType, u64>>,
+    channel_probe: Arc<RwLock<Option<ChannelProbe>>>,
+    latency: Arc<LatencyHistograms>,
+    liveness: Arc<StreamLiveness>
 }
 
 // ─── struct 'MetricsManager' ───
@@ -193,10 +258,12 @@ impl MetricsManager {
         let (tx, rx) = tokio::sync::mpsc::channel::<MetricsMsg>(METRICSCHANNELBOUND);
 
         // ─── define 'manager' ───
-        let manager = Self { metrics: metrics.clone(), config: config.clone(), stream_name, tx };
+        let manager = Self { metrics: metrics.clone(), config: config.clone(), stream_name, tx,
+            parsed_events: Arc::new(DashMap::new()), channel_probe: Arc::new(RwLock::new(None)), latency: Arc::new(LatencyHistograms::default()),
+            liveness: Arc::new(StreamLiveness::new()) };
 
-        // ─── compare 'config.enable_metrics' ───
-        if config.enable_metrics {
+        // ─── compare 'manager.collecting()' ───
+        if manager.collecting() {
             tokio::spawn(Self::run_aggregator(metrics, rx, std::time::Duration::from_millis(METRICSFLUSHINT), std::time::Duration::from_secs(DEFMETRICSWINSEC)));
         }
 
@@ -278,6 +345,31 @@ impl MetricsManager {
                                     };
                                 }
                             }
+                            MetricsMsg::Reconnect { downtime_ms } => {
+                                m.connection.reconnect_count += 1;
+                                m.connection.last_downtime_ms = downtime_ms;
+                                m.connection.total_downtime_ms += downtime_ms;
+                                m.last_update_time = now;
+                            }
+                            MetricsMsg::SlotGap { gap_slots } => {
+                                m.connection.last_gap_slots = gap_slots;
+                                m.connection.total_gap_slots += gap_slots;
+
+                                // ─── compare 'gap_slots' ───
+                                if gap_slots > m.connection.max_gap_slots {
+                                    m.connection.max_gap_slots = gap_slots;
+                                }
+                                m.last_update_time = now;
+                            }
+                            MetricsMsg::Duplicate => {
+                                m.connection.duplicates_dropped += 1;
+                            }
+                            MetricsMsg::PriorityDrop { event_type } => {
+                                m.backpressure.dropped[event_type.as_index()] += 1;
+                            }
+                            MetricsMsg::Coalesced => {
+                                m.backpressure.coalesced_accounts += 1;
+                            }
                         }
                     } else {
                         // ─── proceed 'break' ───
@@ -307,6 +399,22 @@ impl MetricsManager {
         }
     }
 
+    // ─── fn 'collecting' ───
+    /// fn description
+    pub fn collecting(&self) -> bool {
+
+        // ─── return 'bool' ───
+        self.config.enable_metrics || self.config.prometheus.bind.is_some()
+    }
+
+    // ─── fn 'stream_name' ───
+    /// fn description
+    pub fn stream_name(&self) -> &str {
+
+        // ─── return 'str' ───
+        &self.stream_name
+    }
+
     // ─── fn 'get_metrics' ───
     /// fn description
     pub async fn get_metrics(&self) -> PerformanceMetrics {
@@ -323,11 +431,14 @@ impl MetricsManager {
         // ─── define 'metrics' ───
         let metrics = self.get_metrics().await;
 
+        // ─── define 'health' ───
+        let health = self.health();
+
         // ─── define 'event_names' ───
-        let event_names = ["TX", "Account", "Block Meta"];
+        let event_names = ["TX", "Account", "Block Meta", "Slot"];
 
         // ─── define 'event_types' ───
-        let event_types = [MetricsEventType::Tx, MetricsEventType::Account, MetricsEventType::BlockMeta];
+        let event_types = MetricsEventType::ALL;
 
         // ─── define 'now' ───
         let now = std::time::Instant::now();
@@ -358,6 +469,43 @@ impl MetricsManager {
         println!("│ Minimum             │ {:9.2}   │", metrics.min_processing_time_ms);
         println!("│ Maximum             │ {:9.2}   │", metrics.max_processing_time_ms);
         println!("└─────────────────────┴─────────────┘");
+        println!("\nConnection Statistics");
+        println!("┌─────────────────────┬─────────────┐");
+        println!("│ Metric              │ Value       │");
+        println!("├─────────────────────┼─────────────┤");
+        println!("│ Reconnects          │ {:11} │", metrics.connection.reconnect_count);
+        println!("│ Last Gap (slots)    │ {:11} │", metrics.connection.last_gap_slots);
+        println!("│ Max Gap (slots)     │ {:11} │", metrics.connection.max_gap_slots);
+        println!("│ Last Downtime (ms)  │ {:11} │", metrics.connection.last_downtime_ms);
+        println!("│ Total Downtime (ms) │ {:11} │", metrics.connection.total_downtime_ms);
+        println!("│ Duplicates Dropped  │ {:11} │", metrics.connection.duplicates_dropped);
+        println!("│ Last Message (ms)   │ {:>11} │", health.last_message_age_ms.map_or("-".to_string(), |age| age.to_string()));
+        println!("│ Pong RTT (ms)       │ {:>11} │", health.last_pong_rtt_ms.map_or("-".to_string(), |rtt| format!("{rtt:.2}")));
+        println!("│ Stalls              │ {:11} │", health.stalls);
+        println!("└─────────────────────┴─────────────┘");
+        println!("\nBackpressure Statistics");
+        println!("┌─────────────────────┬─────────────┐");
+        println!("│ Metric              │ Value       │");
+        println!("├─────────────────────┼─────────────┤");
+
+        // ─── proceed 'for' ───
+        for (i, name) in event_names.iter().enumerate() {
+            println!("│ Dropped {:11} │ {:11} │", name, metrics.backpressure.dropped[i]);
+        }
+        println!("│ Coalesced Accounts  │ {:11} │", metrics.backpressure.coalesced_accounts);
+        println!("└─────────────────────┴─────────────┘");
+        println!("\nLatency Statistics (ms)");
+        println!("┌─────────────┬────────────┬──────────┬──────────┬──────────┬──────────┐");
+        println!("│ Stage       │ Samples    │ p50      │ p90      │ p99      │ Max      │");
+        println!("├─────────────┼────────────┼──────────┼──────────┼──────────┼──────────┤");
+
+        // ─── proceed 'for' ───
+        for summary in self.latency_summaries() {
+            println!("│ {:11} │ {:10} │ {:8.2} │ {:8.2} │ {:8.2} │ {:8.2} │", summary.stage.name(), summary.count,
+                summary.p50_us as f64 / 1_000.0, summary.p90_us as f64 / 1_000.0, summary.p99_us as f64 / 1_000.0,
+                summary.max_us as f64 / 1_000.0);
+        }
+        println!("└─────────────┴────────────┴──────────┴──────────┴──────────┴──────────┘");
         println!();
     }
 
@@ -389,8 +537,8 @@ impl MetricsManager {
     /// fn description
     pub async fn add_process_count(&self, event_type: MetricsEventType) {
 
-        // ─── compare 'self.config.enable_metrics' ───
-        if !self.config.enable_metrics {
+        // ─── compare 'self.collecting()' ───
+        if !self.collecting() {
             return;
         }
 
@@ -422,12 +570,20 @@ impl MetricsManager {
         self.add_process_count(MetricsEventType::BlockMeta).await;
     }
 
+    // ─── fn 'add_slot_process_count' ───
+    /// fn description
+    pub async fn add_slot_process_count(&self) {
+
+        // ─── return 'self.add_process_count()' ───
+        self.add_process_count(MetricsEventType::Slot).await;
+    }
+
     // ─── fn 'add_process_count' ───
     /// fn description
     pub async fn update_metrics(&self, event_type: MetricsEventType, events_processed: u64, processing_time_ms: f64) {
 
-        // ─── compare 'self.config.enable_metrics' ───
-        if !self.config.enable_metrics {
+        // ─── compare 'self.collecting()' ───
+        if !self.collecting() {
             return;
         }
 
@@ -439,6 +595,146 @@ impl MetricsManager {
         });
     }
 
+    // ─── fn 'record_reconnect' ───
+    /// fn description
+    pub async fn record_reconnect(&self, downtime_ms: u64) {
+
+        // ─── compare 'self.collecting()' ───
+        if !self.collecting() {
+            return;
+        }
+
+        // ─── callback 'self.tx.try_send()' ───
+        let _ = self.tx.try_send(MetricsMsg::Reconnect { downtime_ms });
+    }
+
+    // ─── fn 'record_slot_gap' ───
+    /// fn description
+    pub async fn record_slot_gap(&self, gap_slots: u64) {
+
+        // ─── compare 'self.collecting()' ───
+        if !self.collecting() {
+            return;
+        }
+
+        // ─── callback 'self.tx.try_send()' ───
+        let _ = self.tx.try_send(MetricsMsg::SlotGap { gap_slots });
+    }
+
+    // ─── fn 'add_duplicate_count' ───
+    /// fn description
+    pub async fn add_duplicate_count(&self) {
+
+        // ─── compare 'self.collecting()' ───
+        if !self.collecting() {
+            return;
+        }
+
+        // ─── callback 'self.tx.try_send()' ───
+        let _ = self.tx.try_send(MetricsMsg::Duplicate);
+    }
+
+    // ─── fn 'record_priority_drop' ───
+    /// fn description
+    pub fn record_priority_drop(&self, event_type: MetricsEventType) {
+
+        // ─── compare 'self.collecting()' ───
+        if !self.collecting() {
+            return;
+        }
+
+        // ─── callback 'self.tx.try_send()' ───
+        let _ = self.tx.try_send(MetricsMsg::PriorityDrop { event_type });
+    }
+
+    // ─── fn 'add_coalesced_count' ───
+    /// fn description
+    pub fn add_coalesced_count(&self) {
+
+        // ─── compare 'self.collecting()' ───
+        if !self.collecting() {
+            return;
+        }
+
+        // ─── callback 'self.tx.try_send()' ───
+        let _ = self.tx.try_send(MetricsMsg::Coalesced);
+    }
+
+    // ─── fn 'record_parsed_event' ───
+    /// fn description
+    pub fn record_parsed_event(&self, event_type: EventType) {
+
+        // ─── compare 'self.collecting()' ───
+        if !self.collecting() {
+            return;
+        }
+        *self.parsed_events.entry(event_type).or_insert(0) += 1;
+    }
+
+    // ─── fn 'parsed_events' ───
+    /// fn description
+    pub fn parsed_events(&self) -> Vec<(EventType, u64)> {
+
+        // ─── return 'Vec' ───
+        self.parsed_events.iter().map(|entry| (entry.key().clone(), *entry.value())).collect()
+    }
+
+    // ─── fn 'record_latency' ───
+    /// fn description
+    pub fn record_latency(&self, timings: &EventTimings) {
+
+        // ─── compare 'self.collecting()' ───
+        if !self.collecting() {
+            return;
+        }
+        self.latency.record(timings);
+    }
+
+    // ─── fn 'latency_summaries' ───
+    /// fn description
+    pub fn latency_summaries(&self) -> Vec<LatencySummary> {
+
+        // ─── return 'Vec' ───
+        self.latency.summaries()
+    }
+
+    // ─── fn 'set_channel_probe' ───
+    /// fn description
+    pub fn set_channel_probe(&self, probe: ChannelProbe) {
+
+        // ─── compare 'self.channel_probe.write()' ───
+        if let Ok(mut guard) = self.channel_probe.write() {
+            *guard = Some(probe);
+        }
+    }
+
+    // ─── fn 'channel_depth' ───
+    /// fn description
+    pub fn channel_depth(&self) -> Option<(usize, usize)> {
+
+        // ─── define 'probe' ───
+        let probe = self.channel_probe.read().ok().and_then(|guard| guard.clone());
+
+        // ─── return 'Option' ───
+        probe.map(|probe| probe())
+    }
+
+    // ─── fn 'liveness' ───
+    /// fn description
+    pub fn liveness(&self) -> &Arc<StreamLiveness> {
+
+        // ─── return 'Arc' ───
+        &self.liveness
+    }
+
+    // ─── fn 'health' ───
+    /// fn description
+    pub fn health(&self) -> StreamHealth {
+
+        // ─── return 'StreamHealth' ───
+        self.liveness.health(std::time::Duration::from_millis(self.config.watchdog.idle_timeout_ms))
+    }
+
     // ─── fn 'log_slow_processing' ───
     /// fn description
     pub fn log_slow_processing(&self, processing_time_ms: f64, event_count: usize) {
@@ -459,6 +755,8 @@ impl Clone for MetricsManager {
     fn clone(&self) -> Self {
 
         // ─── return 'Self' ───
-        Self { metrics: self.metrics.clone(), config: self.config.clone(), stream_name: self.stream_name.clone(), tx: self.tx.clone() }
+        Self { metrics: self.metrics.clone(), config: self.config.clone(), stream_name: self.stream_name.clone(), tx: self.tx.clone(),
+            parsed_events: self.parsed_events.clone(), channel_probe: self.channel_probe.clone(), latency: self.latency.clone(),
+            liveness: self.liveness.clone() }
     }
 }
\ No newline at end of file
@@ -6,10 +6,22 @@ pub mod batch;
 /// mod description
 pub mod config;
 
+// ─── mod 'health' ───
+/// mod description
+pub mod health;
+
+// ─── mod 'latency' ───
+/// mod description
+pub mod latency;
+
 // ─── mod 'metrics' ───
 /// mod description
 pub mod metrics;
 
+// ─── mod 'prometheus' ───
+/// mod description
+pub mod prometheus;
+
 // ─── mod 'subscription' ───
 /// mod description
 pub mod subscription;
@@ -22,10 +34,22 @@ pub use batch::*;
 /// use description
 pub use config::*;
 
+// ─── use 'health' ───
+/// use description
+pub use health::*;
+
+// ─── use 'latency' ───
+/// use description
+pub use latency::*;
+
 // ─── use 'metrics' ───
 /// use description
 pub use metrics::*;
 
+// ─── use 'prometheus' ───
+/// use description
+pub use prometheus::*;
+
 // ─── use 'subscription' ───
 /// use description
 pub use subscription::*;
\ No newline at end of file
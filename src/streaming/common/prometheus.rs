@@ -0,0 +1,356 @@
+// ─── import packages ───
+use futures::future::BoxFuture;
+use std::fmt::Write;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+// ─── import crates ───
+use crate::common::AnyResult;
+use crate::globals::constants::*;
+use crate::streaming::common::metrics::MetricsManager;
+
+// ─── type 'PrometheusCollector' ───
+/// type description
+pub type PrometheusCollector = Arc<dyn Fn() -> BoxFuture<'static, String> + Send + Sync>;
+
+// ─── const 'KIND_LABELS' ───
+/// const description
+const KIND_LABELS: [&str; 4] = ["tx", "account", "block_meta", "slot"];
+
+// ─── struct 'PrometheusText' ───
+/// struct description
+#[derive(Debug, Default)]
+pub struct PrometheusText {
+    body: String
+}
+
+// ─── impl 'PrometheusText' ───
+/// impl description
+impl PrometheusText {
+
+    // ─── fn 'new' ───
+    /// fn description
+    pub fn new() -> Self {
+
+        // ─── return 'Self' ───
+        Self::default()
+    }
+
+    // ─── fn 'metric' ───
+    /// fn description
+    pub fn metric(&mut self, name: &str, kind: &str, help: &str, samples: &[(String, f64)]) -> &mut Self {
+        let _ = writeln!(self.body, "# HELP {name} {help}");
+        let _ = writeln!(self.body, "# TYPE {name} {kind}");
+
+        // ─── proceed 'for' ───
+        for (labels, value) in samples {
+
+            // ─── compare 'labels' ───
+            if labels.is_empty() {
+                let _ = writeln!(self.body, "{name} {value}");
+            } else {
+                let _ = writeln!(self.body, "{name}{{{labels}}} {value}");
+            }
+        }
+
+        // ─── return 'Self' ───
+        self
+    }
+
+    // ─── fn 'samples' ───
+    /// fn description
+    pub fn samples(&mut self, name: &str, samples: &[(String, f64)]) -> &mut Self {
+
+        // ─── proceed 'for' ───
+        for (labels, value) in samples {
+            let _ = writeln!(self.body, "{name}{{{labels}}} {value}");
+        }
+
+        // ─── return 'Self' ───
+        self
+    }
+
+    // ─── fn 'counter' ───
+    /// fn description
+    pub fn counter(&mut self, name: &str, help: &str, value: f64) -> &mut Self {
+
+        // ─── return 'Self' ───
+        self.metric(name, "counter", help, &[(String::new(), value)])
+    }
+
+    // ─── fn 'gauge' ───
+    /// fn description
+    pub fn gauge(&mut self, name: &str, help: &str, value: f64) -> &mut Self {
+
+        // ─── return 'Self' ───
+        self.metric(name, "gauge", help, &[(String::new(), value)])
+    }
+
+    // ─── fn 'label' ───
+    /// fn description
+    pub fn label(key: &str, value: &str) -> String {
+
+        // ─── return 'String' ───
+        format!("{key}=\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+    }
+
+    // ─── fn 'finish' ───
+    /// fn description
+    pub fn finish(self) -> String {
+
+        // ─── return 'String' ───
+        self.body
+    }
+}
+
+// ─── struct 'PrometheusExporter' ───
+/// struct description
+#[derive(Clone)]
+pub struct PrometheusExporter {
+    metrics_manager: MetricsManager,
+    collectors: Vec<PrometheusCollector>
+}
+
+// ─── impl 'PrometheusExporter' ───
+/// impl description
+impl PrometheusExporter {
+
+    // ─── fn 'new' ───
+    /// fn description
+    pub fn new(metrics_manager: MetricsManager) -> Self {
+
+        // ─── return 'Self' ───
+        Self { metrics_manager, collectors: Vec::new() }
+    }
+
+    // ─── fn 'with_collector' ───
+    /// fn description
+    pub fn with_collector<F, Fut>(mut self, collector: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = String> + Send + 'static,
+    {
+        self.collectors.push(Arc::new(move || Box::pin(collector())));
+
+        // ─── return 'Self' ───
+        self
+    }
+
+    // ─── fn 'render' ───
+    /// fn description
+    pub async fn render(&self) -> String {
+
+        // ─── define 'metrics' ───
+        let metrics = self.metrics_manager.get_metrics().await;
+
+        // ─── define 'text' ───
+        let mut text = PrometheusText::new();
+
+        // ─── define 'kinds' ───
+        let kinds = |values: &dyn Fn(usize) -> f64| -> Vec<(String, f64)> {
+            KIND_LABELS.iter().enumerate().map(|(i, kind)| (PrometheusText::label("kind", kind), values(i))).collect()
+        };
+
+        text.gauge("ghostreaver_up", "Exporter is serving", 1.0)
+            .gauge("ghostreaver_uptime_seconds", "Seconds since the metrics were created", metrics.start_time.elapsed().as_secs_f64())
+            .gauge("ghostreaver_last_update_age_seconds", "Seconds since the pipeline last recorded activity", metrics.last_update_time.elapsed().as_secs_f64())
+            .metric("ghostreaver_messages_received_total", "counter", "Geyser messages handed to the processor",
+                &kinds(&|i| metrics.event_metrics[i].process_count as f64))
+            .metric("ghostreaver_events_processed_total", "counter", "Events produced by the processor",
+                &kinds(&|i| metrics.event_metrics[i].events_processed as f64))
+            .metric("ghostreaver_events_per_second", "gauge", "Events per second over the last metrics window",
+                &kinds(&|i| metrics.event_metrics[i].events_per_second))
+            .metric("ghostreaver_processing_time_ms", "gauge", "Per-message processing time", &[
+                (PrometheusText::label("stat", "avg"), metrics.average_processing_time_ms),
+                (PrometheusText::label("stat", "min"), metrics.min_processing_time_ms),
+                (PrometheusText::label("stat", "max"), metrics.max_processing_time_ms),
+            ])
+            .counter("ghostreaver_reconnects_total", "Stream reconnects", metrics.connection.reconnect_count as f64)
+            .gauge("ghostreaver_last_gap_slots", "Slots skipped by the last reconnect", metrics.connection.last_gap_slots as f64)
+            .gauge("ghostreaver_max_gap_slots", "Largest slot gap after a reconnect", metrics.connection.max_gap_slots as f64)
+            .counter("ghostreaver_downtime_ms_total", "Milliseconds spent reconnecting", metrics.connection.total_downtime_ms as f64)
+            .counter("ghostreaver_duplicates_dropped_total", "Duplicate updates dropped", metrics.connection.duplicates_dropped as f64)
+            .metric("ghostreaver_backpressure_dropped_total", "counter", "Updates dropped by priority backpressure",
+                &kinds(&|i| metrics.backpressure.dropped[i] as f64))
+            .counter("ghostreaver_backpressure_coalesced_total", "Account updates replaced by a newer update",
+                metrics.backpressure.coalesced_accounts as f64);
+
+        // ─── define 'health' ───
+        let health = self.metrics_manager.health();
+        text.gauge("ghostreaver_healthy", "1 when a reader is connected and updates arrive within the idle timeout", if health.healthy { 1.0 } else { 0.0 })
+            .gauge("ghostreaver_connected", "1 when at least one Geyser reader is connected", if health.connected { 1.0 } else { 0.0 })
+            .counter("ghostreaver_watchdog_pings_total", "Client pings sent by the watchdog", health.pings_sent as f64)
+            .counter("ghostreaver_watchdog_pongs_total", "Pongs received for watchdog pings", health.pongs_received as f64)
+            .counter("ghostreaver_watchdog_stalls_total", "Subscriptions torn down after the idle timeout", health.stalls as f64);
+
+        // ─── compare 'health.last_message_age_ms' ───
+        if let Some(age_ms) = health.last_message_age_ms {
+            text.gauge("ghostreaver_last_message_age_seconds", "Seconds since the last Geyser update", age_ms as f64 / 1_000.0);
+        }
+
+        // ─── compare 'health.last_pong_rtt_ms' ───
+        if let Some(rtt_ms) = health.last_pong_rtt_ms {
+            text.gauge("ghostreaver_pong_rtt_seconds", "Round-trip time of the last watchdog ping", rtt_ms / 1_000.0);
+        }
+
+        // ─── compare 'self.metrics_manager.channel_depth()' ───
+        if let Some((depth, capacity)) = self.metrics_manager.channel_depth() {
+            text.gauge("ghostreaver_channel_depth", "Messages queued between the reader and the processor", depth as f64)
+                .gauge("ghostreaver_channel_capacity", "Capacity of the reader to processor channel", capacity as f64);
+        }
+
+        // ─── define 'latency' ───
+        let latency = self.metrics_manager.latency_summaries();
+
+        // ─── define 'quantiles' ───
+        let mut quantiles = Vec::with_capacity(latency.len() * 3);
+
+        // ─── proceed 'for' ───
+        for summary in &latency {
+
+            // ─── define 'stage' ───
+            let stage = PrometheusText::label("stage", summary.stage.name());
+            quantiles.push((format!("{stage},quantile=\"0.5\""), summary.p50_us as f64 / 1e6));
+            quantiles.push((format!("{stage},quantile=\"0.9\""), summary.p90_us as f64 / 1e6));
+            quantiles.push((format!("{stage},quantile=\"0.99\""), summary.p99_us as f64 / 1e6));
+        }
+        text.metric("ghostreaver_stage_latency_seconds", "summary", "Per-stage event latency (provider, channel, parse, dispatch, end_to_end)", &quantiles);
+
+        // ─── define 'sums' ───
+        let sums: Vec<(String, f64)> = latency.iter()
+            .map(|summary| (PrometheusText::label("stage", summary.stage.name()), summary.sum_us as f64 / 1e6))
+            .collect();
+
+        // ─── define 'counts' ───
+        let counts: Vec<(String, f64)> = latency.iter()
+            .map(|summary| (PrometheusText::label("stage", summary.stage.name()), summary.count as f64))
+            .collect();
+        text.samples("ghostreaver_stage_latency_seconds_sum", &sums)
+            .samples("ghostreaver_stage_latency_seconds_count", &counts);
+
+        // ─── define 'parsed' ───
+        let mut parsed: Vec<(String, f64)> = self.metrics_manager.parsed_events().into_iter()
+            .map(|(event_type, count)| (PrometheusText::label("event_type", &event_type.to_string()), count as f64))
+            .collect();
+        parsed.sort_by(|a, b| a.0.cmp(&b.0));
+        text.metric("ghostreaver_events_parsed_total", "counter", "Parsed events by event type", &parsed);
+
+        // ─── define 'body' ───
+        let mut body = text.finish();
+
+        // ─── proceed 'for' ───
+        for collector in &self.collectors {
+            body.push_str(&collector().await);
+        }
+
+        // ─── return 'String' ───
+        body
+    }
+
+    // ─── fn 'health' ───
+    /// fn description
+    fn health(&self) -> (&'static str, &'static str, String) {
+
+        // ─── define 'health' ───
+        let health = self.metrics_manager.health();
+
+        // ─── define 'body' ───
+        let body = format!("{{\"healthy\":{},\"connected\":{},\"last_message_age_ms\":{},\"last_pong_rtt_ms\":{},\"stalls\":{}}}\n",
+            health.healthy, health.connected,
+            health.last_message_age_ms.map_or("null".to_string(), |age| age.to_string()),
+            health.last_pong_rtt_ms.map_or("null".to_string(), |rtt| format!("{rtt:.3}")),
+            health.stalls);
+
+        // ─── compare 'health.healthy' ───
+        if health.healthy {
+            ("200 OK", "application/json", body)
+        } else {
+            ("503 Service Unavailable", "application/json", body)
+        }
+    }
+
+    // ─── fn 'serve' ───
+    /// fn description
+    pub async fn serve(self, bind: &str) -> AnyResult<JoinHandle<()>> {
+
+        // ─── define 'listener' ───
+        let listener = TcpListener::bind(bind).await?;
+        log::info!("Prometheus metrics available at http://{0}/metrics (health check at http://{0}/health)", listener.local_addr()?);
+
+        // ─── define 'exporter' ───
+        let exporter = Arc::new(self);
+
+        // ─── return 'Result' ───
+        Ok(tokio::spawn(async move {
+            loop {
+
+                // ─── match 'listener.accept()' ───
+                match listener.accept().await {
+                    Ok((stream, _)) => {
+
+                        // ─── define 'exporter' ───
+                        let exporter = Arc::clone(&exporter);
+                        tokio::spawn(async move {
+
+                            // ─── compare 'Self::respond()' ───
+                            if let Err(e) = Self::respond(&exporter, stream).await {
+                                log::debug!("Prometheus scrape failed: {e}");
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        log::warn!("Prometheus listener accept failed: {e}");
+                    }
+                }
+            }
+        }))
+    }
+
+    // ─── fn 'respond' ───
+    /// fn description
+    async fn respond(exporter: &PrometheusExporter, mut stream: TcpStream) -> AnyResult<()> {
+
+        // ─── define 'request' ───
+        let mut request = Vec::with_capacity(1024);
+
+        // ─── define 'buf' ───
+        let mut buf = [0u8; 1024];
+
+        // ─── proceed 'loop' ───
+        loop {
+
+            // ─── define 'n' ───
+            let n = stream.read(&mut buf).await?;
+            request.extend_from_slice(&buf[..n]);
+
+            // ─── compare 'request' ───
+            if n == 0 || request.windows(4).any(|w| w == b"\r\n\r\n") || request.len() >= PROMETHEUSMAXREQUEST {
+                break;
+            }
+        }
+
+        // ─── define 'line' ───
+        let line = String::from_utf8_lossy(&request);
+
+        // ─── define 'path' ───
+        let path = line.lines().next().and_then(|first| first.strip_prefix("GET ")).and_then(|rest| rest.split_whitespace().next());
+
+        // ─── define '(status, content_type, body)' ───
+        let (status, content_type, body) = match path {
+            Some("/metrics") => ("200 OK", "text/plain; version=0.0.4; charset=utf-8", exporter.render().await),
+            Some("/health") => exporter.health(),
+            _ => ("404 Not Found", "text/plain; charset=utf-8", "not found\n".to_string()),
+        };
+
+        // ─── define 'response' ───
+        let response = format!("HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len());
+        stream.write_all(response.as_bytes()).await?;
+        stream.shutdown().await?;
+
+        // ─── return 'Result' ───
+        Ok(())
+    }
+}
@@ -1,12 +1,20 @@
 // ─── import packages ───
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 
+// ─── import crates ───
+use crate::streaming::grpc::control::SubscriptionController;
+
 // ─── struct 'SubscriptionHandle' ───
 /// struct description
 pub struct SubscriptionHandle {
     stream_handle: JoinHandle<()>,
     event_handle: JoinHandle<()>,
-    metrics_handle: Option<JoinHandle<()>>
+    metrics_handle: Option<JoinHandle<()>>,
+    controller: Option<SubscriptionController>
 }
 
 // ─── impl 'SubscriptionHandle' ───
@@ -18,7 +26,34 @@ impl SubscriptionHandle {
     pub fn new(stream_handle: JoinHandle<()>, event_handle: JoinHandle<()>, metrics_handle: Option<JoinHandle<()>>) -> Self {
 
         // ─── return 'Self' ───
-        Self { stream_handle, event_handle, metrics_handle }
+        Self { stream_handle, event_handle, metrics_handle, controller: None }
+    }
+
+    // ─── fn 'with_controller' ───
+    /// fn description
+    pub fn with_controller(mut self, controller: SubscriptionController) -> Self {
+
+        // ─── define 'self.controller' ───
+        self.controller = Some(controller);
+
+        // ─── return 'Self' ───
+        self
+    }
+
+    // ─── fn 'controller' ───
+    /// fn description
+    pub fn controller(&self) -> Option<&SubscriptionController> {
+
+        // ─── return 'Option' ───
+        self.controller.as_ref()
+    }
+
+    // ─── fn 'is_active' ───
+    /// fn description
+    pub fn is_active(&self) -> bool {
+
+        // ─── return 'bool' ───
+        !self.stream_handle.is_finished() && !self.event_handle.is_finished()
     }
 
     // ─── fn 'stop' ───
@@ -33,6 +68,36 @@ impl SubscriptionHandle {
         }
     }
 
+    // ─── fn 'shutdown' ───
+    /// fn description
+    pub async fn shutdown(self, deadline: Duration) -> bool {
+        self.stream_handle.abort();
+
+        // ─── callback 'self.stream_handle' ───
+        let _ = self.stream_handle.await;
+
+        // ─── define 'event_handle' ───
+        let mut event_handle = self.event_handle;
+
+        // ─── define 'drained' ───
+        let drained = match tokio::time::timeout(deadline, &mut event_handle).await {
+            Ok(_) => true,
+            Err(_) => {
+                log::warn!("Event workers did not drain within {deadline:?}; aborting");
+                event_handle.abort();
+                false
+            }
+        };
+
+        // ─── compare 'self.metrics_handle' ───
+        if let Some(handle) = self.metrics_handle {
+            handle.abort();
+        }
+
+        // ─── return 'bool' ───
+        drained
+    }
+
     // ─── fn 'join' ───
     /// fn description
     pub async fn join(self) -> Result<(), tokio::task::JoinError> {
@@ -53,4 +118,70 @@ impl SubscriptionHandle {
         // ─── return 'Result' ───
         Ok(())
     }
-}
\ No newline at end of file
+}
+// ─── struct 'EventStream' ───
+/// struct description
+pub struct EventStream<T> {
+    receiver: mpsc::Receiver<T>,
+    handle: Option<SubscriptionHandle>
+}
+
+// ─── impl 'EventStream' ───
+/// impl description
+impl<T> EventStream<T> {
+
+    // ─── fn 'new' ───
+    /// fn description
+    pub fn new(receiver: mpsc::Receiver<T>, handle: SubscriptionHandle) -> Self {
+
+        // ─── return 'Self' ───
+        Self { receiver, handle: Some(handle) }
+    }
+
+    // ─── fn 'controller' ───
+    /// fn description
+    pub fn controller(&self) -> Option<&SubscriptionController> {
+
+        // ─── return 'Option' ───
+        self.handle.as_ref().and_then(|handle| handle.controller())
+    }
+
+    // ─── fn 'stop' ───
+    /// fn description
+    pub fn stop(mut self) {
+
+        // ─── compare 'self.handle' ───
+        if let Some(handle) = self.handle.take() {
+            handle.stop();
+        }
+    }
+}
+
+// ─── impl 'Stream' ───
+/// impl description
+impl<T> futures::Stream for EventStream<T> {
+    type Item = T;
+
+    // ─── fn 'poll_next' ───
+    /// fn description
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+
+        // ─── return 'Poll' ───
+        self.receiver.poll_recv(cx)
+    }
+}
+
+// ─── impl 'Drop' ───
+/// impl description
+impl<T> Drop for EventStream<T> {
+
+    // ─── fn 'drop' ───
+    /// fn description
+    fn drop(&mut self) {
+
+        // ─── compare 'self.handle' ───
+        if let Some(handle) = self.handle.take() {
+            handle.stop();
+        }
+    }
+}
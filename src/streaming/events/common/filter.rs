@@ -1,5 +1,7 @@
 // ─── import crates ───
-use crate::streaming::events::common::{types::EventType, ACCOUNT_EVENT_TYPES, BLOCK_EVENT_TYPES};
+use crate::streaming::events::common::{
+    types::EventType, ACCOUNT_EVENT_TYPES, BLOCK_EVENT_TYPES, COMMITMENT_EVENT_TYPES, SLOT_EVENT_TYPES
+};
 
 // ─── struct 'EventTypeFilter' ───
 /// struct description
@@ -17,7 +19,8 @@ impl EventTypeFilter {
     pub fn include_transaction_event(&self) -> bool {
 
         // ─── return 'self.include.iter()' ───
-        self.include.iter().any(|event| !ACCOUNT_EVENT_TYPES.contains(event) && !BLOCK_EVENT_TYPES.contains(event))
+        self.include.iter().any(|event| !ACCOUNT_EVENT_TYPES.contains(event) && !BLOCK_EVENT_TYPES.contains(event)
+            && !SLOT_EVENT_TYPES.contains(event) && !COMMITMENT_EVENT_TYPES.contains(event))
     }
 
     // ─── fn 'include_account_event' ───
@@ -35,4 +38,12 @@ impl EventTypeFilter {
         // ─── return 'self.include.iter()' ───
         self.include.iter().any(|event| BLOCK_EVENT_TYPES.contains(event))
     }
+
+    // ─── fn 'include_slot_event' ───
+    /// fn description
+    pub fn include_slot_event(&self) -> bool {
+
+        // ─── return 'self.include.iter()' ───
+        self.include.iter().any(|event| SLOT_EVENT_TYPES.contains(event))
+    }
 }
\ No newline at end of file
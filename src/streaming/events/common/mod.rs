@@ -36,6 +36,14 @@ macro_rules! impl_unified_event {
                 self.metadata.event_type.clone()
             }
 
+            // ─── fn 'protocol' ───
+            /// fn description
+            fn protocol(&self) -> $crate::streaming::events::common::types::ProtocolType {
+
+                // ─── return 'crate' ───
+                self.metadata.protocol.clone()
+            }
+
             // ─── fn 'signature' ───
             /// fn description
             fn signature(&self) -> &str {
@@ -76,6 +84,48 @@ macro_rules! impl_unified_event {
                 self.metadata.program_handle_time_consuming_ms = program_handle_time_consuming_ms;
             }
 
+            // ─── fn 'timings' ───
+            /// fn description
+            fn timings(&self) -> $crate::streaming::events::common::types::EventTimings {
+
+                // ─── return 'crate' ───
+                self.metadata.timings
+            }
+
+            // ─── fn 'set_timings' ───
+            /// fn description
+            fn set_timings(&mut self, timings: $crate::streaming::events::common::types::EventTimings) {
+                self.metadata.timings = timings;
+            }
+
+            // ─── fn 'filters' ───
+            /// fn description
+            fn filters(&self) -> &[String] {
+
+                // ─── return 'String' ───
+                &self.metadata.filters
+            }
+
+            // ─── fn 'set_filters' ───
+            /// fn description
+            fn set_filters(&mut self, filters: Vec<String>) {
+                self.metadata.filters = filters;
+            }
+
+            // ─── fn 'succeeded' ───
+            /// fn description
+            fn succeeded(&self) -> bool {
+
+                // ─── return 'bool' ───
+                self.metadata.succeeded
+            }
+
+            // ─── fn 'set_succeeded' ───
+            /// fn description
+            fn set_succeeded(&mut self, succeeded: bool) {
+                self.metadata.succeeded = succeeded;
+            }
+
             // ─── fn 'as_any' ───
             /// fn description
             fn as_any(&self) -> &dyn std::any::Any {
@@ -5,6 +5,7 @@ use solana_sdk::pubkey::Pubkey;
 use solana_transaction_status::UiInstruction;
 use std::{hash::{DefaultHasher, Hash, Hasher}, sync::Arc};
 use tokio::sync::Mutex;
+use yellowstone_grpc_proto::prelude::InnerInstructions;
 
 // ─── import crates ───
 use crate::globals::constants::*;
@@ -141,12 +142,13 @@ pub enum ProtocolType {
     RaydiumCpmm,
     RaydiumClmm,
     RaydiumAmmV4,
+    Idl,
     Common
 }
 
 // ─── enum 'EventType' ───
 /// enum description
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 pub enum EventType {
 
     #[default]
@@ -214,8 +216,18 @@ pub enum EventType {
     AccountRaydiumCpmmAmmConfig,
     AccountRaydiumCpmmPoolState,
 
+    // ─── 'IDL Events' ───
+    IdlInstruction,
+    IdlEvent,
+    IdlAccount,
+
     // ─── 'Block Events' ───
     BlockMeta,
+    Slot,
+
+    // ─── 'Commitment Events' ───
+    EventConfirmed,
+    EventRolledBack,
     Unknown
 }
 
@@ -235,13 +247,34 @@ pub const ACCOUNT_EVENT_TYPES: &[EventType] = &[
     EventType::AccountRaydiumClmmPoolState,
     EventType::AccountRaydiumClmmTickArrayState,
     EventType::AccountRaydiumCpmmAmmConfig,
-    EventType::AccountRaydiumCpmmPoolState
+    EventType::AccountRaydiumCpmmPoolState,
+    EventType::IdlAccount
 ];
 
 // ─── const 'BLOCK_EVENT_TYPES' ───
 /// const description
 pub const BLOCK_EVENT_TYPES: &[EventType] = &[EventType::BlockMeta];
 
+// ─── const 'SLOT_EVENT_TYPES' ───
+/// const description
+pub const SLOT_EVENT_TYPES: &[EventType] = &[EventType::Slot];
+
+// ─── const 'COMMITMENT_EVENT_TYPES' ───
+/// const description
+pub const COMMITMENT_EVENT_TYPES: &[EventType] = &[EventType::EventConfirmed, EventType::EventRolledBack];
+
+// ─── const 'CRITICAL_EVENT_TYPES' ───
+/// const description
+pub const CRITICAL_EVENT_TYPES: &[EventType] = &[
+    EventType::BonkInitialize,
+    EventType::BonkInitializeV2,
+    EventType::PumpFunCreateToken,
+    EventType::PumpSwapCreatePool,
+    EventType::RaydiumAmmV4Initialize2,
+    EventType::RaydiumClmmCreatePool,
+    EventType::RaydiumCpmmInitialize,
+];
+
 // ─── impl 'EventType' ───
 /// impl description
 impl EventType {
@@ -301,7 +334,13 @@ impl EventType {
             EventType::AccountRaydiumClmmTickArrayState => {"AccountRaydiumClmmTickArrayState".to_string()}
             EventType::AccountRaydiumCpmmAmmConfig => "AccountRaydiumCpmmAmmConfig".to_string(),
             EventType::AccountRaydiumCpmmPoolState => "AccountRaydiumCpmmPoolState".to_string(),
+            EventType::IdlInstruction => "IdlInstruction".to_string(),
+            EventType::IdlEvent => "IdlEvent".to_string(),
+            EventType::IdlAccount => "IdlAccount".to_string(),
             EventType::BlockMeta => "BlockMeta".to_string(),
+            EventType::Slot => "Slot".to_string(),
+            EventType::EventConfirmed => "EventConfirmed".to_string(),
+            EventType::EventRolledBack => "EventRolledBack".to_string(),
             EventType::Unknown => "Unknown".to_string()
         }
     }
@@ -394,9 +433,54 @@ pub struct SwapData {
     pub description: Option<String>
 }
 
+// ─── struct 'EventTimings' ───
+/// struct description
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+pub struct EventTimings {
+    pub created_at_us: i64,
+    pub received_us: i64,
+    pub dequeued_us: i64,
+    pub parsed_us: i64,
+    pub callback_us: i64
+}
+
+// ─── impl 'EventTimings' ───
+/// impl description
+impl EventTimings {
+
+    // ─── fn 'now_us' ───
+    /// fn description
+    pub fn now_us() -> i64 {
+
+        // ─── return 'i64' ───
+        chrono::Utc::now().timestamp_micros()
+    }
+
+    // ─── fn 'received' ───
+    /// fn description
+    pub fn received(created_at_us: i64) -> Self {
+
+        // ─── return 'Self' ───
+        Self { created_at_us, received_us: Self::now_us(), ..Default::default() }
+    }
+
+    // ─── fn 'between' ───
+    /// fn description
+    pub fn between(from_us: i64, to_us: i64) -> Option<u64> {
+
+        // ─── compare 'from_us' ───
+        if from_us <= 0 || to_us <= 0 {
+            return None;
+        }
+
+        // ─── return 'Option' ───
+        Some(to_us.saturating_sub(from_us).max(0) as u64)
+    }
+}
+
 // ─── struct 'EventMetadata' ───
 /// struct description
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 pub struct EventMetadata {
     pub id: String,
     pub signature: String,
@@ -410,7 +494,50 @@ pub struct EventMetadata {
     pub program_id: Pubkey,
     pub transfer_datas: Vec<TransferData>,
     pub swap_data: Option<SwapData>,
-    pub index: String
+    pub index: String,
+    #[serde(default)]
+    pub timings: EventTimings,
+    #[serde(default)]
+    pub filters: Vec<String>,
+    /// 所属交易是否执行成功（订阅包含失败交易时为 false）
+    #[serde(default = "default_succeeded")]
+    pub succeeded: bool
+}
+
+// ─── fn 'default_succeeded' ───
+/// fn description
+fn default_succeeded() -> bool {
+    true
+}
+
+// ─── impl 'Default for EventMetadata' ───
+/// impl description
+impl Default for EventMetadata {
+
+    // ─── fn 'default' ───
+    /// fn description
+    fn default() -> Self {
+
+        // ─── return 'Self' ───
+        Self {
+            id: String::new(),
+            signature: String::new(),
+            slot: 0,
+            block_time: 0,
+            block_time_ms: 0,
+            program_received_time_ms: 0,
+            program_handle_time_consuming_ms: 0,
+            protocol: ProtocolType::default(),
+            event_type: EventType::default(),
+            program_id: Pubkey::default(),
+            transfer_datas: Vec::new(),
+            swap_data: None,
+            index: String::new(),
+            timings: EventTimings::default(),
+            filters: Vec::new(),
+            succeeded: true,
+        }
+    }
 }
 
 // ─── impl 'EventMetadata' ───
@@ -438,6 +565,9 @@ impl EventMetadata {
             transfer_datas: Vec::with_capacity(4),
             swap_data: None,
             index,
+            timings: EventTimings::default(),
+            filters: Vec::new(),
+            succeeded: true,
         }
     }
 
@@ -483,124 +613,148 @@ pub fn parse_transfer_datas_from_next_instructions(event: Box<dyn UnifiedEvent>,
     // ─── define 'next_instructions' ───
     let next_instructions: Vec<&UiInstruction> = inner_instruction.instructions.iter().skip((current_index + 1) as usize).collect();
 
-    // ─── define 'system_programs' ───
-    let system_programs = vec![
-        system_pubkeys::TOKEN,
-        system_pubkeys::TOKEN2022,
-        system_pubkeys::SYSTEM
-    ];
-
     // ─── proceed 'for' ───
     for instruction in next_instructions {
 
         // ─── compare 'instruction' ───
         if let UiInstruction::Compiled(compiled) = instruction {
 
-            // ─── compare 'system_programs.contains()' ───
-            if !system_programs.contains(&accounts[compiled.program_id_index as usize]) {
+            // ─── compare 'is_transfer_program()' ───
+            if !is_transfer_program(compiled.program_id_index as usize, accounts) {
                 break;
             }
 
             // ─── compare 'data' ───
             if let Ok(data) = bs58::decode(compiled.data.clone()).into_vec() {
 
-                // ─── compare 'data' ───
-                if data[0] == 12 {
-
-                    // ─── define 'account_pubkeys' ───
-                    let account_pubkeys: Vec<Pubkey> = compiled.accounts.iter().map(|a| accounts[*a as usize]).collect();
-
-                    // ─── compare 'account_pubkeys' ───
-                    if account_pubkeys.len() < 4 {
-                        continue;
-                    }
-
-                    // ─── define '(source, mint, destination, authority)' ───
-                    let (source, mint, destination, authority) = (
-                        account_pubkeys[0],
-                        account_pubkeys[1],
-                        account_pubkeys[2],
-                        account_pubkeys[3],
-                    );
-
-                    // ─── define 'amount' ───
-                    let amount = u64::from_le_bytes(data[1..9].try_into().unwrap());
-
-                    // ─── define 'decimals' ───
-                    let decimals = data[9];
-
-                    // ─── define 'token_program' ───
-                    let token_program = accounts[compiled.program_id_index as usize];
-                    transfer_datas.push(TransferData {
-                        amount,
-                        decimals: Some(decimals),
-                        mint: Some(mint),
-                        source,
-                        destination,
-                        authority: Some(authority),
-                        token_program,
-                    });
-                }
-                else if data[0] == 3 {
-
-                    // ─── define 'account_pubkeys' ───
-                    let account_pubkeys: Vec<Pubkey> = compiled.accounts.iter().map(|a| accounts[*a as usize]).collect();
-
-                    // ─── compare 'account_pubkeys' ───
-                    if account_pubkeys.len() < 3 {
-                        continue;
-                    }
-
-                    // ─── define '(source, destination, authority)' ───
-                    let (source, destination, authority) = (account_pubkeys[0], account_pubkeys[1], account_pubkeys[2]);
-
-                    // ─── define 'amount' ───
-                    let amount = u64::from_le_bytes(data[1..9].try_into().unwrap());
-
-                    // ─── define 'token_program' ───
-                    let token_program = accounts[compiled.program_id_index as usize];
-                    transfer_datas.push(TransferData {
-                        amount,
-                        decimals: None,
-                        mint: None,
-                        source,
-                        destination,
-                        authority: Some(authority),
-                        token_program,
-                    });
-                }
-                else if data[0] == 2 {
-
-                    // ─── define 'account_pubkeys' ───
-                    let account_pubkeys: Vec<Pubkey> = compiled.accounts.iter().map(|a| accounts[*a as usize]).collect();
-
-                    // ─── compare 'account_pubkeys' ───
-                    if account_pubkeys.len() < 2 {
-                        continue;
-                    }
-
-                    // ─── define '(source, destination)' ───
-                    let (source, destination) = (account_pubkeys[0], account_pubkeys[1]);
-
-                    // ─── define 'amount' ───
-                    let amount = u64::from_le_bytes(data[4..12].try_into().unwrap());
-
-                    // ─── define 'token_program' ───
-                    let token_program = accounts[compiled.program_id_index as usize];
-                    transfer_datas.push(TransferData {
-                        amount,
-                        decimals: None,
-                        mint: None,
-                        source,
-                        destination,
-                        authority: None,
-                        token_program,
-                    });
+                // ─── compare 'parse_transfer_data()' ───
+                if let Some(transfer_data) = parse_transfer_data(compiled.program_id_index as usize, &compiled.accounts, &data, accounts) {
+                    transfer_datas.push(transfer_data);
                 }
             }
         }
     }
 
+    // ─── return 'parse_swap_data_from_transfers()' ───
+    parse_swap_data_from_transfers(event, transfer_datas)
+}
+
+// ─── fn 'parse_transfer_datas_from_next_proto_instructions' ───
+/// fn description
+pub fn parse_transfer_datas_from_next_proto_instructions(event: Box<dyn UnifiedEvent>,
+    inner_instruction: &InnerInstructions,
+    current_index: i8, accounts: &[Pubkey]) -> (Vec<TransferData>, Option<SwapData>) {
+
+    // ─── define 'transfer_datas' ───
+    let mut transfer_datas = vec![];
+
+    // ─── proceed 'for' ───
+    for instruction in inner_instruction.instructions.iter().skip((current_index + 1) as usize) {
+
+        // ─── compare 'is_transfer_program()' ───
+        if !is_transfer_program(instruction.program_id_index as usize, accounts) {
+            break;
+        }
+
+        // ─── compare 'parse_transfer_data()' ───
+        if let Some(transfer_data) = parse_transfer_data(instruction.program_id_index as usize, &instruction.accounts, &instruction.data, accounts) {
+            transfer_datas.push(transfer_data);
+        }
+    }
+
+    // ─── return 'parse_swap_data_from_transfers()' ───
+    parse_swap_data_from_transfers(event, transfer_datas)
+}
+
+// ─── fn 'is_transfer_program' ───
+/// fn description
+fn is_transfer_program(program_id_index: usize, accounts: &[Pubkey]) -> bool {
+
+    // ─── define 'system_programs' ───
+    let system_programs = [
+        system_pubkeys::TOKEN,
+        system_pubkeys::TOKEN2022,
+        system_pubkeys::SYSTEM
+    ];
+
+    // ─── return 'bool' ───
+    accounts.get(program_id_index).is_some_and(|program| system_programs.contains(program))
+}
+
+// ─── fn 'parse_transfer_data' ───
+/// fn description
+fn parse_transfer_data(program_id_index: usize, instruction_accounts: &[u8], data: &[u8], accounts: &[Pubkey]) -> Option<TransferData> {
+
+    // ─── define 'token_program' ───
+    let token_program = *accounts.get(program_id_index)?;
+
+    // ─── define 'account_pubkeys' ───
+    let account_pubkeys: Vec<Pubkey> = instruction_accounts.iter().filter_map(|a| accounts.get(*a as usize).copied()).collect();
+
+    // ─── match 'data' ───
+    match data.first()? {
+        12 => {
+
+            // ─── compare 'account_pubkeys' ───
+            if account_pubkeys.len() < 4 || data.len() < 10 {
+                return None;
+            }
+
+            // ─── return 'TransferData' ───
+            Some(TransferData {
+                amount: u64::from_le_bytes(data[1..9].try_into().ok()?),
+                decimals: Some(data[9]),
+                mint: Some(account_pubkeys[1]),
+                source: account_pubkeys[0],
+                destination: account_pubkeys[2],
+                authority: Some(account_pubkeys[3]),
+                token_program,
+            })
+        }
+        3 => {
+
+            // ─── compare 'account_pubkeys' ───
+            if account_pubkeys.len() < 3 || data.len() < 9 {
+                return None;
+            }
+
+            // ─── return 'TransferData' ───
+            Some(TransferData {
+                amount: u64::from_le_bytes(data[1..9].try_into().ok()?),
+                decimals: None,
+                mint: None,
+                source: account_pubkeys[0],
+                destination: account_pubkeys[1],
+                authority: Some(account_pubkeys[2]),
+                token_program,
+            })
+        }
+        2 => {
+
+            // ─── compare 'account_pubkeys' ───
+            if account_pubkeys.len() < 2 || data.len() < 12 {
+                return None;
+            }
+
+            // ─── return 'TransferData' ───
+            Some(TransferData {
+                amount: u64::from_le_bytes(data[4..12].try_into().ok()?),
+                decimals: None,
+                mint: None,
+                source: account_pubkeys[0],
+                destination: account_pubkeys[1],
+                authority: None,
+                token_program,
+            })
+        }
+        _ => None,
+    }
+}
+
+// ─── fn 'parse_swap_data_from_transfers' ───
+/// fn description
+fn parse_swap_data_from_transfers(event: Box<dyn UnifiedEvent>, transfer_datas: Vec<TransferData>) -> (Vec<TransferData>, Option<SwapData>) {
+
     // ─── define 'swap_data' ───
     let mut swap_data: SwapData = SwapData {
         from_mint: Pubkey::default(),
@@ -9,6 +9,7 @@ use crate::streaming::events::common::{EventMetadata, EventType, ProtocolType};
 use crate::streaming::events::core::traits::UnifiedEvent;
 use crate::streaming::events::Protocol;
 use crate::streaming::events::protocols::block::blockmeta::BlockMetaEvent;
+use crate::streaming::events::protocols::block::slot::{SlotEvent, SlotStatus};
 use crate::streaming::events::protocols::bonk::parser::BONK_PROGRAM_ID;
 use crate::streaming::events::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
 use crate::streaming::events::protocols::pumpswap::parser::PUMPSWAP_PROGRAM_ID;
@@ -307,4 +308,13 @@ impl CommonEventParser {
         let block_meta_event = BlockMetaEvent::new(slot, block_hash.to_string(), block_time_ms);
         Box::new(block_meta_event)
     }
-}
\ No newline at end of file
+
+    // ─── fn 'generate_slot_event' ───
+    /// fn description
+    pub fn generate_slot_event(slot: u64, parent: Option<u64>, status: SlotStatus, dead_error: Option<String>, created_at_ms: i64) -> Box<dyn UnifiedEvent> {
+
+        // ─── define 'slot_event' ───
+        let slot_event = SlotEvent::new(slot, parent, status, dead_error, created_at_ms);
+        Box::new(slot_event)
+    }
+}
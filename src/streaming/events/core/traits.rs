@@ -6,12 +6,16 @@ use solana_transaction_status::{
 };
 use std::fmt::Debug;
 use std::{collections::HashMap, str::FromStr};
+use yellowstone_grpc_proto::prelude::SubscribeUpdateTransactionInfo;
 
 use crate::utils::scripts::Scripts;
-use crate::streaming::events::common::{parse_transfer_datas_from_next_instructions, SwapData, TransferData};
+use crate::streaming::events::common::{
+    parse_transfer_datas_from_next_instructions, parse_transfer_datas_from_next_proto_instructions,
+    SwapData, TransferData,
+};
 use crate::streaming::events::protocols::pumpswap::{PumpSwapBuyEvent, PumpSwapSellEvent};
 use crate::streaming::events::{
-    common::{EventMetadata, EventType, ProtocolType},
+    common::{EventMetadata, EventTimings, EventType, ProtocolType},
     protocols::{
         bonk::{BonkPoolCreateEvent, BonkTradeEvent},
         pumpfun::{PumpFunCreateTokenEvent, PumpFunTradeEvent},
@@ -22,15 +26,29 @@ use crate::streaming::events::{
 pub trait UnifiedEvent: Debug + Send + Sync {
     fn id(&self) -> &str;
     fn event_type(&self) -> EventType;
+    fn protocol(&self) -> ProtocolType;
     fn signature(&self) -> &str;
     fn slot(&self) -> u64;
     fn program_received_time_ms(&self) -> i64;
     fn program_handle_time_consuming_ms(&self) -> i64;
     fn set_program_handle_time_consuming_ms(&mut self, program_handle_time_consuming_ms: i64);
+    /// 流水线各阶段时间戳（Geyser 创建、接收、出队、解析完成、回调开始）
+    fn timings(&self) -> EventTimings;
+    fn set_timings(&mut self, timings: EventTimings);
+    /// 命中的订阅过滤器名称（交易 / 账户过滤器），供回调按过滤器路由
+    fn filters(&self) -> &[String];
+    fn set_filters(&mut self, filters: Vec<String>);
+    /// 所属交易是否执行成功；订阅包含失败交易时，失败交易解析出的事件为 false
+    fn succeeded(&self) -> bool;
+    fn set_succeeded(&mut self, succeeded: bool);
     fn as_any(&self) -> &dyn std::any::Any;
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
     fn clone_boxed(&self) -> Box<dyn UnifiedEvent>;
     fn merge(&mut self, _other: Box<dyn UnifiedEvent>) {}
+    /// 是否合并同一指令下的全部匹配内联事件（默认只合并第一个）
+    fn merge_all_inner(&self) -> bool {
+        false
+    }
     fn set_transfer_datas(&mut self, transfer_datas: Vec<TransferData>, swap_data: Option<SwapData>);
     fn index(&self) -> String;
 }
@@ -51,6 +69,56 @@ pub trait EventParser: Send + Sync {
         index: String,
     ) -> Vec<Box<dyn UnifiedEvent>>;
 
+    /// 按原始字节解析内联指令事件（protobuf 快速路径，免 base58 往返）。
+    /// 默认实现回退到 UI 指令解析，GenericEventParser 及其包装器直接处理字节
+    fn parse_events_from_inner_instruction_data(
+        &self,
+        data: &[u8],
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        let instruction = UiCompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![],
+            data: bs58::encode(data).into_string(),
+            stack_height: None,
+        };
+        self.parse_events_from_inner_instruction(
+            &instruction,
+            signature,
+            slot,
+            block_time,
+            program_received_time_ms,
+            index,
+        )
+    }
+
+    /// 已知被调用程序时解析内联指令事件（原始字节）。默认不区分程序；
+    /// 多个程序共用同一事件 discriminator 时（如 fork 程序），解析器应覆盖以只解码自身程序发出的事件
+    #[allow(clippy::too_many_arguments)]
+    fn parse_events_from_program_inner_instruction(
+        &self,
+        _program_id: &Pubkey,
+        data: &[u8],
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        self.parse_events_from_inner_instruction_data(
+            data,
+            signature,
+            slot,
+            block_time,
+            program_received_time_ms,
+            index,
+        )
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn parse_events_from_instruction(
         &self,
@@ -153,26 +221,28 @@ pub trait EventParser: Send + Sync {
         let mut address_table_lookups: Vec<Pubkey> = Vec::with_capacity(32);
         let mut inner_instructions: Vec<UiInnerInstructions> = Vec::with_capacity(8);
 
-        if meta.err.is_none() {
+        let succeeded = meta.err.is_none();
+        if succeeded {
             // OptionSerializer::Some
             if let solana_transaction_status::option_serializer::OptionSerializer::Some(meta_inner) =
                 &meta.inner_instructions
             {
                 inner_instructions = meta_inner.clone();
             }
-            if let solana_transaction_status::option_serializer::OptionSerializer::Some(loaded) =
-                &meta.loaded_addresses
-            {
-                address_table_lookups.reserve(loaded.writable.len() + loaded.readonly.len());
-                for lookup in &loaded.writable {
-                    if let Ok(pk) = Pubkey::from_str(lookup) {
-                        address_table_lookups.push(pk);
-                    }
+        }
+        // 失败交易同样加载了查找表，账户索引需要完整的账户集合
+        if let solana_transaction_status::option_serializer::OptionSerializer::Some(loaded) =
+            &meta.loaded_addresses
+        {
+            address_table_lookups.reserve(loaded.writable.len() + loaded.readonly.len());
+            for lookup in &loaded.writable {
+                if let Ok(pk) = Pubkey::from_str(lookup) {
+                    address_table_lookups.push(pk);
                 }
-                for lookup in &loaded.readonly {
-                    if let Ok(pk) = Pubkey::from_str(lookup) {
-                        address_table_lookups.push(pk);
-                    }
+            }
+            for lookup in &loaded.readonly {
+                if let Ok(pk) = Pubkey::from_str(lookup) {
+                    address_table_lookups.push(pk);
                 }
             }
         }
@@ -207,7 +277,7 @@ pub trait EventParser: Send + Sync {
 
         // 解析内联指令事件
         let mut inner_instruction_events = Vec::with_capacity(8);
-        if meta.err.is_none() {
+        if succeeded {
             for inner_instruction in &inner_instructions {
                 for (idx, instruction) in inner_instruction.instructions.iter().enumerate() {
                     if let UiInstruction::Compiled(compiled) = instruction {
@@ -250,30 +320,29 @@ pub trait EventParser: Send + Sync {
                             }
                         }
 
-                        // 内联解析
-                        if let Ok(mut events) = self
-                            .parse_inner_instruction(
-                                compiled,
-                                signature,
-                                slot,
-                                block_time,
-                                program_received_time_ms,
-                                format!("{}.{}", inner_instruction.index, idx),
-                            )
-                            .await
-                        {
-                            if !events.is_empty() {
-                                events.iter_mut().for_each(|event| {
-                                    let (transfer_datas, swap_data) = parse_transfer_datas_from_next_instructions(
-                                        event.clone_boxed(),
-                                        inner_instruction,
-                                        idx as i8,
-                                        &accounts,
-                                    );
-                                    event.set_transfer_datas(transfer_datas, swap_data);
-                                });
-                                inner_instruction_events.extend(events);
-                            }
+                        // 内联解析（按被调用程序）
+                        let program_id =
+                            accounts.get(compiled.program_id_index as usize).copied().unwrap_or_default();
+                        let mut events = self.parse_events_from_program_inner_instruction(
+                            &program_id,
+                            &compiled_instruction.data,
+                            signature,
+                            slot.unwrap_or(0),
+                            block_time,
+                            program_received_time_ms,
+                            format!("{}.{}", inner_instruction.index, idx),
+                        );
+                        if !events.is_empty() {
+                            events.iter_mut().for_each(|event| {
+                                let (transfer_datas, swap_data) = parse_transfer_datas_from_next_instructions(
+                                    event.clone_boxed(),
+                                    inner_instruction,
+                                    idx as i8,
+                                    &accounts,
+                                );
+                                event.set_transfer_datas(transfer_datas, swap_data);
+                            });
+                            inner_instruction_events.extend(events);
                         }
                     }
                 }
@@ -281,42 +350,186 @@ pub trait EventParser: Send + Sync {
         }
 
         // 合并同一 id 的内联/普通事件
-        if !instruction_events.is_empty() && !inner_instruction_events.is_empty() {
-            for instruction_event in &mut instruction_events {
-                for inner_instruction_event in &inner_instruction_events {
-                    if instruction_event.id() != inner_instruction_event.id() {
+        merge_inner_instruction_events(&mut instruction_events, &inner_instruction_events);
+        if !succeeded {
+            instruction_events.iter_mut().for_each(|event| event.set_succeeded(false));
+        }
+
+        Ok(self.process_events(instruction_events, bot_wallet))
+    }
+
+    /// 直接解析 Geyser protobuf 交易（快速路径）：
+    /// 读取原始公钥字节与指令字节，不经过 UI 编码、base58 和 Pubkey::from_str
+    async fn parse_proto_transaction(
+        &self,
+        tx: &SubscribeUpdateTransactionInfo,
+        signature: &str,
+        slot: Option<u64>,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        bot_wallet: Option<Pubkey>,
+    ) -> Result<Vec<Box<dyn UnifiedEvent>>> {
+        let meta = tx
+            .meta
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Missing transaction metadata"))?;
+        let message = tx
+            .transaction
+            .as_ref()
+            .and_then(|transaction| transaction.message.as_ref())
+            .ok_or_else(|| anyhow::anyhow!("Missing transaction message"))?;
+        let succeeded = meta.err.is_none();
+
+        // 账户集合：静态账户 + 查找表账户（写在前，只读在后；失败交易同样已加载）
+        let mut accounts: Vec<Pubkey> = Vec::with_capacity(
+            message.account_keys.len()
+                + meta.loaded_writable_addresses.len()
+                + meta.loaded_readonly_addresses.len(),
+        );
+        let to_pubkey = |key: &Vec<u8>| Pubkey::try_from(key.as_slice()).unwrap_or_default();
+        accounts.extend(message.account_keys.iter().map(to_pubkey));
+        accounts.extend(meta.loaded_writable_addresses.iter().map(to_pubkey));
+        accounts.extend(meta.loaded_readonly_addresses.iter().map(to_pubkey));
+
+        // 被调用的程序必然出现在账户集合中：不含我们关心的 Program 时直接返回
+        if !accounts.iter().any(|account| self.should_handle(account)) {
+            return Ok(Vec::new());
+        }
+
+        // 解析指令事件
+        let mut instruction_events = Vec::with_capacity(16);
+        let mut accounts_buf = accounts.clone();
+        for (index, instruction) in message.instructions.iter().enumerate() {
+            let Some(program_id) = accounts_buf.get(instruction.program_id_index as usize) else {
+                continue;
+            };
+            if !self.should_handle(program_id) {
+                continue;
+            }
+
+            // 防止账户索引越界：补齐 accounts（稀疏索引填充默认值）
+            if let Some(max_idx) = instruction.accounts.iter().max() {
+                let need = *max_idx as usize + 1;
+                if need > accounts_buf.len() {
+                    accounts_buf.resize(need, Pubkey::default());
+                }
+            }
+
+            let compiled_instruction = CompiledInstruction {
+                program_id_index: instruction.program_id_index as u8,
+                accounts: instruction.accounts.clone(),
+                data: instruction.data.clone(),
+            };
+            if let Ok(mut events) = self
+                .parse_instruction(
+                    &compiled_instruction,
+                    &accounts_buf,
+                    signature,
+                    slot,
+                    block_time,
+                    program_received_time_ms,
+                    format!("{index}"),
+                )
+                .await
+                && !events.is_empty()
+            {
+                if succeeded
+                    && let Some(inn) = meta.inner_instructions.iter().find(|ii| ii.index == index as u32)
+                {
+                    events.iter_mut().for_each(|event| {
+                        let (transfer_datas, swap_data) = parse_transfer_datas_from_next_proto_instructions(
+                            event.clone_boxed(),
+                            inn,
+                            -1_i8,
+                            &accounts_buf,
+                        );
+                        event.set_transfer_datas(transfer_datas, swap_data);
+                    });
+                }
+                instruction_events.extend(events);
+            }
+        }
+
+        // 解析内联指令事件（仅限我们关心的 Program）
+        let mut inner_instruction_events = Vec::with_capacity(8);
+        if succeeded {
+            for inner_instruction in &meta.inner_instructions {
+                for (idx, instruction) in inner_instruction.instructions.iter().enumerate() {
+                    if instruction.data.is_empty() {
                         continue;
                     }
+                    let Some(program_id) = accounts
+                        .get(instruction.program_id_index as usize)
+                        .filter(|program_id| self.should_handle(program_id))
+                    else {
+                        continue;
+                    };
 
-                    let i_index = instruction_event.index();
-                    let in_index = inner_instruction_event.index();
+                    let index = format!("{}.{}", inner_instruction.index, idx);
+                    let compiled_instruction = CompiledInstruction {
+                        program_id_index: instruction.program_id_index as u8,
+                        accounts: instruction.accounts.clone(),
+                        data: instruction.data.clone(),
+                    };
 
-                    if !i_index.contains('.') && in_index.contains('.') {
-                        if let Some(first) = in_index.split('.').next() {
-                            if first == i_index {
-                                instruction_event.merge(inner_instruction_event.clone_boxed());
-                                break;
-                            }
-                        }
-                    } else if i_index.contains('.') && in_index.contains('.') {
-                        let mut i_iter = i_index.split('.');
-                        let mut in_iter = in_index.split('.');
-
-                        if let (Some(a), Some(b)) = (i_iter.next(), in_iter.next()) {
-                            if a == b {
-                                let i_child = i_iter.next().and_then(|s| s.parse::<u32>().ok()).unwrap_or(0);
-                                let in_child = in_iter.next().and_then(|s| s.parse::<u32>().ok()).unwrap_or(0);
-                                if in_child > i_child {
-                                    instruction_event.merge(inner_instruction_event.clone_boxed());
-                                    break;
-                                }
-                            }
-                        }
+                    // 指令解析
+                    if let Ok(mut events) = self
+                        .parse_instruction(
+                            &compiled_instruction,
+                            &accounts,
+                            signature,
+                            slot,
+                            block_time,
+                            program_received_time_ms,
+                            index.clone(),
+                        )
+                        .await
+                        && !events.is_empty()
+                    {
+                        events.iter_mut().for_each(|event| {
+                            let (transfer_datas, swap_data) = parse_transfer_datas_from_next_proto_instructions(
+                                event.clone_boxed(),
+                                inner_instruction,
+                                idx as i8,
+                                &accounts,
+                            );
+                            event.set_transfer_datas(transfer_datas, swap_data);
+                        });
+                        instruction_events.extend(events);
+                    }
+
+                    // 内联解析（原始字节，按被调用程序）
+                    let mut events = self.parse_events_from_program_inner_instruction(
+                        program_id,
+                        &instruction.data,
+                        signature,
+                        slot.unwrap_or(0),
+                        block_time,
+                        program_received_time_ms,
+                        index,
+                    );
+                    if !events.is_empty() {
+                        events.iter_mut().for_each(|event| {
+                            let (transfer_datas, swap_data) = parse_transfer_datas_from_next_proto_instructions(
+                                event.clone_boxed(),
+                                inner_instruction,
+                                idx as i8,
+                                &accounts,
+                            );
+                            event.set_transfer_datas(transfer_datas, swap_data);
+                        });
+                        inner_instruction_events.extend(events);
                     }
                 }
             }
         }
 
+        // 合并同一 id 的内联/普通事件
+        merge_inner_instruction_events(&mut instruction_events, &inner_instruction_events);
+        if !succeeded {
+            instruction_events.iter_mut().for_each(|event| event.set_succeeded(false));
+        }
+
         Ok(self.process_events(instruction_events, bot_wallet))
     }
 
@@ -437,6 +650,50 @@ pub trait EventParser: Send + Sync {
     fn supported_program_ids(&self) -> Vec<Pubkey>;
 }
 
+/// 合并同一 id 的内联/普通事件（内联事件补充到其所属的外层指令事件上）
+fn merge_inner_instruction_events(
+    instruction_events: &mut [Box<dyn UnifiedEvent>],
+    inner_instruction_events: &[Box<dyn UnifiedEvent>],
+) {
+    if !instruction_events.is_empty() && !inner_instruction_events.is_empty() {
+        for instruction_event in instruction_events.iter_mut() {
+            for inner_instruction_event in inner_instruction_events {
+                if instruction_event.id() != inner_instruction_event.id() {
+                    continue;
+                }
+
+                let i_index = instruction_event.index();
+                let in_index = inner_instruction_event.index();
+
+                if !i_index.contains('.') && in_index.contains('.') {
+                    if in_index.split('.').next() == Some(i_index.as_str()) {
+                        instruction_event.merge(inner_instruction_event.clone_boxed());
+                        if !instruction_event.merge_all_inner() {
+                            break;
+                        }
+                    }
+                } else if i_index.contains('.') && in_index.contains('.') {
+                    let mut i_iter = i_index.split('.');
+                    let mut in_iter = in_index.split('.');
+
+                    if let (Some(a), Some(b)) = (i_iter.next(), in_iter.next())
+                        && a == b
+                    {
+                        let i_child = i_iter.next().and_then(|s| s.parse::<u32>().ok()).unwrap_or(0);
+                        let in_child = in_iter.next().and_then(|s| s.parse::<u32>().ok()).unwrap_or(0);
+                        if in_child > i_child {
+                            instruction_event.merge(inner_instruction_event.clone_boxed());
+                            if !instruction_event.merge_all_inner() {
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 // 为 Box<dyn UnifiedEvent> 实现 Clone
 impl Clone for Box<dyn UnifiedEvent> {
     fn clone(&self) -> Self {
@@ -572,12 +829,32 @@ impl EventParser for GenericEventParser {
         program_received_time_ms: i64,
         index: String,
     ) -> Vec<Box<dyn UnifiedEvent>> {
-        // base58 -> bytes；不足 16 字节直接返回
+        // base58 -> bytes
         let decoded = bs58::decode(&inner_instruction.data).into_vec().unwrap_or_default();
+        self.parse_events_from_inner_instruction_data(
+            &decoded,
+            signature,
+            slot,
+            block_time,
+            program_received_time_ms,
+            index,
+        )
+    }
+
+    fn parse_events_from_inner_instruction_data(
+        &self,
+        decoded: &[u8],
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        // 不足 16 字节直接返回
         if decoded.len() < 16 {
             return Vec::new();
         }
-        let disc_hex = format!("0x{}", hex::encode(&decoded));
+        let disc_hex = format!("0x{}", hex::encode(decoded));
         let data = &decoded[16..];
 
         let mut events = Vec::new();
@@ -670,4 +947,168 @@ impl EventParser for GenericEventParser {
     fn supported_program_ids(&self) -> Vec<Pubkey> {
         self.program_ids.clone()
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::streaming::events::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+    use crate::streaming::events::protocols::pumpfun::{
+        discriminators, PumpFunEventParser, PUMPFUN_TRADE_EVENT_LOG_SIZE,
+    };
+    use crate::streaming::grpc::types::TransactionPayload;
+    use yellowstone_grpc_proto::prelude::{
+        CompiledInstruction as ProtoInstruction, InnerInstruction, InnerInstructions, Message,
+        MessageAddressTableLookup, MessageHeader, Transaction, TransactionStatusMeta,
+    };
+
+    fn key(seed: u8) -> Pubkey {
+        Pubkey::new_from_array([seed; 32])
+    }
+
+    /// PumpFun 卖出交易：global 来自地址查找表，内联指令携带 TradeEvent（区块解析测试同样使用）
+    pub(crate) fn pumpfun_sell_fixture() -> SubscribeUpdateTransactionInfo {
+        let (user, bonding_curve, associated_bonding_curve, associated_user) =
+            (key(1), key(2), key(3), key(4));
+        let (fee_recipient, creator_vault, mint, global) = (key(5), key(6), key(7), key(8));
+        let (event_authority, creator, table) = (key(9), key(10), key(11));
+        // token program 仅作为占位账户
+
+        // 静态账户：可写 0..=5，只读 6..=10；查找表只读账户位于 11
+        let account_keys = [
+            user,
+            bonding_curve,
+            associated_bonding_curve,
+            associated_user,
+            fee_recipient,
+            creator_vault,
+            mint,
+            solana_sdk::system_program::ID,
+            key(12),
+            event_authority,
+            PUMPFUN_PROGRAM_ID,
+        ];
+
+        let mut sell = discriminators::SELL_IX.to_vec();
+        sell.extend_from_slice(&1_000_000u64.to_le_bytes());
+        sell.extend_from_slice(&90_000u64.to_le_bytes());
+
+        let mut trade_event = hex::decode(&discriminators::TRADE_EVENT[2..]).unwrap();
+        trade_event.extend_from_slice(mint.as_ref());
+        trade_event.extend_from_slice(&100_000u64.to_le_bytes());
+        trade_event.extend_from_slice(&1_000_000u64.to_le_bytes());
+        trade_event.push(0);
+        trade_event.extend_from_slice(user.as_ref());
+        trade_event.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        for reserve in [30_000_000_000u64, 1_000_000_000_000, 1_000_000_000, 800_000_000_000] {
+            trade_event.extend_from_slice(&reserve.to_le_bytes());
+        }
+        trade_event.extend_from_slice(fee_recipient.as_ref());
+        trade_event.extend_from_slice(&95u64.to_le_bytes());
+        trade_event.extend_from_slice(&950u64.to_le_bytes());
+        trade_event.extend_from_slice(creator.as_ref());
+        trade_event.extend_from_slice(&5u64.to_le_bytes());
+        trade_event.extend_from_slice(&50u64.to_le_bytes());
+        trade_event.push(1);
+        for volume in [0u64, 0, 100_000] {
+            trade_event.extend_from_slice(&volume.to_le_bytes());
+        }
+        trade_event.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        assert_eq!(trade_event.len(), 16 + PUMPFUN_TRADE_EVENT_LOG_SIZE);
+
+        SubscribeUpdateTransactionInfo {
+            signature: vec![7; 64],
+            is_vote: false,
+            transaction: Some(Transaction {
+                signatures: vec![vec![7; 64]],
+                message: Some(Message {
+                    header: Some(MessageHeader {
+                        num_required_signatures: 1,
+                        num_readonly_signed_accounts: 0,
+                        num_readonly_unsigned_accounts: 5,
+                    }),
+                    account_keys: account_keys.iter().map(|key| key.to_bytes().to_vec()).collect(),
+                    recent_blockhash: vec![0; 32],
+                    instructions: vec![ProtoInstruction {
+                        program_id_index: 10,
+                        accounts: vec![11, 4, 6, 1, 2, 3, 0, 7, 5, 8, 9, 10],
+                        data: sell,
+                    }],
+                    versioned: true,
+                    address_table_lookups: vec![MessageAddressTableLookup {
+                        account_key: table.to_bytes().to_vec(),
+                        writable_indexes: vec![],
+                        readonly_indexes: vec![0],
+                    }],
+                }),
+            }),
+            meta: Some(TransactionStatusMeta {
+                fee: 5000,
+                pre_balances: vec![0; 12],
+                post_balances: vec![0; 12],
+                inner_instructions: vec![InnerInstructions {
+                    index: 0,
+                    instructions: vec![InnerInstruction {
+                        program_id_index: 10,
+                        accounts: vec![9],
+                        data: trade_event,
+                        stack_height: Some(2),
+                    }],
+                }],
+                loaded_readonly_addresses: vec![global.to_bytes().to_vec()],
+                return_data_none: true,
+                ..Default::default()
+            }),
+            index: 0,
+        }
+    }
+
+    /// 去掉与解析路径无关的耗时字段
+    fn normalized(events: Vec<Box<dyn UnifiedEvent>>) -> Vec<PumpFunTradeEvent> {
+        events
+            .into_iter()
+            .map(|event| {
+                let mut trade = event
+                    .as_any()
+                    .downcast_ref::<PumpFunTradeEvent>()
+                    .expect("pumpfun trade")
+                    .clone();
+                trade.metadata.program_handle_time_consuming_ms = 0;
+                trade
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn proto_and_encoded_paths_parse_the_same_events() {
+        let parser = PumpFunEventParser::new();
+        let info = pumpfun_sell_fixture();
+        let encoded =
+            TransactionPayload::Proto(info.clone()).to_encoded().expect("encodable fixture");
+        let signature = bs58::encode(&info.signature).into_string();
+        let block_time = Some(Timestamp { seconds: 1_700_000_000, nanos: 0 });
+
+        let proto = parser
+            .parse_proto_transaction(&info, &signature, Some(42), block_time, 0, None)
+            .await
+            .unwrap();
+        let encoded = parser
+            .parse_transaction(&encoded, &signature, Some(42), block_time, 0, None)
+            .await
+            .unwrap();
+
+        let proto = normalized(proto);
+        assert_eq!(proto.len(), 1);
+        assert_eq!(proto, normalized(encoded));
+
+        // 指令账户（含查找表账户）与内联 TradeEvent 均已合并到同一事件
+        let trade = &proto[0];
+        assert_eq!(trade.global, key(8));
+        assert_eq!(trade.mint, key(7));
+        assert_eq!(trade.user, key(1));
+        assert_eq!(trade.program, PUMPFUN_PROGRAM_ID);
+        assert_eq!(trade.min_sol_output, 90_000);
+        assert_eq!(trade.sol_amount, 100_000);
+        assert_eq!(trade.creator, key(10));
+        assert!(!trade.is_buy);
+    }
+}
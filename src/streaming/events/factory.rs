@@ -51,6 +51,16 @@ pub enum Protocol {
 /// impl description
 impl Protocol {
 
+    // ─── const 'ALL' ───
+    pub const ALL: [Protocol; 6] = [
+        Protocol::PumpSwap,
+        Protocol::PumpFun,
+        Protocol::Bonk,
+        Protocol::RaydiumCpmm,
+        Protocol::RaydiumClmm,
+        Protocol::RaydiumAmmV4
+    ];
+
     // ─── fn 'get_program_id' ───
     /// fn description
     pub fn get_program_id(&self) -> Vec<Pubkey> {
@@ -36,4 +36,20 @@ macro_rules! eventsmatch {
 
 // ─── use 'eventsmatch' ───
 /// use description
-pub use eventsmatch;
\ No newline at end of file
+pub use eventsmatch;
+
+// ─── macro_rules 'eventsmatchasync' ───
+/// macro_rules description
+#[macro_export]
+macro_rules! eventsmatchasync {
+    ($event:expr, {$($event_type:ty => $handler:expr),* $(,)?}) => {
+        $(if let Some(typed_event) = {$event.as_any().downcast_ref::<$event_type>().cloned()} {$handler(typed_event).await;} else)*
+        {
+            // No action defined
+        }
+    };
+}
+
+// ─── use 'eventsmatchasync' ───
+/// use description
+pub use eventsmatchasync;
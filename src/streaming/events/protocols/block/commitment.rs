@@ -0,0 +1,96 @@
+use crate::impl_unified_event;
+use crate::streaming::events::common::{types::EventType, EventMetadata};
+use crate::streaming::events::protocols::block::slot::SlotStatus;
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+
+/// 回滚原因
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub enum RollbackReason {
+    /// 收到该 slot 的 dead 状态
+    #[default]
+    Dead,
+    /// 更高的 slot 已 finalized，而该 slot 始终未被确认（所在分叉被放弃）
+    Orphaned,
+}
+
+/// 之前以 processed 提交级别发出的事件已被确认
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EventConfirmedEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    /// 原事件 id
+    pub original_id: String,
+    pub original_event_type: EventType,
+    pub original_signature: String,
+    pub slot: u64,
+    /// confirmed 或 finalized
+    pub status: SlotStatus,
+}
+
+impl EventConfirmedEvent {
+    pub fn new(
+        original_id: String,
+        original_event_type: EventType,
+        original_signature: String,
+        slot: u64,
+        status: SlotStatus,
+    ) -> Self {
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        let metadata = EventMetadata::new(
+            format!("confirmed_{}", original_id),
+            original_signature.clone(),
+            slot,
+            now_ms / 1000,
+            now_ms,
+            crate::streaming::events::common::types::ProtocolType::Common,
+            EventType::EventConfirmed,
+            solana_sdk::pubkey::Pubkey::default(),
+            "".to_string(),
+            now_ms,
+        );
+        Self { metadata, original_id, original_event_type, original_signature, slot, status }
+    }
+}
+
+/// 之前以 processed 提交级别发出的事件所在 slot 已失效
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EventRolledBackEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    /// 原事件 id
+    pub original_id: String,
+    pub original_event_type: EventType,
+    pub original_signature: String,
+    pub slot: u64,
+    pub reason: RollbackReason,
+}
+
+impl EventRolledBackEvent {
+    pub fn new(
+        original_id: String,
+        original_event_type: EventType,
+        original_signature: String,
+        slot: u64,
+        reason: RollbackReason,
+    ) -> Self {
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        let metadata = EventMetadata::new(
+            format!("rolledback_{}", original_id),
+            original_signature.clone(),
+            slot,
+            now_ms / 1000,
+            now_ms,
+            crate::streaming::events::common::types::ProtocolType::Common,
+            EventType::EventRolledBack,
+            solana_sdk::pubkey::Pubkey::default(),
+            "".to_string(),
+            now_ms,
+        );
+        Self { metadata, original_id, original_event_type, original_signature, slot, reason }
+    }
+}
+
+// 使用macro生成UnifiedEvent实现
+impl_unified_event!(EventConfirmedEvent,);
+impl_unified_event!(EventRolledBackEvent,);
@@ -1 +1,3 @@
-pub mod blockmeta;
\ No newline at end of file
+pub mod blockmeta;
+pub mod commitment;
+pub mod slot;
@@ -0,0 +1,111 @@
+use crate::impl_unified_event;
+use crate::streaming::events::common::{types::EventType, EventMetadata};
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+
+/// Slot 状态（仅保留与提交级别相关的状态）
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, BorshDeserialize)]
+pub enum SlotStatus {
+    #[default]
+    Processed,
+    Confirmed,
+    Finalized,
+    /// 该 slot 被跳过 / 所在分叉被放弃
+    Dead,
+}
+
+impl SlotStatus {
+    /// 由 Geyser 的 SlotStatus 数值转换；首分片、完成、建 bank 等中间状态返回 None
+    pub fn from_geyser(status: i32) -> Option<Self> {
+        use yellowstone_grpc_proto::geyser::SlotStatus as GeyserSlotStatus;
+        match GeyserSlotStatus::try_from(status).ok()? {
+            GeyserSlotStatus::SlotProcessed => Some(Self::Processed),
+            GeyserSlotStatus::SlotConfirmed => Some(Self::Confirmed),
+            GeyserSlotStatus::SlotFinalized => Some(Self::Finalized),
+            GeyserSlotStatus::SlotDead => Some(Self::Dead),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Processed => "processed",
+            Self::Confirmed => "confirmed",
+            Self::Finalized => "finalized",
+            Self::Dead => "dead",
+        }
+    }
+}
+
+/// Slot 状态事件
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SlotEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub slot: u64,
+    pub parent: Option<u64>,
+    pub status: SlotStatus,
+    pub dead_error: Option<String>,
+}
+
+impl SlotEvent {
+    pub fn new(
+        slot: u64,
+        parent: Option<u64>,
+        status: SlotStatus,
+        dead_error: Option<String>,
+        created_at_ms: i64,
+    ) -> Self {
+        let metadata = EventMetadata::new(
+            format!("slot_{}_{}", slot, status.as_str()),
+            "".to_string(),
+            slot,
+            created_at_ms / 1000,
+            created_at_ms,
+            crate::streaming::events::common::types::ProtocolType::Common,
+            EventType::Slot,
+            solana_sdk::pubkey::Pubkey::default(),
+            "".to_string(),
+            chrono::Utc::now().timestamp_millis(),
+        );
+        Self { metadata, slot, parent, status, dead_error }
+    }
+}
+
+// 使用macro生成UnifiedEvent实现
+impl_unified_event!(SlotEvent,);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yellowstone_grpc_proto::geyser::SlotStatus as GeyserSlotStatus;
+
+    #[test]
+    fn from_geyser_keeps_commitment_statuses_only() {
+        let convert = |status: GeyserSlotStatus| SlotStatus::from_geyser(status as i32);
+
+        assert_eq!(convert(GeyserSlotStatus::SlotProcessed), Some(SlotStatus::Processed));
+        assert_eq!(convert(GeyserSlotStatus::SlotConfirmed), Some(SlotStatus::Confirmed));
+        assert_eq!(convert(GeyserSlotStatus::SlotFinalized), Some(SlotStatus::Finalized));
+        assert_eq!(convert(GeyserSlotStatus::SlotDead), Some(SlotStatus::Dead));
+
+        // 中间状态与未知数值都不产生事件
+        assert_eq!(convert(GeyserSlotStatus::SlotFirstShredReceived), None);
+        assert_eq!(convert(GeyserSlotStatus::SlotCompleted), None);
+        assert_eq!(convert(GeyserSlotStatus::SlotCreatedBank), None);
+        assert_eq!(SlotStatus::from_geyser(i32::MAX), None);
+    }
+
+    #[test]
+    fn slot_event_id_includes_status() {
+        let confirmed = SlotEvent::new(7, Some(6), SlotStatus::Confirmed, None, 1_500);
+        let dead = SlotEvent::new(7, Some(6), SlotStatus::Dead, Some("skipped".to_string()), 1_500);
+
+        assert_eq!(confirmed.metadata.id, "slot_7_confirmed");
+        assert_eq!(confirmed.metadata.slot, 7);
+        assert_eq!(confirmed.metadata.block_time, 1);
+        assert_eq!(confirmed.metadata.event_type, EventType::Slot);
+        // 同一 slot 的不同状态不能被去重为同一事件
+        assert_ne!(confirmed.metadata.id, dead.metadata.id);
+    }
+}
@@ -12,6 +12,7 @@ use crate::streaming::events::{
         bonk_pool_create_event_log_decode, bonk_trade_event_log_decode, discriminators, AmmFeeOn, BonkMigrateToAmmEvent, BonkMigrateToCpswapEvent, BonkPoolCreateEvent, BonkTradeEvent, ConstantCurve, CurveParams, FixedCurve, LinearCurve, MintParams, TradeDirection, VestingParams
     },
 };
+use crate::streaming::events::protocols::idl::bonk::{args, ix_accounts};
 
 /// Bonk Program ID
 pub const BONK_PROGRAM_ID: Pubkey =
@@ -157,37 +158,42 @@ impl BonkEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 18 {
+        if accounts.len() < ix_accounts::buy_exact_in::LEN {
             return None;
         }
 
-        let amount_in = Scripts::readu64le(data, 0)?;
-        let minimum_amount_out = Scripts::readu64le(data, 8)?;
-        let share_fee_rate = Scripts::readu64le(data, 16)?;
+        let args::BuyExactIn { amount_in, minimum_amount_out, share_fee_rate } =
+            args::BuyExactIn::decode(data)?;
 
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}", metadata.signature, accounts[4]));
+        metadata.set_id(format!(
+            "{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::buy_exact_in::POOL_STATE]
+        ));
 
         Some(Box::new(BonkTradeEvent {
             metadata,
             amount_in,
             minimum_amount_out,
             share_fee_rate,
-            payer: accounts[0],
-            global_config: accounts[2],
-            platform_config: accounts[3],
-            pool_state: accounts[4],
-            user_base_token: accounts[5],
-            user_quote_token: accounts[6],
-            base_vault: accounts[7],
-            quote_vault: accounts[8],
-            base_token_mint: accounts[9],
-            quote_token_mint: accounts[10],
-            base_token_program: accounts[11],
-            quote_token_program: accounts[12],
-            system_program: accounts[15],
-            platform_associated_account: accounts[16],
-            creator_associated_account: accounts[17],
+            payer: accounts[ix_accounts::buy_exact_in::PAYER],
+            global_config: accounts[ix_accounts::buy_exact_in::GLOBAL_CONFIG],
+            platform_config: accounts[ix_accounts::buy_exact_in::PLATFORM_CONFIG],
+            pool_state: accounts[ix_accounts::buy_exact_in::POOL_STATE],
+            user_base_token: accounts[ix_accounts::buy_exact_in::USER_BASE_TOKEN],
+            user_quote_token: accounts[ix_accounts::buy_exact_in::USER_QUOTE_TOKEN],
+            base_vault: accounts[ix_accounts::buy_exact_in::BASE_VAULT],
+            quote_vault: accounts[ix_accounts::buy_exact_in::QUOTE_VAULT],
+            base_token_mint: accounts[ix_accounts::buy_exact_in::BASE_TOKEN_MINT],
+            quote_token_mint: accounts[ix_accounts::buy_exact_in::QUOTE_TOKEN_MINT],
+            base_token_program: accounts[ix_accounts::buy_exact_in::BASE_TOKEN_PROGRAM],
+            quote_token_program: accounts[ix_accounts::buy_exact_in::QUOTE_TOKEN_PROGRAM],
+            system_program: accounts[ix_accounts::buy_exact_in::SYSTEM_PROGRAM],
+            platform_associated_account: accounts
+                [ix_accounts::buy_exact_in::PLATFORM_ASSOCIATED_ACCOUNT],
+            creator_associated_account: accounts
+                [ix_accounts::buy_exact_in::CREATOR_ASSOCIATED_ACCOUNT],
             trade_direction: TradeDirection::Buy,
             ..Default::default()
         }))
@@ -198,37 +204,42 @@ impl BonkEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 18 {
+        if accounts.len() < ix_accounts::buy_exact_out::LEN {
             return None;
         }
 
-        let amount_out = Scripts::readu64le(data, 0)?;
-        let maximum_amount_in = Scripts::readu64le(data, 8)?;
-        let share_fee_rate = Scripts::readu64le(data, 16)?;
+        let args::BuyExactOut { amount_out, maximum_amount_in, share_fee_rate } =
+            args::BuyExactOut::decode(data)?;
 
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}", metadata.signature, accounts[4]));
+        metadata.set_id(format!(
+            "{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::buy_exact_out::POOL_STATE]
+        ));
 
         Some(Box::new(BonkTradeEvent {
             metadata,
             amount_out,
             maximum_amount_in,
             share_fee_rate,
-            payer: accounts[0],
-            global_config: accounts[2],
-            platform_config: accounts[3],
-            pool_state: accounts[4],
-            user_base_token: accounts[5],
-            user_quote_token: accounts[6],
-            base_vault: accounts[7],
-            quote_vault: accounts[8],
-            base_token_mint: accounts[9],
-            quote_token_mint: accounts[10],
-            base_token_program: accounts[11],
-            quote_token_program: accounts[12],
-            system_program: accounts[15],
-            platform_associated_account: accounts[16],
-            creator_associated_account: accounts[17],
+            payer: accounts[ix_accounts::buy_exact_out::PAYER],
+            global_config: accounts[ix_accounts::buy_exact_out::GLOBAL_CONFIG],
+            platform_config: accounts[ix_accounts::buy_exact_out::PLATFORM_CONFIG],
+            pool_state: accounts[ix_accounts::buy_exact_out::POOL_STATE],
+            user_base_token: accounts[ix_accounts::buy_exact_out::USER_BASE_TOKEN],
+            user_quote_token: accounts[ix_accounts::buy_exact_out::USER_QUOTE_TOKEN],
+            base_vault: accounts[ix_accounts::buy_exact_out::BASE_VAULT],
+            quote_vault: accounts[ix_accounts::buy_exact_out::QUOTE_VAULT],
+            base_token_mint: accounts[ix_accounts::buy_exact_out::BASE_TOKEN_MINT],
+            quote_token_mint: accounts[ix_accounts::buy_exact_out::QUOTE_TOKEN_MINT],
+            base_token_program: accounts[ix_accounts::buy_exact_out::BASE_TOKEN_PROGRAM],
+            quote_token_program: accounts[ix_accounts::buy_exact_out::QUOTE_TOKEN_PROGRAM],
+            system_program: accounts[ix_accounts::buy_exact_out::SYSTEM_PROGRAM],
+            platform_associated_account: accounts
+                [ix_accounts::buy_exact_out::PLATFORM_ASSOCIATED_ACCOUNT],
+            creator_associated_account: accounts
+                [ix_accounts::buy_exact_out::CREATOR_ASSOCIATED_ACCOUNT],
             trade_direction: TradeDirection::Buy,
             ..Default::default()
         }))
@@ -239,37 +250,42 @@ impl BonkEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 18 {
+        if accounts.len() < ix_accounts::sell_exact_in::LEN {
             return None;
         }
 
-        let amount_in = Scripts::readu64le(data, 0)?;
-        let minimum_amount_out = Scripts::readu64le(data, 8)?;
-        let share_fee_rate = Scripts::readu64le(data, 16)?;
+        let args::SellExactIn { amount_in, minimum_amount_out, share_fee_rate } =
+            args::SellExactIn::decode(data)?;
 
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}", metadata.signature, accounts[4]));
+        metadata.set_id(format!(
+            "{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::sell_exact_in::POOL_STATE]
+        ));
 
         Some(Box::new(BonkTradeEvent {
             metadata,
             amount_in,
             minimum_amount_out,
             share_fee_rate,
-            payer: accounts[0],
-            global_config: accounts[2],
-            platform_config: accounts[3],
-            pool_state: accounts[4],
-            user_base_token: accounts[5],
-            user_quote_token: accounts[6],
-            base_vault: accounts[7],
-            quote_vault: accounts[8],
-            base_token_mint: accounts[9],
-            quote_token_mint: accounts[10],
-            base_token_program: accounts[11],
-            quote_token_program: accounts[12],
-            system_program: accounts[15],
-            platform_associated_account: accounts[16],
-            creator_associated_account: accounts[17],
+            payer: accounts[ix_accounts::sell_exact_in::PAYER],
+            global_config: accounts[ix_accounts::sell_exact_in::GLOBAL_CONFIG],
+            platform_config: accounts[ix_accounts::sell_exact_in::PLATFORM_CONFIG],
+            pool_state: accounts[ix_accounts::sell_exact_in::POOL_STATE],
+            user_base_token: accounts[ix_accounts::sell_exact_in::USER_BASE_TOKEN],
+            user_quote_token: accounts[ix_accounts::sell_exact_in::USER_QUOTE_TOKEN],
+            base_vault: accounts[ix_accounts::sell_exact_in::BASE_VAULT],
+            quote_vault: accounts[ix_accounts::sell_exact_in::QUOTE_VAULT],
+            base_token_mint: accounts[ix_accounts::sell_exact_in::BASE_TOKEN_MINT],
+            quote_token_mint: accounts[ix_accounts::sell_exact_in::QUOTE_TOKEN_MINT],
+            base_token_program: accounts[ix_accounts::sell_exact_in::BASE_TOKEN_PROGRAM],
+            quote_token_program: accounts[ix_accounts::sell_exact_in::QUOTE_TOKEN_PROGRAM],
+            system_program: accounts[ix_accounts::sell_exact_in::SYSTEM_PROGRAM],
+            platform_associated_account: accounts
+                [ix_accounts::sell_exact_in::PLATFORM_ASSOCIATED_ACCOUNT],
+            creator_associated_account: accounts
+                [ix_accounts::sell_exact_in::CREATOR_ASSOCIATED_ACCOUNT],
             trade_direction: TradeDirection::Sell,
             ..Default::default()
         }))
@@ -280,37 +296,42 @@ impl BonkEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 18 {
+        if accounts.len() < ix_accounts::sell_exact_out::LEN {
             return None;
         }
 
-        let amount_out = Scripts::readu64le(data, 0)?;
-        let maximum_amount_in = Scripts::readu64le(data, 8)?;
-        let share_fee_rate = Scripts::readu64le(data, 16)?;
+        let args::SellExactOut { amount_out, maximum_amount_in, share_fee_rate } =
+            args::SellExactOut::decode(data)?;
 
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}", metadata.signature, accounts[4]));
+        metadata.set_id(format!(
+            "{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::sell_exact_out::POOL_STATE]
+        ));
 
         Some(Box::new(BonkTradeEvent {
             metadata,
             amount_out,
             maximum_amount_in,
             share_fee_rate,
-            payer: accounts[0],
-            global_config: accounts[2],
-            platform_config: accounts[3],
-            pool_state: accounts[4],
-            user_base_token: accounts[5],
-            user_quote_token: accounts[6],
-            base_vault: accounts[7],
-            quote_vault: accounts[8],
-            base_token_mint: accounts[9],
-            quote_token_mint: accounts[10],
-            base_token_program: accounts[11],
-            quote_token_program: accounts[12],
-            system_program: accounts[15],
-            platform_associated_account: accounts[16],
-            creator_associated_account: accounts[17],
+            payer: accounts[ix_accounts::sell_exact_out::PAYER],
+            global_config: accounts[ix_accounts::sell_exact_out::GLOBAL_CONFIG],
+            platform_config: accounts[ix_accounts::sell_exact_out::PLATFORM_CONFIG],
+            pool_state: accounts[ix_accounts::sell_exact_out::POOL_STATE],
+            user_base_token: accounts[ix_accounts::sell_exact_out::USER_BASE_TOKEN],
+            user_quote_token: accounts[ix_accounts::sell_exact_out::USER_QUOTE_TOKEN],
+            base_vault: accounts[ix_accounts::sell_exact_out::BASE_VAULT],
+            quote_vault: accounts[ix_accounts::sell_exact_out::QUOTE_VAULT],
+            base_token_mint: accounts[ix_accounts::sell_exact_out::BASE_TOKEN_MINT],
+            quote_token_mint: accounts[ix_accounts::sell_exact_out::QUOTE_TOKEN_MINT],
+            base_token_program: accounts[ix_accounts::sell_exact_out::BASE_TOKEN_PROGRAM],
+            quote_token_program: accounts[ix_accounts::sell_exact_out::QUOTE_TOKEN_PROGRAM],
+            system_program: accounts[ix_accounts::sell_exact_out::SYSTEM_PROGRAM],
+            platform_associated_account: accounts
+                [ix_accounts::sell_exact_out::PLATFORM_ASSOCIATED_ACCOUNT],
+            creator_associated_account: accounts
+                [ix_accounts::sell_exact_out::CREATOR_ASSOCIATED_ACCOUNT],
             trade_direction: TradeDirection::Sell,
             ..Default::default()
         }))
@@ -336,15 +357,15 @@ impl BonkEventParser {
 
         Some(Box::new(BonkPoolCreateEvent {
             metadata,
-            payer: accounts[0],
-            creator: accounts[1],
-            global_config: accounts[2],
-            platform_config: accounts[3],
-            pool_state: accounts[5],
-            base_mint: accounts[6],
-            quote_mint: accounts[7],
-            base_vault: accounts[8],
-            quote_vault: accounts[9],
+            payer: accounts[ix_accounts::initialize::PAYER],
+            creator: accounts[ix_accounts::initialize::CREATOR],
+            global_config: accounts[ix_accounts::initialize::GLOBAL_CONFIG],
+            platform_config: accounts[ix_accounts::initialize::PLATFORM_CONFIG],
+            pool_state: accounts[ix_accounts::initialize::POOL_STATE],
+            base_mint: accounts[ix_accounts::initialize::BASE_MINT],
+            quote_mint: accounts[ix_accounts::initialize::QUOTE_MINT],
+            base_vault: accounts[ix_accounts::initialize::BASE_VAULT],
+            quote_vault: accounts[ix_accounts::initialize::QUOTE_VAULT],
             base_mint_param,
             curve_param,
             vesting_param,
@@ -505,13 +526,12 @@ impl BonkEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 {
+        if accounts.len() < ix_accounts::migrate_to_amm::LEN {
             return None;
         }
 
-        let base_lot_size = u64::from_le_bytes(data[0..8].try_into().unwrap());
-        let quote_lot_size = u64::from_le_bytes(data[8..16].try_into().unwrap());
-        let market_vault_signer_nonce = data[16];
+        let args::MigrateToAmm { base_lot_size, quote_lot_size, market_vault_signer_nonce } =
+            args::MigrateToAmm::decode(data)?;
 
         let mut metadata = metadata;
         metadata.set_id(metadata.signature.to_string());
@@ -521,38 +541,40 @@ impl BonkEventParser {
             base_lot_size,
             quote_lot_size,
             market_vault_signer_nonce,
-            payer: accounts[0],
-            base_mint: accounts[1],
-            quote_mint: accounts[2],
-            openbook_program: accounts[3],
-            market: accounts[4],
-            request_queue: accounts[5],
-            event_queue: accounts[6],
-            bids: accounts[7],
-            asks: accounts[8],
-            market_vault_signer: accounts[9],
-            market_base_vault: accounts[10],
-            market_quote_vault: accounts[11],
-            amm_program: accounts[12],
-            amm_pool: accounts[13],
-            amm_authority: accounts[14],
-            amm_open_orders: accounts[15],
-            amm_lp_mint: accounts[16],
-            amm_base_vault: accounts[17],
-            amm_quote_vault: accounts[18],
-            amm_target_orders: accounts[19],
-            amm_config: accounts[20],
-            amm_create_fee_destination: accounts[21],
-            authority: accounts[22],
-            pool_state: accounts[23],
-            global_config: accounts[24],
-            base_vault: accounts[25],
-            quote_vault: accounts[26],
-            pool_lp_token: accounts[27],
-            spl_token_program: accounts[28],
-            associated_token_program: accounts[29],
-            system_program: accounts[30],
-            rent_program: accounts[31],
+            payer: accounts[ix_accounts::migrate_to_amm::PAYER],
+            base_mint: accounts[ix_accounts::migrate_to_amm::BASE_MINT],
+            quote_mint: accounts[ix_accounts::migrate_to_amm::QUOTE_MINT],
+            openbook_program: accounts[ix_accounts::migrate_to_amm::OPENBOOK_PROGRAM],
+            market: accounts[ix_accounts::migrate_to_amm::MARKET],
+            request_queue: accounts[ix_accounts::migrate_to_amm::REQUEST_QUEUE],
+            event_queue: accounts[ix_accounts::migrate_to_amm::EVENT_QUEUE],
+            bids: accounts[ix_accounts::migrate_to_amm::BIDS],
+            asks: accounts[ix_accounts::migrate_to_amm::ASKS],
+            market_vault_signer: accounts[ix_accounts::migrate_to_amm::MARKET_VAULT_SIGNER],
+            market_base_vault: accounts[ix_accounts::migrate_to_amm::MARKET_BASE_VAULT],
+            market_quote_vault: accounts[ix_accounts::migrate_to_amm::MARKET_QUOTE_VAULT],
+            amm_program: accounts[ix_accounts::migrate_to_amm::AMM_PROGRAM],
+            amm_pool: accounts[ix_accounts::migrate_to_amm::AMM_POOL],
+            amm_authority: accounts[ix_accounts::migrate_to_amm::AMM_AUTHORITY],
+            amm_open_orders: accounts[ix_accounts::migrate_to_amm::AMM_OPEN_ORDERS],
+            amm_lp_mint: accounts[ix_accounts::migrate_to_amm::AMM_LP_MINT],
+            amm_base_vault: accounts[ix_accounts::migrate_to_amm::AMM_BASE_VAULT],
+            amm_quote_vault: accounts[ix_accounts::migrate_to_amm::AMM_QUOTE_VAULT],
+            amm_target_orders: accounts[ix_accounts::migrate_to_amm::AMM_TARGET_ORDERS],
+            amm_config: accounts[ix_accounts::migrate_to_amm::AMM_CONFIG],
+            amm_create_fee_destination: accounts
+                [ix_accounts::migrate_to_amm::AMM_CREATE_FEE_DESTINATION],
+            authority: accounts[ix_accounts::migrate_to_amm::AUTHORITY],
+            pool_state: accounts[ix_accounts::migrate_to_amm::POOL_STATE],
+            global_config: accounts[ix_accounts::migrate_to_amm::GLOBAL_CONFIG],
+            base_vault: accounts[ix_accounts::migrate_to_amm::BASE_VAULT],
+            quote_vault: accounts[ix_accounts::migrate_to_amm::QUOTE_VAULT],
+            pool_lp_token: accounts[ix_accounts::migrate_to_amm::POOL_LP_TOKEN],
+            spl_token_program: accounts[ix_accounts::migrate_to_amm::SPL_TOKEN_PROGRAM],
+            associated_token_program: accounts
+                [ix_accounts::migrate_to_amm::ASSOCIATED_TOKEN_PROGRAM],
+            system_program: accounts[ix_accounts::migrate_to_amm::SYSTEM_PROGRAM],
+            rent_program: accounts[ix_accounts::migrate_to_amm::RENT_PROGRAM],
             ..Default::default()
         }))
     }
@@ -568,35 +590,37 @@ impl BonkEventParser {
 
         Some(Box::new(BonkMigrateToCpswapEvent {
             metadata,
-            payer: accounts[0],
-            base_mint: accounts[1],
-            quote_mint: accounts[2],
-            platform_config: accounts[3],
-            cpswap_program: accounts[4],
-            cpswap_pool: accounts[5],
-            cpswap_authority: accounts[6],
-            cpswap_lp_mint: accounts[7],
-            cpswap_base_vault: accounts[8],
-            cpswap_quote_vault: accounts[9],
-            cpswap_config: accounts[10],
-            cpswap_create_pool_fee: accounts[11],
-            cpswap_observation: accounts[12],
-            lock_program: accounts[13],
-            lock_authority: accounts[14],
-            lock_lp_vault: accounts[15],
-            authority: accounts[16],
-            pool_state: accounts[17],
-            global_config: accounts[18],
-            base_vault: accounts[19],
-            quote_vault: accounts[20],
-            pool_lp_token: accounts[21],
-            base_token_program: accounts[22],
-            quote_token_program: accounts[23],
-            associated_token_program: accounts[24],
-            system_program: accounts[25],
-            rent_program: accounts[26],
-            metadata_program: accounts[27],
-            remaining_accounts: accounts[28..].to_vec(),
+            payer: accounts[ix_accounts::migrate_to_cpswap::PAYER],
+            base_mint: accounts[ix_accounts::migrate_to_cpswap::BASE_MINT],
+            quote_mint: accounts[ix_accounts::migrate_to_cpswap::QUOTE_MINT],
+            platform_config: accounts[ix_accounts::migrate_to_cpswap::PLATFORM_CONFIG],
+            cpswap_program: accounts[ix_accounts::migrate_to_cpswap::CPSWAP_PROGRAM],
+            cpswap_pool: accounts[ix_accounts::migrate_to_cpswap::CPSWAP_POOL],
+            cpswap_authority: accounts[ix_accounts::migrate_to_cpswap::CPSWAP_AUTHORITY],
+            cpswap_lp_mint: accounts[ix_accounts::migrate_to_cpswap::CPSWAP_LP_MINT],
+            cpswap_base_vault: accounts[ix_accounts::migrate_to_cpswap::CPSWAP_BASE_VAULT],
+            cpswap_quote_vault: accounts[ix_accounts::migrate_to_cpswap::CPSWAP_QUOTE_VAULT],
+            cpswap_config: accounts[ix_accounts::migrate_to_cpswap::CPSWAP_CONFIG],
+            cpswap_create_pool_fee: accounts
+                [ix_accounts::migrate_to_cpswap::CPSWAP_CREATE_POOL_FEE],
+            cpswap_observation: accounts[ix_accounts::migrate_to_cpswap::CPSWAP_OBSERVATION],
+            lock_program: accounts[ix_accounts::migrate_to_cpswap::LOCK_PROGRAM],
+            lock_authority: accounts[ix_accounts::migrate_to_cpswap::LOCK_AUTHORITY],
+            lock_lp_vault: accounts[ix_accounts::migrate_to_cpswap::LOCK_LP_VAULT],
+            authority: accounts[ix_accounts::migrate_to_cpswap::AUTHORITY],
+            pool_state: accounts[ix_accounts::migrate_to_cpswap::POOL_STATE],
+            global_config: accounts[ix_accounts::migrate_to_cpswap::GLOBAL_CONFIG],
+            base_vault: accounts[ix_accounts::migrate_to_cpswap::BASE_VAULT],
+            quote_vault: accounts[ix_accounts::migrate_to_cpswap::QUOTE_VAULT],
+            pool_lp_token: accounts[ix_accounts::migrate_to_cpswap::POOL_LP_TOKEN],
+            base_token_program: accounts[ix_accounts::migrate_to_cpswap::BASE_TOKEN_PROGRAM],
+            quote_token_program: accounts[ix_accounts::migrate_to_cpswap::QUOTE_TOKEN_PROGRAM],
+            associated_token_program: accounts
+                [ix_accounts::migrate_to_cpswap::ASSOCIATED_TOKEN_PROGRAM],
+            system_program: accounts[ix_accounts::migrate_to_cpswap::SYSTEM_PROGRAM],
+            rent_program: accounts[ix_accounts::migrate_to_cpswap::RENT_PROGRAM],
+            metadata_program: accounts[ix_accounts::migrate_to_cpswap::METADATA_PROGRAM],
+            remaining_accounts: accounts[ix_accounts::migrate_to_cpswap::LEN..].to_vec(),
             ..Default::default()
         }))
     }
@@ -629,6 +653,25 @@ impl EventParser for BonkEventParser {
         )
     }
 
+    fn parse_events_from_inner_instruction_data(
+        &self,
+        data: &[u8],
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        self.inner.parse_events_from_inner_instruction_data(
+            data,
+            signature,
+            slot,
+            block_time,
+            program_received_time_ms,
+            index,
+        )
+    }
+
     fn parse_events_from_instruction(
         &self,
         instruction: &CompiledInstruction,
@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::events::common::{
+    types::{EventTimings, EventType, ProtocolType, SwapData, TransferData},
+    EventMetadata,
+};
+use crate::streaming::events::core::traits::UnifiedEvent;
+
+/// IdlEvent 的来源
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IdlEventKind {
+    /// 程序指令
+    #[default]
+    Instruction,
+    /// emit_cpi 发出的事件
+    Event,
+    /// 程序账户更新
+    Account,
+}
+
+/// 同一指令内通过 emit_cpi 发出的事件
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct IdlEmittedEvent {
+    pub name: String,
+    pub data: Value,
+}
+
+/// 由运行时 IDL 解码得到的通用事件
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct IdlEvent {
+    pub metadata: EventMetadata,
+    /// IDL 中的程序名
+    pub program: String,
+    pub kind: IdlEventKind,
+    /// 指令名 / 事件名 / 账户类型名（与 IDL 一致）
+    pub name: String,
+    /// IDL 账户名与公钥，顺序同指令账户；账户更新时为该账户自身
+    pub accounts: Vec<(String, Pubkey)>,
+    /// IDL 未声明的尾部账户（remaining_accounts）
+    pub remaining_accounts: Vec<Pubkey>,
+    /// 指令参数 / 事件字段 / 账户数据
+    pub args: Value,
+    /// 合并到该指令上的 emit_cpi 事件
+    pub emitted: Vec<IdlEmittedEvent>,
+}
+
+impl IdlEvent {
+    /// 按 IDL 账户名查找公钥
+    pub fn account(&self, name: &str) -> Option<Pubkey> {
+        self.accounts.iter().find(|(account, _)| account == name).map(|(_, pubkey)| *pubkey)
+    }
+
+    /// 按名称查找合并进来的 emit_cpi 事件
+    pub fn emitted(&self, name: &str) -> Option<&Value> {
+        self.emitted.iter().find(|event| event.name == name).map(|event| &event.data)
+    }
+}
+
+// 手动实现 UnifiedEvent：merge 只接收同一程序的 emit_cpi 事件并追加到 emitted（可多个）
+impl UnifiedEvent for IdlEvent {
+    fn id(&self) -> &str {
+        &self.metadata.id
+    }
+
+    fn event_type(&self) -> EventType {
+        self.metadata.event_type.clone()
+    }
+
+    fn protocol(&self) -> ProtocolType {
+        self.metadata.protocol.clone()
+    }
+
+    fn signature(&self) -> &str {
+        &self.metadata.signature
+    }
+
+    fn slot(&self) -> u64 {
+        self.metadata.slot
+    }
+
+    fn program_received_time_ms(&self) -> i64 {
+        self.metadata.program_received_time_ms
+    }
+
+    fn program_handle_time_consuming_ms(&self) -> i64 {
+        self.metadata.program_handle_time_consuming_ms
+    }
+
+    fn set_program_handle_time_consuming_ms(&mut self, program_handle_time_consuming_ms: i64) {
+        self.metadata.program_handle_time_consuming_ms = program_handle_time_consuming_ms;
+    }
+
+    fn timings(&self) -> EventTimings {
+        self.metadata.timings
+    }
+
+    fn set_timings(&mut self, timings: EventTimings) {
+        self.metadata.timings = timings;
+    }
+
+    fn filters(&self) -> &[String] {
+        &self.metadata.filters
+    }
+
+    fn set_filters(&mut self, filters: Vec<String>) {
+        self.metadata.filters = filters;
+    }
+
+    fn succeeded(&self) -> bool {
+        self.metadata.succeeded
+    }
+
+    fn set_succeeded(&mut self, succeeded: bool) {
+        self.metadata.succeeded = succeeded;
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn clone_boxed(&self) -> Box<dyn UnifiedEvent> {
+        Box::new(self.clone())
+    }
+
+    fn merge(&mut self, other: Box<dyn UnifiedEvent>) {
+        if let Some(e) = other.as_any().downcast_ref::<IdlEvent>()
+            && e.kind == IdlEventKind::Event
+            && e.metadata.program_id == self.metadata.program_id
+        {
+            self.emitted.push(IdlEmittedEvent { name: e.name.clone(), data: e.args.clone() });
+        }
+    }
+
+    /// 一条指令可以发出多个 emit_cpi 事件
+    fn merge_all_inner(&self) -> bool {
+        true
+    }
+
+    fn set_transfer_datas(
+        &mut self,
+        transfer_datas: Vec<TransferData>,
+        swap_data: Option<SwapData>,
+    ) {
+        self.metadata.set_transfer_datas(transfer_datas, swap_data);
+    }
+
+    fn index(&self) -> String {
+        self.metadata.index.clone()
+    }
+}
@@ -0,0 +1,7 @@
+pub mod events;
+pub mod parser;
+pub mod schema;
+
+pub use events::*;
+pub use parser::DynamicIdlParser;
+pub use schema::IdlSchema;
@@ -0,0 +1,483 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use prost_types::Timestamp;
+use solana_sdk::{instruction::CompiledInstruction, pubkey::Pubkey};
+use solana_transaction_status::UiCompiledInstruction;
+
+use crate::streaming::events::common::{EventMetadata, EventType, ProtocolType};
+use crate::streaming::events::core::traits::{EventParser, GenericEventParseConfig, UnifiedEvent};
+use crate::streaming::events::protocols::dynamic::events::{IdlEvent, IdlEventKind};
+use crate::streaming::events::protocols::dynamic::schema::IdlSchema;
+use crate::streaming::grpc::AccountPretty;
+
+/// emit_cpi 事件指令的固定前缀（sha256("anchor:event")[..8] 的小端序）
+const EVENT_IX_TAG: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
+
+/// 运行时加载 Anchor IDL 的通用解析器：按 IDL 中的 discriminator 匹配指令、
+/// emit_cpi 事件与账户，解码为 IdlEvent（参数以 serde_json::Value 表示），
+/// 无需为新程序编写事件类型与解析配置
+pub struct DynamicIdlParser {
+    schema: IdlSchema,
+    program_id: Pubkey,
+    /// 账户更新的 owner 为字符串，预先转换避免逐条格式化
+    program_id_str: String,
+}
+
+impl DynamicIdlParser {
+    /// program_id 为 None 时使用 IDL 中的 address（旧版为 metadata.address）
+    pub fn new(schema: IdlSchema, program_id: Option<Pubkey>) -> Result<Self> {
+        let program_id = program_id
+            .or(schema.address)
+            .ok_or_else(|| anyhow!("IDL {} has no address, program id required", schema.name))?;
+        Ok(Self { schema, program_id, program_id_str: program_id.to_string() })
+    }
+
+    pub fn from_json(json: &str, program_id: Option<Pubkey>) -> Result<Self> {
+        Self::new(IdlSchema::from_json(json)?, program_id)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>, program_id: Option<Pubkey>) -> Result<Self> {
+        let path = path.as_ref();
+        let json =
+            std::fs::read_to_string(path).with_context(|| format!("read {}", path.display()))?;
+        Self::from_json(&json, program_id)
+    }
+
+    pub fn schema(&self) -> &IdlSchema {
+        &self.schema
+    }
+
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    /// 解码指令数据（含 discriminator），accounts 为该指令按顺序排列的账户公钥
+    pub fn decode_instruction(
+        &self,
+        data: &[u8],
+        accounts: &[Pubkey],
+        mut metadata: EventMetadata,
+    ) -> Option<IdlEvent> {
+        let ix = self.schema.instruction(data)?;
+        let args = match self.schema.decode_args(&ix.args, &data[ix.discriminator.len()..]) {
+            Ok(args) => args,
+            Err(e) => {
+                log::debug!(
+                    "{}: failed to decode instruction {}: {:#}",
+                    self.schema.name,
+                    ix.name,
+                    e
+                );
+                return None;
+            }
+        };
+        metadata.event_type = EventType::IdlInstruction;
+        Some(IdlEvent {
+            metadata,
+            program: self.schema.name.clone(),
+            kind: IdlEventKind::Instruction,
+            name: ix.name.clone(),
+            accounts: ix.accounts.iter().cloned().zip(accounts.iter().copied()).collect(),
+            remaining_accounts: accounts.get(ix.accounts.len()..).unwrap_or_default().to_vec(),
+            args,
+            emitted: Vec::new(),
+        })
+    }
+
+    /// 解码 emit_cpi 内部指令数据（事件指令标签 + 事件 discriminator + 字段）
+    pub fn decode_event(&self, data: &[u8], mut metadata: EventMetadata) -> Option<IdlEvent> {
+        let data = data.strip_prefix(&EVENT_IX_TAG)?;
+        let event = self.schema.event(data)?;
+        let args = match self.schema.decode_defined(&event.name, &data[event.discriminator.len()..])
+        {
+            Ok(args) => args,
+            Err(e) => {
+                log::debug!("{}: failed to decode event {}: {:#}", self.schema.name, event.name, e);
+                return None;
+            }
+        };
+        metadata.event_type = EventType::IdlEvent;
+        Some(IdlEvent {
+            metadata,
+            program: self.schema.name.clone(),
+            kind: IdlEventKind::Event,
+            name: event.name.clone(),
+            args,
+            ..Default::default()
+        })
+    }
+
+    /// 解码程序账户更新（owner 须为该程序），未匹配任何账户类型时返回 None
+    pub fn parse_account_event(
+        &self,
+        account: &AccountPretty,
+        program_received_time_ms: i64,
+    ) -> Option<IdlEvent> {
+        if account.owner != self.program_id_str {
+            return None;
+        }
+        let item = self.schema.account(&account.data)?;
+        let args =
+            match self.schema.decode_defined(&item.name, &account.data[item.discriminator.len()..])
+            {
+                Ok(args) => args,
+                Err(e) => {
+                    log::debug!(
+                        "{}: failed to decode account {}: {:#}",
+                        self.schema.name,
+                        item.name,
+                        e
+                    );
+                    return None;
+                }
+            };
+        let pubkey = account.pubkey.parse().unwrap_or_default();
+        let mut metadata = EventMetadata {
+            slot: account.slot,
+            signature: account.signature.clone(),
+            protocol: ProtocolType::Idl,
+            event_type: EventType::IdlAccount,
+            program_id: self.program_id,
+            program_received_time_ms,
+            ..Default::default()
+        };
+        metadata.set_id(format!("{}-{}", account.pubkey, account.slot));
+        metadata.program_handle_time_consuming_ms =
+            chrono::Utc::now().timestamp_millis() - program_received_time_ms;
+        Some(IdlEvent {
+            metadata,
+            program: self.schema.name.clone(),
+            kind: IdlEventKind::Account,
+            name: item.name.clone(),
+            accounts: vec![(item.name.clone(), pubkey)],
+            args,
+            ..Default::default()
+        })
+    }
+
+    fn metadata(
+        &self,
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> EventMetadata {
+        let timestamp = block_time.unwrap_or(Timestamp { seconds: 0, nanos: 0 });
+        let block_time_ms = timestamp.seconds * 1000 + (timestamp.nanos as i64) / 1_000_000;
+        // 同一顶层指令下的指令与 emit_cpi 事件共用 id，以便合并
+        let top_level_index = index.split('.').next().unwrap_or_default().to_string();
+        let mut metadata = EventMetadata::new(
+            String::new(),
+            signature.to_string(),
+            slot,
+            timestamp.seconds,
+            block_time_ms,
+            ProtocolType::Idl,
+            EventType::Unknown,
+            self.program_id,
+            index,
+            program_received_time_ms,
+        );
+        metadata.set_id(format!("{}-{}", self.program_id, top_level_index));
+        metadata
+    }
+}
+
+#[async_trait::async_trait]
+impl EventParser for DynamicIdlParser {
+    /// 指令与事件按 IDL 动态匹配，不使用静态解析配置
+    fn inner_instruction_configs(&self) -> HashMap<&'static str, Vec<GenericEventParseConfig>> {
+        HashMap::new()
+    }
+
+    fn instruction_configs(&self) -> HashMap<Vec<u8>, Vec<GenericEventParseConfig>> {
+        HashMap::new()
+    }
+
+    fn parse_events_from_inner_instruction(
+        &self,
+        inner_instruction: &UiCompiledInstruction,
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        let decoded = bs58::decode(&inner_instruction.data).into_vec().unwrap_or_default();
+        self.parse_events_from_inner_instruction_data(
+            &decoded,
+            signature,
+            slot,
+            block_time,
+            program_received_time_ms,
+            index,
+        )
+    }
+
+    fn parse_events_from_inner_instruction_data(
+        &self,
+        data: &[u8],
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        let metadata = self.metadata(signature, slot, block_time, program_received_time_ms, index);
+        match self.decode_event(data, metadata) {
+            Some(event) => vec![Box::new(event)],
+            None => Vec::new(),
+        }
+    }
+
+    /// emit_cpi 事件由程序自调用发出：只解码本程序的内联指令，
+    /// 避免共用事件 discriminator 的其他程序（如 fork）被误认
+    fn parse_events_from_program_inner_instruction(
+        &self,
+        program_id: &Pubkey,
+        data: &[u8],
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        if !self.should_handle(program_id) {
+            return Vec::new();
+        }
+        self.parse_events_from_inner_instruction_data(
+            data,
+            signature,
+            slot,
+            block_time,
+            program_received_time_ms,
+            index,
+        )
+    }
+
+    fn parse_events_from_instruction(
+        &self,
+        instruction: &CompiledInstruction,
+        accounts: &[Pubkey],
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        match accounts.get(instruction.program_id_index as usize) {
+            Some(program_id) if self.should_handle(program_id) => {}
+            _ => return Vec::new(),
+        }
+        let account_pubkeys: Vec<Pubkey> = instruction
+            .accounts
+            .iter()
+            .filter_map(|&idx| accounts.get(idx as usize).copied())
+            .collect();
+        let metadata = self.metadata(signature, slot, block_time, program_received_time_ms, index);
+        match self.decode_instruction(&instruction.data, &account_pubkeys, metadata) {
+            Some(event) => vec![Box::new(event)],
+            None => Vec::new(),
+        }
+    }
+
+    fn should_handle(&self, program_id: &Pubkey) -> bool {
+        *program_id == self.program_id
+    }
+
+    fn supported_program_ids(&self) -> Vec<Pubkey> {
+        vec![self.program_id]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::events::protocols::idl::{pumpfun, raydiumcpmm};
+    use borsh::BorshSerialize;
+    use serde_json::json;
+
+    fn pumpfun_parser() -> DynamicIdlParser {
+        DynamicIdlParser::from_json(include_str!("../../../../../programs/pumpfun.json"), None)
+            .unwrap()
+    }
+
+    fn borsh<T: BorshSerialize>(prefix: &[u8], value: &T) -> Vec<u8> {
+        let mut data = prefix.to_vec();
+        value.serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn loads_bundled_anchor_idls() {
+        for json in [
+            include_str!("../../../../../programs/bonk.json"),
+            include_str!("../../../../../programs/pumpfun.json"),
+            include_str!("../../../../../programs/pumpswap.json"),
+            include_str!("../../../../../programs/raydiumclmm.json"),
+            include_str!("../../../../../programs/raydiumcpmm.json"),
+        ] {
+            let parser = DynamicIdlParser::from_json(json, None).unwrap();
+            assert!(!parser.schema().instructions.is_empty());
+        }
+    }
+
+    #[test]
+    fn computed_discriminators_match_generated() {
+        // 旧版 IDL 不含 discriminator，按名称计算
+        let parser = DynamicIdlParser::from_json(
+            include_str!("../../../../../programs/raydiumcpmm.json"),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parser.program_id().to_string(), raydiumcpmm::PROGRAM_ID);
+        let schema = parser.schema();
+        assert_eq!(
+            schema.instruction(raydiumcpmm::instructions::SWAP_BASE_INPUT).unwrap().name,
+            "swapBaseInput"
+        );
+        assert_eq!(schema.account(raydiumcpmm::accounts::POOL_STATE).unwrap().name, "PoolState");
+        assert_eq!(schema.event(raydiumcpmm::events::SWAP_EVENT).unwrap().name, "SwapEvent");
+    }
+
+    #[test]
+    fn instruction_merges_emitted_event() {
+        let parser = pumpfun_parser();
+        let keys: Vec<Pubkey> = (0..16).map(|_| Pubkey::new_unique()).collect();
+        let program_id = parser.program_id();
+        let mut accounts = keys.clone();
+        accounts.push(program_id);
+
+        let instruction = CompiledInstruction {
+            program_id_index: 16,
+            accounts: (0..16).collect(),
+            data: borsh(
+                pumpfun::instructions::BUY,
+                &pumpfun::args::Buy { amount: 1_000, max_sol_cost: 5 },
+            ),
+        };
+        let mut events = parser.parse_events_from_instruction(
+            &instruction,
+            &accounts,
+            "sig",
+            1,
+            None,
+            0,
+            "0".to_string(),
+        );
+        assert_eq!(events.len(), 1);
+
+        let trade = pumpfun::types::TradeEvent {
+            mint: keys[2],
+            sol_amount: 5,
+            token_amount: 1_000,
+            is_buy: true,
+            user: keys[6],
+            timestamp: 1,
+            virtual_sol_reserves: 2,
+            virtual_token_reserves: 3,
+            real_sol_reserves: 4,
+            real_token_reserves: 5,
+            fee_recipient: keys[1],
+            fee_basis_points: 95,
+            fee: 1,
+            creator: keys[9],
+            creator_fee_basis_points: 5,
+            creator_fee: 0,
+        };
+        let cpi = borsh(&[&EVENT_IX_TAG[..], pumpfun::events::TRADE_EVENT].concat(), &trade);
+        let inner = parser.parse_events_from_program_inner_instruction(
+            &program_id,
+            &cpi,
+            "sig",
+            1,
+            None,
+            0,
+            "0.1".to_string(),
+        );
+        assert_eq!(inner.len(), 1);
+        assert_eq!(inner[0].id(), events[0].id());
+        assert!(events[0].merge_all_inner());
+        events[0].merge(inner[0].clone());
+        events[0].merge(inner[0].clone());
+
+        // 其他顶层指令的事件 id 不同
+        let other = parser.parse_events_from_program_inner_instruction(
+            &program_id,
+            &cpi,
+            "sig",
+            1,
+            None,
+            0,
+            "1.1".to_string(),
+        );
+        assert_ne!(other[0].id(), events[0].id());
+
+        // 共用事件 discriminator 的其他程序不解码
+        assert!(parser
+            .parse_events_from_program_inner_instruction(
+                &Pubkey::new_unique(),
+                &cpi,
+                "sig",
+                1,
+                None,
+                0,
+                "0.1".to_string(),
+            )
+            .is_empty());
+
+        let event = events[0].as_any().downcast_ref::<IdlEvent>().unwrap();
+        assert_eq!(event.event_type(), EventType::IdlInstruction);
+        assert_eq!(event.name, "buy");
+        assert_eq!(event.account("mint"), Some(keys[2]));
+        assert_eq!(event.account("program"), Some(keys[11]));
+        assert_eq!(event.account("user_volume_accumulator"), Some(keys[13]));
+        assert_eq!(event.remaining_accounts, keys[14..].to_vec());
+        assert_eq!(event.args, json!({ "amount": 1_000, "max_sol_cost": 5 }));
+        let emitted = event.emitted("TradeEvent").unwrap();
+        assert_eq!(emitted["user"], json!(keys[6].to_string()));
+        assert_eq!(emitted["is_buy"], json!(true));
+        assert_eq!(emitted["fee_basis_points"], json!(95));
+        assert_eq!(event.emitted.len(), 2);
+    }
+
+    #[test]
+    fn decodes_program_account() {
+        let parser = pumpfun_parser();
+        let creator = Pubkey::new_unique();
+        let curve = pumpfun::types::BondingCurve {
+            virtual_token_reserves: 1,
+            virtual_sol_reserves: 2,
+            real_token_reserves: 3,
+            real_sol_reserves: 4,
+            token_total_supply: 5,
+            complete: false,
+            creator,
+        };
+        let pubkey = Pubkey::new_unique();
+        let account = AccountPretty {
+            slot: 1,
+            signature: String::new(),
+            pubkey: pubkey.to_string(),
+            executable: false,
+            lamports: 0,
+            owner: parser.program_id().to_string(),
+            rent_epoch: 0,
+            data: borsh(pumpfun::accounts::BONDING_CURVE, &curve),
+            timings: Default::default(),
+            filters: Vec::new(),
+        };
+        let event = parser.parse_account_event(&account, 0).unwrap();
+        assert_eq!(event.kind, IdlEventKind::Account);
+        assert_eq!(event.name, "BondingCurve");
+        assert_eq!(event.account("BondingCurve"), Some(pubkey));
+        assert_eq!(event.args["creator"], json!(creator.to_string()));
+        assert_eq!(event.args["complete"], json!(false));
+
+        let foreign = AccountPretty { owner: Pubkey::new_unique().to_string(), ..account };
+        assert!(parser.parse_account_event(&foreign, 0).is_none());
+    }
+}
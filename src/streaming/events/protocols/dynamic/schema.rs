@@ -0,0 +1,472 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde_json::{Map, Number, Value};
+use sha2::{Digest, Sha256};
+use solana_sdk::pubkey::Pubkey;
+
+/// 自定义类型的最大嵌套深度，防止自引用类型导致无限递归
+const MAX_DEPTH: usize = 64;
+
+/// IDL 字段类型
+#[derive(Clone, Debug, PartialEq)]
+pub enum IdlType {
+    Bool,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    U128,
+    I128,
+    F32,
+    F64,
+    String,
+    Bytes,
+    Pubkey,
+    Option(Box<IdlType>),
+    /// 4 字节标签的 COption
+    COption(Box<IdlType>),
+    Vec(Box<IdlType>),
+    Array(Box<IdlType>, usize),
+    Defined(String),
+}
+
+/// 具名字段
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdlField {
+    pub name: String,
+    pub ty: IdlType,
+}
+
+/// 结构体 / 枚举变体的字段（具名或元组）
+#[derive(Clone, Debug, PartialEq)]
+pub enum IdlFields {
+    Named(Vec<IdlField>),
+    Tuple(Vec<IdlType>),
+}
+
+/// 枚举变体
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdlVariant {
+    pub name: String,
+    pub fields: IdlFields,
+}
+
+/// 自定义类型定义
+#[derive(Clone, Debug, PartialEq)]
+pub enum IdlTypeDef {
+    Struct(IdlFields),
+    Enum(Vec<IdlVariant>),
+    /// 新版 IDL 的类型别名
+    Alias(IdlType),
+}
+
+/// 指令定义
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdlInstruction {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+    /// 展开嵌套账户组后的账户名，顺序同 instruction.accounts
+    pub accounts: Vec<String>,
+    pub args: Vec<IdlField>,
+}
+
+/// 账户 / 事件定义，数据布局为 types 中的同名类型
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdlItem {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+}
+
+/// 运行时加载的 Anchor IDL，同时支持新版（显式 discriminator）与旧版（按名称计算）格式
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdlSchema {
+    /// IDL 中的程序名
+    pub name: String,
+    /// address 或 metadata.address
+    pub address: Option<Pubkey>,
+    pub instructions: Vec<IdlInstruction>,
+    pub accounts: Vec<IdlItem>,
+    pub events: Vec<IdlItem>,
+    pub types: HashMap<String, IdlTypeDef>,
+}
+
+impl IdlSchema {
+    pub fn from_json(json: &str) -> Result<Self> {
+        let idl: Value = serde_json::from_str(json).context("invalid IDL json")?;
+        Self::from_value(&idl)
+    }
+
+    pub fn from_value(idl: &Value) -> Result<Self> {
+        let name = idl["metadata"]["name"]
+            .as_str()
+            .or_else(|| idl["name"].as_str())
+            .unwrap_or_default()
+            .to_string();
+        let address = match idl["address"].as_str().or_else(|| idl["metadata"]["address"].as_str())
+        {
+            Some(address) => Some(Pubkey::from_str(address).context("invalid IDL address")?),
+            None => None,
+        };
+
+        let mut instructions = Vec::new();
+        for ix in array(idl, "instructions") {
+            let ix_name = str_field(ix, "name")?;
+            let mut accounts = Vec::new();
+            flatten_accounts(&ix["accounts"], &mut accounts)?;
+            instructions.push(IdlInstruction {
+                name: ix_name.to_string(),
+                discriminator: discriminator(ix, "global", &to_snake(ix_name))?,
+                accounts,
+                args: named_fields(array(ix, "args"))
+                    .with_context(|| format!("instruction {}", ix_name))?,
+            });
+        }
+
+        let mut types = HashMap::new();
+        for ty in array(idl, "types") {
+            let ty_name = str_field(ty, "name")?;
+            let def = type_def(&ty["type"]).with_context(|| format!("type {}", ty_name))?;
+            types.insert(ty_name.to_string(), def);
+        }
+
+        // 旧版 IDL 的账户结构与事件字段内联定义
+        let mut accounts = Vec::new();
+        for account in array(idl, "accounts") {
+            let account_name = str_field(account, "name")?;
+            if account.get("type").is_some() && !types.contains_key(account_name) {
+                let def = type_def(&account["type"])
+                    .with_context(|| format!("account {}", account_name))?;
+                types.insert(account_name.to_string(), def);
+            }
+            accounts.push(IdlItem {
+                name: account_name.to_string(),
+                discriminator: discriminator(account, "account", account_name)?,
+            });
+        }
+
+        let mut events = Vec::new();
+        for event in array(idl, "events") {
+            let event_name = str_field(event, "name")?;
+            if event.get("fields").is_some() && !types.contains_key(event_name) {
+                let fields = named_fields(array(event, "fields"))
+                    .with_context(|| format!("event {}", event_name))?;
+                types.insert(event_name.to_string(), IdlTypeDef::Struct(IdlFields::Named(fields)));
+            }
+            events.push(IdlItem {
+                name: event_name.to_string(),
+                discriminator: discriminator(event, "event", event_name)?,
+            });
+        }
+
+        Ok(Self { name, address, instructions, accounts, events, types })
+    }
+
+    /// 按指令数据前缀匹配指令
+    pub fn instruction(&self, data: &[u8]) -> Option<&IdlInstruction> {
+        self.instructions.iter().find(|ix| data.starts_with(&ix.discriminator))
+    }
+
+    /// 按事件数据前缀匹配事件
+    pub fn event(&self, data: &[u8]) -> Option<&IdlItem> {
+        self.events.iter().find(|event| data.starts_with(&event.discriminator))
+    }
+
+    /// 按账户数据前缀匹配账户类型
+    pub fn account(&self, data: &[u8]) -> Option<&IdlItem> {
+        self.accounts.iter().find(|account| data.starts_with(&account.discriminator))
+    }
+
+    /// 解码具名字段为 JSON 对象；按前缀解码，忽略尾部多余字节
+    pub fn decode_args(&self, fields: &[IdlField], data: &[u8]) -> Result<Value> {
+        let mut cursor = data;
+        self.decode_named(fields, &mut cursor, 0)
+    }
+
+    /// 解码自定义类型（账户、事件结构）为 JSON；零拷贝账户按 packed 布局解码
+    pub fn decode_defined(&self, name: &str, data: &[u8]) -> Result<Value> {
+        let mut cursor = data;
+        self.decode_type(&IdlType::Defined(name.to_string()), &mut cursor, 0)
+    }
+
+    fn decode_named(&self, fields: &[IdlField], data: &mut &[u8], depth: usize) -> Result<Value> {
+        let mut object = Map::with_capacity(fields.len());
+        for field in fields {
+            let value = self
+                .decode_type(&field.ty, data, depth)
+                .with_context(|| format!("field {}", field.name))?;
+            object.insert(field.name.clone(), value);
+        }
+        Ok(Value::Object(object))
+    }
+
+    fn decode_fields(&self, fields: &IdlFields, data: &mut &[u8], depth: usize) -> Result<Value> {
+        match fields {
+            IdlFields::Named(fields) => self.decode_named(fields, data, depth),
+            IdlFields::Tuple(types) => types
+                .iter()
+                .map(|ty| self.decode_type(ty, data, depth))
+                .collect::<Result<Vec<_>>>()
+                .map(Value::Array),
+        }
+    }
+
+    /// borsh 解码单个值：u128 / i128 以字符串表示，公钥为 base58，
+    /// 无字段的枚举变体为变体名，其余变体为 { 变体名: 字段 }
+    fn decode_type(&self, ty: &IdlType, data: &mut &[u8], depth: usize) -> Result<Value> {
+        Ok(match ty {
+            IdlType::Bool => match take::<1>(data)?[0] {
+                0 => Value::Bool(false),
+                1 => Value::Bool(true),
+                other => bail!("invalid bool {}", other),
+            },
+            IdlType::U8 => u8::from_le_bytes(take(data)?).into(),
+            IdlType::I8 => i8::from_le_bytes(take(data)?).into(),
+            IdlType::U16 => u16::from_le_bytes(take(data)?).into(),
+            IdlType::I16 => i16::from_le_bytes(take(data)?).into(),
+            IdlType::U32 => u32::from_le_bytes(take(data)?).into(),
+            IdlType::I32 => i32::from_le_bytes(take(data)?).into(),
+            IdlType::U64 => u64::from_le_bytes(take(data)?).into(),
+            IdlType::I64 => i64::from_le_bytes(take(data)?).into(),
+            IdlType::U128 => Value::String(u128::from_le_bytes(take(data)?).to_string()),
+            IdlType::I128 => Value::String(i128::from_le_bytes(take(data)?).to_string()),
+            IdlType::F32 => float(f32::from_le_bytes(take(data)?) as f64),
+            IdlType::F64 => float(f64::from_le_bytes(take(data)?)),
+            IdlType::String => {
+                let bytes = take_len(data)?;
+                Value::String(String::from_utf8(bytes.to_vec()).context("invalid utf8 string")?)
+            }
+            IdlType::Bytes => {
+                Value::Array(take_len(data)?.iter().map(|b| Value::from(*b)).collect())
+            }
+            IdlType::Pubkey => Value::String(Pubkey::new_from_array(take(data)?).to_string()),
+            IdlType::Option(inner) => match take::<1>(data)?[0] {
+                0 => Value::Null,
+                _ => self.decode_type(inner, data, depth)?,
+            },
+            IdlType::COption(inner) => match u32::from_le_bytes(take(data)?) {
+                0 => Value::Null,
+                _ => self.decode_type(inner, data, depth)?,
+            },
+            IdlType::Vec(inner) => {
+                let len = u32::from_le_bytes(take(data)?) as usize;
+                let mut items = Vec::with_capacity(len.min(data.len()));
+                for _ in 0..len {
+                    items.push(self.decode_type(inner, data, depth)?);
+                }
+                Value::Array(items)
+            }
+            IdlType::Array(inner, len) => {
+                let mut items = Vec::with_capacity(*len);
+                for _ in 0..*len {
+                    items.push(self.decode_type(inner, data, depth)?);
+                }
+                Value::Array(items)
+            }
+            IdlType::Defined(name) => {
+                if depth >= MAX_DEPTH {
+                    bail!("type {} nested too deeply", name);
+                }
+                let def = self.types.get(name).ok_or_else(|| anyhow!("undefined type {}", name))?;
+                match def {
+                    IdlTypeDef::Struct(fields) => self.decode_fields(fields, data, depth + 1)?,
+                    IdlTypeDef::Alias(ty) => self.decode_type(ty, data, depth + 1)?,
+                    IdlTypeDef::Enum(variants) => {
+                        let tag = take::<1>(data)?[0] as usize;
+                        let variant = variants
+                            .get(tag)
+                            .ok_or_else(|| anyhow!("invalid variant {} for {}", tag, name))?;
+                        match &variant.fields {
+                            IdlFields::Named(fields) if fields.is_empty() => {
+                                Value::String(variant.name.clone())
+                            }
+                            IdlFields::Tuple(types) if types.is_empty() => {
+                                Value::String(variant.name.clone())
+                            }
+                            fields => {
+                                let mut object = Map::with_capacity(1);
+                                object.insert(
+                                    variant.name.clone(),
+                                    self.decode_fields(fields, data, depth + 1)?,
+                                );
+                                Value::Object(object)
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
+
+fn take<const N: usize>(data: &mut &[u8]) -> Result<[u8; N]> {
+    if data.len() < N {
+        bail!("unexpected end of data");
+    }
+    let (head, rest) = data.split_at(N);
+    *data = rest;
+    Ok(head.try_into().expect("length checked"))
+}
+
+/// u32 长度前缀的字节串
+fn take_len<'a>(data: &mut &'a [u8]) -> Result<&'a [u8]> {
+    let len = u32::from_le_bytes(take(data)?) as usize;
+    if data.len() < len {
+        bail!("unexpected end of data");
+    }
+    let (head, rest) = data.split_at(len);
+    *data = rest;
+    Ok(head)
+}
+
+fn float(value: f64) -> Value {
+    Number::from_f64(value).map(Value::Number).unwrap_or(Value::Null)
+}
+
+fn type_def(ty: &Value) -> Result<IdlTypeDef> {
+    match str_field(ty, "kind")? {
+        "struct" => Ok(IdlTypeDef::Struct(fields(array(ty, "fields"))?)),
+        "enum" => array(ty, "variants")
+            .iter()
+            .map(|variant| {
+                Ok(IdlVariant {
+                    name: str_field(variant, "name")?.to_string(),
+                    fields: fields(array(variant, "fields"))?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(IdlTypeDef::Enum),
+        "type" => Ok(IdlTypeDef::Alias(idl_type(&ty["alias"])?)),
+        kind => bail!("unsupported type kind {}", kind),
+    }
+}
+
+/// 字段列表：元素带 name 时为具名字段，否则为元组字段
+fn fields(fields: &[Value]) -> Result<IdlFields> {
+    if fields.first().is_some_and(|field| field.get("name").is_some()) {
+        named_fields(fields).map(IdlFields::Named)
+    } else {
+        fields.iter().map(idl_type).collect::<Result<Vec<_>>>().map(IdlFields::Tuple)
+    }
+}
+
+fn named_fields(fields: &[Value]) -> Result<Vec<IdlField>> {
+    fields
+        .iter()
+        .map(|field| {
+            let name = str_field(field, "name")?;
+            let ty = idl_type(&field["type"]).with_context(|| format!("field {}", name))?;
+            Ok(IdlField { name: name.to_string(), ty })
+        })
+        .collect()
+}
+
+fn idl_type(ty: &Value) -> Result<IdlType> {
+    if let Some(primitive) = ty.as_str() {
+        return Ok(match primitive {
+            "bool" => IdlType::Bool,
+            "u8" => IdlType::U8,
+            "i8" => IdlType::I8,
+            "u16" => IdlType::U16,
+            "i16" => IdlType::I16,
+            "u32" => IdlType::U32,
+            "i32" => IdlType::I32,
+            "u64" => IdlType::U64,
+            "i64" => IdlType::I64,
+            "u128" => IdlType::U128,
+            "i128" => IdlType::I128,
+            "f32" => IdlType::F32,
+            "f64" => IdlType::F64,
+            "string" => IdlType::String,
+            "bytes" => IdlType::Bytes,
+            "pubkey" | "publicKey" => IdlType::Pubkey,
+            other => bail!("unsupported IDL type {}", other),
+        });
+    }
+    if let Some(inner) = ty.get("vec") {
+        return Ok(IdlType::Vec(Box::new(idl_type(inner)?)));
+    }
+    if let Some(inner) = ty.get("option") {
+        return Ok(IdlType::Option(Box::new(idl_type(inner)?)));
+    }
+    if let Some(inner) = ty.get("coption") {
+        return Ok(IdlType::COption(Box::new(idl_type(inner)?)));
+    }
+    if let Some(array) = ty.get("array").and_then(Value::as_array) {
+        let len = array
+            .get(1)
+            .and_then(Value::as_u64)
+            .ok_or_else(|| anyhow!("unsupported array length in {}", ty))?;
+        let inner = array.first().ok_or_else(|| anyhow!("missing array type in {}", ty))?;
+        return Ok(IdlType::Array(Box::new(idl_type(inner)?), len as usize));
+    }
+    if let Some(defined) = ty.get("defined") {
+        let name = defined
+            .as_str()
+            .or_else(|| defined["name"].as_str())
+            .ok_or_else(|| anyhow!("missing defined name in {}", ty))?;
+        return Ok(IdlType::Defined(name.to_string()));
+    }
+    bail!("unsupported IDL type {}", ty)
+}
+
+/// 读取 discriminator 字段，缺省时按 Anchor 规则计算 sha256("<namespace>:<name>")[..8]
+fn discriminator(item: &Value, namespace: &str, name: &str) -> Result<Vec<u8>> {
+    if let Some(bytes) = item["discriminator"].as_array() {
+        return bytes
+            .iter()
+            .map(|b| {
+                b.as_u64()
+                    .and_then(|b| u8::try_from(b).ok())
+                    .ok_or_else(|| anyhow!("invalid discriminator"))
+            })
+            .collect();
+    }
+    let hash = Sha256::digest(format!("{}:{}", namespace, name).as_bytes());
+    Ok(hash[..8].to_vec())
+}
+
+/// 展开指令账户（旧版 IDL 允许嵌套账户组）
+fn flatten_accounts(accounts: &Value, names: &mut Vec<String>) -> Result<()> {
+    for account in accounts.as_array().into_iter().flatten() {
+        if account.get("accounts").is_some() {
+            flatten_accounts(&account["accounts"], names)?;
+        } else {
+            names.push(str_field(account, "name")?.to_string());
+        }
+    }
+    Ok(())
+}
+
+fn array<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value[key].as_array().map(Vec::as_slice).unwrap_or(&[])
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> Result<&'a str> {
+    value[key].as_str().ok_or_else(|| anyhow!("missing {} in IDL", key))
+}
+
+/// camelCase / PascalCase / snake_case 转 snake_case（与 build.rs 一致）
+fn to_snake(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next_lower)
+            {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+    out
+}
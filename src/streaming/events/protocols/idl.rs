@@ -0,0 +1,131 @@
+//! 由 build.rs 根据 programs/*.json 生成的 IDL 定义：
+//! 指令 / 账户 / 事件 discriminator、指令账户下标（ix_accounts）与 borsh 结构体（args / types）
+
+pub mod bonk {
+    include!(concat!(env!("OUT_DIR"), "/idl_bonk.rs"));
+}
+
+pub mod pumpfun {
+    include!(concat!(env!("OUT_DIR"), "/idl_pumpfun.rs"));
+}
+
+pub mod pumpswap {
+    include!(concat!(env!("OUT_DIR"), "/idl_pumpswap.rs"));
+}
+
+pub mod raydiumamm {
+    include!(concat!(env!("OUT_DIR"), "/idl_raydiumamm.rs"));
+}
+
+pub mod raydiumclmm {
+    include!(concat!(env!("OUT_DIR"), "/idl_raydiumclmm.rs"));
+}
+
+pub mod raydiumcpmm {
+    include!(concat!(env!("OUT_DIR"), "/idl_raydiumcpmm.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::events::protocols::{
+        bonk::events::discriminators as bonk_disc, pumpfun::events::discriminators as pumpfun_disc,
+        pumpswap::events::discriminators as pumpswap_disc,
+        raydiumamm::events::discriminators as raydiumamm_disc,
+        raydiumclmm::events::discriminators as raydiumclmm_disc,
+        raydiumcpmm::events::discriminators as raydiumcpmm_disc,
+    };
+
+    #[test]
+    fn pumpfun_discriminators_match_idl() {
+        assert_eq!(pumpfun_disc::CREATE_TOKEN_EVENT, pumpfun::cpi_events::CREATE_EVENT);
+        assert_eq!(pumpfun_disc::TRADE_EVENT, pumpfun::cpi_events::TRADE_EVENT);
+        assert_eq!(
+            pumpfun_disc::COMPLETE_PUMP_AMM_MIGRATION_EVENT,
+            pumpfun::cpi_events::COMPLETE_PUMP_AMM_MIGRATION_EVENT
+        );
+        assert_eq!(pumpfun_disc::CREATE_TOKEN_IX, pumpfun::instructions::CREATE);
+        assert_eq!(pumpfun_disc::BUY_IX, pumpfun::instructions::BUY);
+        assert_eq!(pumpfun_disc::SELL_IX, pumpfun::instructions::SELL);
+        assert_eq!(pumpfun_disc::MIGRATE_IX, pumpfun::instructions::MIGRATE);
+        assert_eq!(pumpfun_disc::BONDING_CURVE_ACCOUNT, pumpfun::accounts::BONDING_CURVE);
+        assert_eq!(pumpfun_disc::GLOBAL_ACCOUNT, pumpfun::accounts::GLOBAL);
+    }
+
+    #[test]
+    fn bonk_discriminators_match_idl() {
+        assert_eq!(bonk_disc::TRADE_EVENT, bonk::cpi_events::TRADE_EVENT);
+        assert_eq!(bonk_disc::POOL_CREATE_EVENT, bonk::cpi_events::POOL_CREATE_EVENT);
+        assert_eq!(bonk_disc::BUY_EXACT_IN, bonk::instructions::BUY_EXACT_IN);
+        assert_eq!(bonk_disc::BUY_EXACT_OUT, bonk::instructions::BUY_EXACT_OUT);
+        assert_eq!(bonk_disc::SELL_EXACT_IN, bonk::instructions::SELL_EXACT_IN);
+        assert_eq!(bonk_disc::SELL_EXACT_OUT, bonk::instructions::SELL_EXACT_OUT);
+        assert_eq!(bonk_disc::INITIALIZE, bonk::instructions::INITIALIZE);
+        assert_eq!(bonk_disc::MIGRATE_TO_AMM, bonk::instructions::MIGRATE_TO_AMM);
+        assert_eq!(bonk_disc::MIGRATE_TO_CP_SWAP, bonk::instructions::MIGRATE_TO_CPSWAP);
+        assert_eq!(bonk_disc::POOL_STATE_ACCOUNT, bonk::accounts::POOL_STATE);
+        assert_eq!(bonk_disc::GLOBAL_CONFIG_ACCOUNT, bonk::accounts::GLOBAL_CONFIG);
+        assert_eq!(bonk_disc::PLATFORM_CONFIG_ACCOUNT, bonk::accounts::PLATFORM_CONFIG);
+    }
+
+    #[test]
+    fn pumpswap_discriminators_match_idl() {
+        assert_eq!(pumpswap_disc::BUY_EVENT, pumpswap::cpi_events::BUY_EVENT);
+        assert_eq!(pumpswap_disc::SELL_EVENT, pumpswap::cpi_events::SELL_EVENT);
+        assert_eq!(pumpswap_disc::CREATE_POOL_EVENT, pumpswap::cpi_events::CREATE_POOL_EVENT);
+        assert_eq!(pumpswap_disc::DEPOSIT_EVENT, pumpswap::cpi_events::DEPOSIT_EVENT);
+        assert_eq!(pumpswap_disc::WITHDRAW_EVENT, pumpswap::cpi_events::WITHDRAW_EVENT);
+        assert_eq!(pumpswap_disc::BUY_IX, pumpswap::instructions::BUY);
+        assert_eq!(pumpswap_disc::SELL_IX, pumpswap::instructions::SELL);
+        assert_eq!(pumpswap_disc::CREATE_POOL_IX, pumpswap::instructions::CREATE_POOL);
+        assert_eq!(pumpswap_disc::DEPOSIT_IX, pumpswap::instructions::DEPOSIT);
+        assert_eq!(pumpswap_disc::WITHDRAW_IX, pumpswap::instructions::WITHDRAW);
+        assert_eq!(pumpswap_disc::GLOBAL_CONFIG_ACCOUNT, pumpswap::accounts::GLOBAL_CONFIG);
+        assert_eq!(pumpswap_disc::POOL_ACCOUNT, pumpswap::accounts::POOL);
+    }
+
+    #[test]
+    fn raydiumamm_discriminators_match_idl() {
+        assert_eq!(raydiumamm_disc::SWAP_BASE_IN, raydiumamm::instructions::SWAP_BASE_IN);
+        assert_eq!(raydiumamm_disc::SWAP_BASE_OUT, raydiumamm::instructions::SWAP_BASE_OUT);
+        assert_eq!(raydiumamm_disc::DEPOSIT, raydiumamm::instructions::DEPOSIT);
+        assert_eq!(raydiumamm_disc::INITIALIZE2, raydiumamm::instructions::INITIALIZE2);
+        assert_eq!(raydiumamm_disc::WITHDRAW, raydiumamm::instructions::WITHDRAW);
+        assert_eq!(raydiumamm_disc::WITHDRAW_PNL, raydiumamm::instructions::WITHDRAW_PNL);
+    }
+
+    #[test]
+    fn raydiumclmm_discriminators_match_idl() {
+        assert_eq!(raydiumclmm_disc::SWAP, raydiumclmm::instructions::SWAP);
+        assert_eq!(raydiumclmm_disc::SWAP_V2, raydiumclmm::instructions::SWAP_V2);
+        assert_eq!(raydiumclmm_disc::CLOSE_POSITION, raydiumclmm::instructions::CLOSE_POSITION);
+        assert_eq!(
+            raydiumclmm_disc::INCREASE_LIQUIDITY_V2,
+            raydiumclmm::instructions::INCREASE_LIQUIDITY_V2
+        );
+        assert_eq!(
+            raydiumclmm_disc::DECREASE_LIQUIDITY_V2,
+            raydiumclmm::instructions::DECREASE_LIQUIDITY_V2
+        );
+        assert_eq!(raydiumclmm_disc::CREATE_POOL, raydiumclmm::instructions::CREATE_POOL);
+        assert_eq!(
+            raydiumclmm_disc::OPEN_POSITION_WITH_TOKEN_22_NFT,
+            raydiumclmm::instructions::OPEN_POSITION_WITH_TOKEN22_NFT
+        );
+        assert_eq!(raydiumclmm_disc::OPEN_POSITION_V2, raydiumclmm::instructions::OPEN_POSITION_V2);
+        assert_eq!(raydiumclmm_disc::AMM_CONFIG, raydiumclmm::accounts::AMM_CONFIG);
+        assert_eq!(raydiumclmm_disc::POOL_STATE, raydiumclmm::accounts::POOL_STATE);
+        assert_eq!(raydiumclmm_disc::TICK_ARRAY_STATE, raydiumclmm::accounts::TICK_ARRAY_STATE);
+    }
+
+    #[test]
+    fn raydiumcpmm_discriminators_match_idl() {
+        assert_eq!(raydiumcpmm_disc::SWAP_BASE_IN, raydiumcpmm::instructions::SWAP_BASE_INPUT);
+        assert_eq!(raydiumcpmm_disc::SWAP_BASE_OUT, raydiumcpmm::instructions::SWAP_BASE_OUTPUT);
+        assert_eq!(raydiumcpmm_disc::DEPOSIT, raydiumcpmm::instructions::DEPOSIT);
+        assert_eq!(raydiumcpmm_disc::INITIALIZE, raydiumcpmm::instructions::INITIALIZE);
+        assert_eq!(raydiumcpmm_disc::WITHDRAW, raydiumcpmm::instructions::WITHDRAW);
+        assert_eq!(raydiumcpmm_disc::AMM_CONFIG, raydiumcpmm::accounts::AMM_CONFIG);
+        assert_eq!(raydiumcpmm_disc::POOL_STATE, raydiumcpmm::accounts::POOL_STATE);
+    }
+}
@@ -6,6 +6,8 @@ pub mod raydiumclmm;
 pub mod raydiumamm;
 pub mod block;
 pub mod mutil;
+pub mod idl;
+pub mod dynamic;
 
 pub use pumpfun::PumpFunEventParser;
 pub use pumpswap::PumpSwapEventParser;
@@ -14,4 +16,7 @@ pub use raydiumcpmm::RaydiumCpmmEventParser;
 pub use raydiumclmm::RaydiumClmmEventParser;
 pub use raydiumamm::RaydiumAmmV4EventParser;
 pub use block::blockmeta::BlockMetaEvent;
-pub use mutil::MutilEventParser;
\ No newline at end of file
+pub use block::slot::{SlotEvent, SlotStatus};
+pub use block::commitment::{EventConfirmedEvent, EventRolledBackEvent, RollbackReason};
+pub use mutil::MutilEventParser;
+pub use dynamic::{DynamicIdlParser, IdlEvent};
\ No newline at end of file
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use prost_types::Timestamp;
 use solana_sdk::{instruction::CompiledInstruction, pubkey::Pubkey};
@@ -12,6 +13,9 @@ use crate::streaming::events::{
 
 pub struct MutilEventParser {
     inner: GenericEventParser,
+    /// 运行时追加的解析器（如 DynamicIdlParser），按 program id 分派
+    extra_parsers: Vec<Arc<dyn EventParser>>,
+    event_type_filter: Option<EventTypeFilter>,
 }
 
 impl MutilEventParser {
@@ -40,7 +44,17 @@ impl MutilEventParser {
             // Append program_ids (this is already appending)
             inner.program_ids.extend(parse.supported_program_ids().clone());
         }
-        Self { inner }
+        Self { inner, extra_parsers: Vec::new(), event_type_filter }
+    }
+
+    /// 追加解析器，其指令事件同样受 event_type_filter 约束
+    pub fn with_parser(mut self, parser: Arc<dyn EventParser>) -> Self {
+        self.extra_parsers.push(parser);
+        self
+    }
+
+    fn include(&self, event: &dyn UnifiedEvent) -> bool {
+        self.event_type_filter.as_ref().is_none_or(|filter| filter.include.contains(&event.event_type()))
     }
 }
 
@@ -61,14 +75,91 @@ impl EventParser for MutilEventParser {
         program_received_time_ms: i64,
         index: String,
     ) -> Vec<Box<dyn UnifiedEvent>> {
-        self.inner.parse_events_from_inner_instruction(
+        let mut events = self.inner.parse_events_from_inner_instruction(
             inner_instruction,
             signature,
             slot,
             block_time,
             program_received_time_ms,
-            index,
-        )
+            index.clone(),
+        );
+        for parser in &self.extra_parsers {
+            // 内联事件仅用于合并到所属指令事件，不按事件类型过滤
+            events.extend(parser.parse_events_from_inner_instruction(
+                inner_instruction,
+                signature,
+                slot,
+                block_time,
+                program_received_time_ms,
+                index.clone(),
+            ));
+        }
+        events
+    }
+
+    fn parse_events_from_inner_instruction_data(
+        &self,
+        data: &[u8],
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        let mut events = self.inner.parse_events_from_inner_instruction_data(
+            data,
+            signature,
+            slot,
+            block_time,
+            program_received_time_ms,
+            index.clone(),
+        );
+        for parser in &self.extra_parsers {
+            // 内联事件仅用于合并到所属指令事件，不按事件类型过滤
+            events.extend(parser.parse_events_from_inner_instruction_data(
+                data,
+                signature,
+                slot,
+                block_time,
+                program_received_time_ms,
+                index.clone(),
+            ));
+        }
+        events
+    }
+
+    fn parse_events_from_program_inner_instruction(
+        &self,
+        program_id: &Pubkey,
+        data: &[u8],
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        let mut events = self.inner.parse_events_from_program_inner_instruction(
+            program_id,
+            data,
+            signature,
+            slot,
+            block_time,
+            program_received_time_ms,
+            index.clone(),
+        );
+        // 追加的解析器只解码各自程序发出的事件
+        for parser in self.extra_parsers.iter().filter(|parser| parser.should_handle(program_id)) {
+            events.extend(parser.parse_events_from_program_inner_instruction(
+                program_id,
+                data,
+                signature,
+                slot,
+                block_time,
+                program_received_time_ms,
+                index.clone(),
+            ));
+        }
+        events
     }
 
     fn parse_events_from_instruction(
@@ -81,22 +172,44 @@ impl EventParser for MutilEventParser {
         program_received_time_ms: i64,
         index: String,
     ) -> Vec<Box<dyn UnifiedEvent>> {
-        self.inner.parse_events_from_instruction(
+        let mut events = self.inner.parse_events_from_instruction(
             instruction,
             accounts,
             signature,
             slot,
             block_time,
             program_received_time_ms,
-            index,
-        )
+            index.clone(),
+        );
+        for parser in &self.extra_parsers {
+            events.extend(
+                parser
+                    .parse_events_from_instruction(
+                        instruction,
+                        accounts,
+                        signature,
+                        slot,
+                        block_time,
+                        program_received_time_ms,
+                        index.clone(),
+                    )
+                    .into_iter()
+                    .filter(|event| self.include(event.as_ref())),
+            );
+        }
+        events
     }
 
     fn should_handle(&self, program_id: &Pubkey) -> bool {
         self.inner.should_handle(program_id)
+            || self.extra_parsers.iter().any(|parser| parser.should_handle(program_id))
     }
 
     fn supported_program_ids(&self) -> Vec<Pubkey> {
-        self.inner.supported_program_ids()
+        let mut program_ids = self.inner.supported_program_ids();
+        for parser in &self.extra_parsers {
+            program_ids.extend(parser.supported_program_ids());
+        }
+        program_ids
     }
 }
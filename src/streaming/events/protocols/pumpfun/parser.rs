@@ -13,6 +13,7 @@ use crate::streaming::events::{
         PumpFunTradeEvent,
     },
 };
+use crate::streaming::events::protocols::idl::pumpfun::{args, ix_accounts};
 
 /// PumpFun程序ID
 pub const PUMPFUN_PROGRAM_ID: Pubkey =
@@ -153,7 +154,13 @@ impl PumpFunEventParser {
         };
 
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}-{}-{}", metadata.signature, name, symbol, accounts[0]));
+        metadata.set_id(format!(
+            "{}-{}-{}-{}",
+            metadata.signature,
+            name,
+            symbol,
+            accounts[ix_accounts::create::MINT]
+        ));
 
         Some(Box::new(PumpFunCreateTokenEvent {
             metadata,
@@ -161,11 +168,11 @@ impl PumpFunEventParser {
             symbol: symbol.to_string(),
             uri: uri.to_string(),
             creator,
-            mint: accounts[0],
-            mint_authority: accounts[1],
-            bonding_curve: accounts[2],
-            associated_bonding_curve: accounts[3],
-            user: accounts[7],
+            mint: accounts[ix_accounts::create::MINT],
+            mint_authority: accounts[ix_accounts::create::MINT_AUTHORITY],
+            bonding_curve: accounts[ix_accounts::create::BONDING_CURVE],
+            associated_bonding_curve: accounts[ix_accounts::create::ASSOCIATED_BONDING_CURVE],
+            user: accounts[ix_accounts::create::USER],
             ..Default::default()
         }))
     }
@@ -176,29 +183,34 @@ impl PumpFunEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 13 {
+        if accounts.len() < ix_accounts::buy::LEN {
             return None;
         }
-        let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
-        let max_sol_cost = u64::from_le_bytes(data[8..16].try_into().unwrap());
+        let args::Buy { amount, max_sol_cost } = args::Buy::decode(data)?;
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}-{}-{}", metadata.signature, accounts[2], accounts[6], true));
+        metadata.set_id(format!(
+            "{}-{}-{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::buy::MINT],
+            accounts[ix_accounts::buy::USER],
+            true
+        ));
         Some(Box::new(PumpFunTradeEvent {
             metadata,
-            global: accounts[0],
-            fee_recipient: accounts[1],
-            mint: accounts[2],
-            bonding_curve: accounts[3],
-            associated_bonding_curve: accounts[4],
-            associated_user: accounts[5],
-            user: accounts[6],
-            system_program: accounts[7],
-            token_program: accounts[8],
-            creator_vault: accounts[9],
-            event_authority: accounts[10],
-            program: accounts[11],
-            global_volume_accumulator: accounts[12],
-            user_volume_accumulator: accounts[13],
+            global: accounts[ix_accounts::buy::GLOBAL],
+            fee_recipient: accounts[ix_accounts::buy::FEE_RECIPIENT],
+            mint: accounts[ix_accounts::buy::MINT],
+            bonding_curve: accounts[ix_accounts::buy::BONDING_CURVE],
+            associated_bonding_curve: accounts[ix_accounts::buy::ASSOCIATED_BONDING_CURVE],
+            associated_user: accounts[ix_accounts::buy::ASSOCIATED_USER],
+            user: accounts[ix_accounts::buy::USER],
+            system_program: accounts[ix_accounts::buy::SYSTEM_PROGRAM],
+            token_program: accounts[ix_accounts::buy::TOKEN_PROGRAM],
+            creator_vault: accounts[ix_accounts::buy::CREATOR_VAULT],
+            event_authority: accounts[ix_accounts::buy::EVENT_AUTHORITY],
+            program: accounts[ix_accounts::buy::PROGRAM],
+            global_volume_accumulator: accounts[ix_accounts::buy::GLOBAL_VOLUME_ACCUMULATOR],
+            user_volume_accumulator: accounts[ix_accounts::buy::USER_VOLUME_ACCUMULATOR],
             max_sol_cost,
             amount,
             is_buy: true,
@@ -212,28 +224,33 @@ impl PumpFunEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 11 {
+        if accounts.len() < ix_accounts::sell::LEN {
             return None;
         }
-        let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
-        let min_sol_output = u64::from_le_bytes(data[8..16].try_into().unwrap());
+        let args::Sell { amount, min_sol_output } = args::Sell::decode(data)?;
         let mut metadata = metadata;
-        metadata
-            .set_id(format!("{}-{}-{}-{}", metadata.signature, accounts[2], accounts[6], false));
+        metadata.set_id(format!(
+            "{}-{}-{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::sell::MINT],
+            accounts[ix_accounts::sell::USER],
+            false
+        ));
         Some(Box::new(PumpFunTradeEvent {
             metadata,
-            global: accounts[0],
-            fee_recipient: accounts[1],
-            mint: accounts[2],
-            bonding_curve: accounts[3],
-            associated_bonding_curve: accounts[4],
-            associated_user: accounts[5],
-            user: accounts[6],
-            system_program: accounts[7],
-            creator_vault: accounts[8],
-            token_program: accounts[9],
-            event_authority: accounts[10],
-            program: accounts[11],
+            global: accounts[ix_accounts::sell::GLOBAL],
+            fee_recipient: accounts[ix_accounts::sell::FEE_RECIPIENT],
+            mint: accounts[ix_accounts::sell::MINT],
+            bonding_curve: accounts[ix_accounts::sell::BONDING_CURVE],
+            associated_bonding_curve: accounts[ix_accounts::sell::ASSOCIATED_BONDING_CURVE],
+            associated_user: accounts[ix_accounts::sell::ASSOCIATED_USER],
+            user: accounts[ix_accounts::sell::USER],
+            system_program: accounts[ix_accounts::sell::SYSTEM_PROGRAM],
+            creator_vault: accounts[ix_accounts::sell::CREATOR_VAULT],
+            token_program: accounts[ix_accounts::sell::TOKEN_PROGRAM],
+            event_authority: accounts[ix_accounts::sell::EVENT_AUTHORITY],
+            program: accounts[ix_accounts::sell::PROGRAM],
+            // 交易量累计账户为新增账户，随附的 IDL 尚未包含
             global_volume_accumulator: *accounts.get(12).unwrap_or(&Pubkey::default()),
             user_volume_accumulator: *accounts.get(13).unwrap_or(&Pubkey::default()),
             min_sol_output,
@@ -249,37 +266,44 @@ impl PumpFunEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if accounts.len() < 24 {
+        if accounts.len() < ix_accounts::migrate::LEN {
             return None;
         }
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}-{}", metadata.signature, accounts[5], accounts[2]));
+        metadata.set_id(format!(
+            "{}-{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::migrate::USER],
+            accounts[ix_accounts::migrate::MINT]
+        ));
         Some(Box::new(PumpFunMigrateEvent {
             metadata,
-            global: accounts[0],
-            withdraw_authority: accounts[1],
-            mint: accounts[2],
-            bonding_curve: accounts[3],
-            associated_bonding_curve: accounts[4],
-            user: accounts[5],
-            system_program: accounts[6],
-            token_program: accounts[7],
-            pump_amm: accounts[8],
-            pool: accounts[9],
-            pool_authority: accounts[10],
-            pool_authority_mint_account: accounts[11],
-            pool_authority_wsol_account: accounts[12],
-            amm_global_config: accounts[13],
-            wsol_mint: accounts[14],
-            lp_mint: accounts[15],
-            user_pool_token_account: accounts[16],
-            pool_base_token_account: accounts[17],
-            pool_quote_token_account: accounts[18],
-            token_2022_program: accounts[19],
-            associated_token_program: accounts[20],
-            pump_amm_event_authority: accounts[21],
-            event_authority: accounts[22],
-            program: accounts[23],
+            global: accounts[ix_accounts::migrate::GLOBAL],
+            withdraw_authority: accounts[ix_accounts::migrate::WITHDRAW_AUTHORITY],
+            mint: accounts[ix_accounts::migrate::MINT],
+            bonding_curve: accounts[ix_accounts::migrate::BONDING_CURVE],
+            associated_bonding_curve: accounts[ix_accounts::migrate::ASSOCIATED_BONDING_CURVE],
+            user: accounts[ix_accounts::migrate::USER],
+            system_program: accounts[ix_accounts::migrate::SYSTEM_PROGRAM],
+            token_program: accounts[ix_accounts::migrate::TOKEN_PROGRAM],
+            pump_amm: accounts[ix_accounts::migrate::PUMP_AMM],
+            pool: accounts[ix_accounts::migrate::POOL],
+            pool_authority: accounts[ix_accounts::migrate::POOL_AUTHORITY],
+            pool_authority_mint_account: accounts
+                [ix_accounts::migrate::POOL_AUTHORITY_MINT_ACCOUNT],
+            pool_authority_wsol_account: accounts
+                [ix_accounts::migrate::POOL_AUTHORITY_WSOL_ACCOUNT],
+            amm_global_config: accounts[ix_accounts::migrate::AMM_GLOBAL_CONFIG],
+            wsol_mint: accounts[ix_accounts::migrate::WSOL_MINT],
+            lp_mint: accounts[ix_accounts::migrate::LP_MINT],
+            user_pool_token_account: accounts[ix_accounts::migrate::USER_POOL_TOKEN_ACCOUNT],
+            pool_base_token_account: accounts[ix_accounts::migrate::POOL_BASE_TOKEN_ACCOUNT],
+            pool_quote_token_account: accounts[ix_accounts::migrate::POOL_QUOTE_TOKEN_ACCOUNT],
+            token_2022_program: accounts[ix_accounts::migrate::TOKEN_2022_PROGRAM],
+            associated_token_program: accounts[ix_accounts::migrate::ASSOCIATED_TOKEN_PROGRAM],
+            pump_amm_event_authority: accounts[ix_accounts::migrate::PUMP_AMM_EVENT_AUTHORITY],
+            event_authority: accounts[ix_accounts::migrate::EVENT_AUTHORITY],
+            program: accounts[ix_accounts::migrate::PROGRAM],
             ..Default::default()
         }))
     }
@@ -312,6 +336,25 @@ impl EventParser for PumpFunEventParser {
         )
     }
 
+    fn parse_events_from_inner_instruction_data(
+        &self,
+        data: &[u8],
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        self.inner.parse_events_from_inner_instruction_data(
+            data,
+            signature,
+            slot,
+            block_time,
+            program_received_time_ms,
+            index,
+        )
+    }
+
     fn parse_events_from_instruction(
         &self,
         instruction: &CompiledInstruction,
@@ -4,7 +4,6 @@ use prost_types::Timestamp;
 use solana_sdk::{instruction::CompiledInstruction, pubkey::Pubkey};
 use solana_transaction_status::UiCompiledInstruction;
 
-use crate::utils::scripts::Scripts;
 use crate::streaming::events::{
     common::{EventMetadata, EventType, ProtocolType},
     core::traits::{EventParser, GenericEventParseConfig, GenericEventParser, UnifiedEvent},
@@ -15,6 +14,7 @@ use crate::streaming::events::{
         PumpSwapDepositEvent, PumpSwapSellEvent, PumpSwapWithdrawEvent,
     },
 };
+use crate::streaming::events::protocols::idl::pumpswap::{args, ix_accounts};
 
 /// PumpSwap程序ID
 pub const PUMPSWAP_PROGRAM_ID: Pubkey =
@@ -178,37 +178,47 @@ impl PumpSwapEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 11 {
+        // coin_creator_vault_* 为新版追加的可选账户，其余账户必须齐全
+        if accounts.len() < ix_accounts::buy::COIN_CREATOR_VAULT_ATA {
             return None;
         }
 
-        let base_amount_out = Scripts::readu64le(data, 0)?;
-        let max_quote_amount_in = Scripts::readu64le(data, 8)?;
+        let args::Buy { base_amount_out, max_quote_amount_in } = args::Buy::decode(data)?;
 
         let mut metadata = metadata;
         metadata.set_id(format!(
             "{}-{}-{}-{}",
-            metadata.signature, accounts[1], accounts[0], base_amount_out
+            metadata.signature,
+            accounts[ix_accounts::buy::USER],
+            accounts[ix_accounts::buy::POOL],
+            base_amount_out
         ));
 
         Some(Box::new(PumpSwapBuyEvent {
             metadata,
             base_amount_out,
             max_quote_amount_in,
-            pool: accounts[0],
-            user: accounts[1],
-            base_mint: accounts[3],
-            quote_mint: accounts[4],
-            user_base_token_account: accounts[5],
-            user_quote_token_account: accounts[6],
-            pool_base_token_account: accounts[7],
-            pool_quote_token_account: accounts[8],
-            protocol_fee_recipient: accounts[9],
-            protocol_fee_recipient_token_account: accounts[10],
-            base_token_program: accounts[11],
-            quote_token_program: accounts[12],
-            coin_creator_vault_ata: accounts.get(17).copied().unwrap_or_default(),
-            coin_creator_vault_authority: accounts.get(18).copied().unwrap_or_default(),
+            pool: accounts[ix_accounts::buy::POOL],
+            user: accounts[ix_accounts::buy::USER],
+            base_mint: accounts[ix_accounts::buy::BASE_MINT],
+            quote_mint: accounts[ix_accounts::buy::QUOTE_MINT],
+            user_base_token_account: accounts[ix_accounts::buy::USER_BASE_TOKEN_ACCOUNT],
+            user_quote_token_account: accounts[ix_accounts::buy::USER_QUOTE_TOKEN_ACCOUNT],
+            pool_base_token_account: accounts[ix_accounts::buy::POOL_BASE_TOKEN_ACCOUNT],
+            pool_quote_token_account: accounts[ix_accounts::buy::POOL_QUOTE_TOKEN_ACCOUNT],
+            protocol_fee_recipient: accounts[ix_accounts::buy::PROTOCOL_FEE_RECIPIENT],
+            protocol_fee_recipient_token_account: accounts
+                [ix_accounts::buy::PROTOCOL_FEE_RECIPIENT_TOKEN_ACCOUNT],
+            base_token_program: accounts[ix_accounts::buy::BASE_TOKEN_PROGRAM],
+            quote_token_program: accounts[ix_accounts::buy::QUOTE_TOKEN_PROGRAM],
+            coin_creator_vault_ata: accounts
+                .get(ix_accounts::buy::COIN_CREATOR_VAULT_ATA)
+                .copied()
+                .unwrap_or_default(),
+            coin_creator_vault_authority: accounts
+                .get(ix_accounts::buy::COIN_CREATOR_VAULT_AUTHORITY)
+                .copied()
+                .unwrap_or_default(),
             ..Default::default()
         }))
     }
@@ -219,37 +229,47 @@ impl PumpSwapEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 11 {
+        // coin_creator_vault_* 为新版追加的可选账户，其余账户必须齐全
+        if accounts.len() < ix_accounts::sell::COIN_CREATOR_VAULT_ATA {
             return None;
         }
 
-        let base_amount_in = Scripts::readu64le(data, 0)?;
-        let min_quote_amount_out = Scripts::readu64le(data, 8)?;
+        let args::Sell { base_amount_in, min_quote_amount_out } = args::Sell::decode(data)?;
 
         let mut metadata = metadata;
         metadata.set_id(format!(
             "{}-{}-{}-{}",
-            metadata.signature, accounts[1], accounts[0], base_amount_in
+            metadata.signature,
+            accounts[ix_accounts::sell::USER],
+            accounts[ix_accounts::sell::POOL],
+            base_amount_in
         ));
 
         Some(Box::new(PumpSwapSellEvent {
             metadata,
             base_amount_in,
             min_quote_amount_out,
-            pool: accounts[0],
-            user: accounts[1],
-            base_mint: accounts[3],
-            quote_mint: accounts[4],
-            user_base_token_account: accounts[5],
-            user_quote_token_account: accounts[6],
-            pool_base_token_account: accounts[7],
-            pool_quote_token_account: accounts[8],
-            protocol_fee_recipient: accounts[9],
-            protocol_fee_recipient_token_account: accounts[10],
-            base_token_program: accounts[11],
-            quote_token_program: accounts[12],
-            coin_creator_vault_ata: accounts.get(17).copied().unwrap_or_default(),
-            coin_creator_vault_authority: accounts.get(18).copied().unwrap_or_default(),
+            pool: accounts[ix_accounts::sell::POOL],
+            user: accounts[ix_accounts::sell::USER],
+            base_mint: accounts[ix_accounts::sell::BASE_MINT],
+            quote_mint: accounts[ix_accounts::sell::QUOTE_MINT],
+            user_base_token_account: accounts[ix_accounts::sell::USER_BASE_TOKEN_ACCOUNT],
+            user_quote_token_account: accounts[ix_accounts::sell::USER_QUOTE_TOKEN_ACCOUNT],
+            pool_base_token_account: accounts[ix_accounts::sell::POOL_BASE_TOKEN_ACCOUNT],
+            pool_quote_token_account: accounts[ix_accounts::sell::POOL_QUOTE_TOKEN_ACCOUNT],
+            protocol_fee_recipient: accounts[ix_accounts::sell::PROTOCOL_FEE_RECIPIENT],
+            protocol_fee_recipient_token_account: accounts
+                [ix_accounts::sell::PROTOCOL_FEE_RECIPIENT_TOKEN_ACCOUNT],
+            base_token_program: accounts[ix_accounts::sell::BASE_TOKEN_PROGRAM],
+            quote_token_program: accounts[ix_accounts::sell::QUOTE_TOKEN_PROGRAM],
+            coin_creator_vault_ata: accounts
+                .get(ix_accounts::sell::COIN_CREATOR_VAULT_ATA)
+                .copied()
+                .unwrap_or_default(),
+            coin_creator_vault_authority: accounts
+                .get(ix_accounts::sell::COIN_CREATOR_VAULT_AUTHORITY)
+                .copied()
+                .unwrap_or_default(),
             ..Default::default()
         }))
     }
@@ -276,7 +296,10 @@ impl PumpSwapEventParser {
         let mut metadata = metadata;
         metadata.set_id(format!(
             "{}-{}-{}-{}",
-            metadata.signature, accounts[0], accounts[2], base_amount_in
+            metadata.signature,
+            accounts[ix_accounts::create_pool::POOL],
+            accounts[ix_accounts::create_pool::CREATOR],
+            base_amount_in
         ));
 
         Some(Box::new(PumpSwapCreatePoolEvent {
@@ -284,16 +307,16 @@ impl PumpSwapEventParser {
             index,
             base_amount_in,
             quote_amount_in,
-            pool: accounts[0],
-            creator: accounts[2],
-            base_mint: accounts[3],
-            quote_mint: accounts[4],
-            lp_mint: accounts[5],
-            user_base_token_account: accounts[6],
-            user_quote_token_account: accounts[7],
-            user_pool_token_account: accounts[8],
-            pool_base_token_account: accounts[9],
-            pool_quote_token_account: accounts[10],
+            pool: accounts[ix_accounts::create_pool::POOL],
+            creator: accounts[ix_accounts::create_pool::CREATOR],
+            base_mint: accounts[ix_accounts::create_pool::BASE_MINT],
+            quote_mint: accounts[ix_accounts::create_pool::QUOTE_MINT],
+            lp_mint: accounts[ix_accounts::create_pool::LP_MINT],
+            user_base_token_account: accounts[ix_accounts::create_pool::USER_BASE_TOKEN_ACCOUNT],
+            user_quote_token_account: accounts[ix_accounts::create_pool::USER_QUOTE_TOKEN_ACCOUNT],
+            user_pool_token_account: accounts[ix_accounts::create_pool::USER_POOL_TOKEN_ACCOUNT],
+            pool_base_token_account: accounts[ix_accounts::create_pool::POOL_BASE_TOKEN_ACCOUNT],
+            pool_quote_token_account: accounts[ix_accounts::create_pool::POOL_QUOTE_TOKEN_ACCOUNT],
             coin_creator,
             ..Default::default()
         }))
@@ -305,18 +328,20 @@ impl PumpSwapEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 24 || accounts.len() < 11 {
+        if accounts.len() < 11 {
             return None;
         }
 
-        let lp_token_amount_out = u64::from_le_bytes(data[0..8].try_into().ok()?);
-        let max_base_amount_in = u64::from_le_bytes(data[8..16].try_into().ok()?);
-        let max_quote_amount_in = u64::from_le_bytes(data[16..24].try_into().ok()?);
+        let args::Deposit { lp_token_amount_out, max_base_amount_in, max_quote_amount_in } =
+            args::Deposit::decode(data)?;
 
         let mut metadata = metadata;
         metadata.set_id(format!(
             "{}-{}-{}-{}",
-            metadata.signature, accounts[0], accounts[2], lp_token_amount_out
+            metadata.signature,
+            accounts[ix_accounts::deposit::POOL],
+            accounts[ix_accounts::deposit::USER],
+            lp_token_amount_out
         ));
 
         Some(Box::new(PumpSwapDepositEvent {
@@ -324,15 +349,15 @@ impl PumpSwapEventParser {
             lp_token_amount_out,
             max_base_amount_in,
             max_quote_amount_in,
-            pool: accounts[0],
-            user: accounts[2],
-            base_mint: accounts[3],
-            quote_mint: accounts[4],
-            user_base_token_account: accounts[6],
-            user_quote_token_account: accounts[7],
-            user_pool_token_account: accounts[8],
-            pool_base_token_account: accounts[9],
-            pool_quote_token_account: accounts[10],
+            pool: accounts[ix_accounts::deposit::POOL],
+            user: accounts[ix_accounts::deposit::USER],
+            base_mint: accounts[ix_accounts::deposit::BASE_MINT],
+            quote_mint: accounts[ix_accounts::deposit::QUOTE_MINT],
+            user_base_token_account: accounts[ix_accounts::deposit::USER_BASE_TOKEN_ACCOUNT],
+            user_quote_token_account: accounts[ix_accounts::deposit::USER_QUOTE_TOKEN_ACCOUNT],
+            user_pool_token_account: accounts[ix_accounts::deposit::USER_POOL_TOKEN_ACCOUNT],
+            pool_base_token_account: accounts[ix_accounts::deposit::POOL_BASE_TOKEN_ACCOUNT],
+            pool_quote_token_account: accounts[ix_accounts::deposit::POOL_QUOTE_TOKEN_ACCOUNT],
             ..Default::default()
         }))
     }
@@ -343,18 +368,20 @@ impl PumpSwapEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 24 || accounts.len() < 11 {
+        if accounts.len() < 11 {
             return None;
         }
 
-        let lp_token_amount_in = u64::from_le_bytes(data[0..8].try_into().ok()?);
-        let min_base_amount_out = u64::from_le_bytes(data[8..16].try_into().ok()?);
-        let min_quote_amount_out = u64::from_le_bytes(data[16..24].try_into().ok()?);
+        let args::Withdraw { lp_token_amount_in, min_base_amount_out, min_quote_amount_out } =
+            args::Withdraw::decode(data)?;
 
         let mut metadata = metadata;
         metadata.set_id(format!(
             "{}-{}-{}-{}",
-            metadata.signature, accounts[0], accounts[2], lp_token_amount_in
+            metadata.signature,
+            accounts[ix_accounts::withdraw::POOL],
+            accounts[ix_accounts::withdraw::USER],
+            lp_token_amount_in
         ));
 
         Some(Box::new(PumpSwapWithdrawEvent {
@@ -362,15 +389,15 @@ impl PumpSwapEventParser {
             lp_token_amount_in,
             min_base_amount_out,
             min_quote_amount_out,
-            pool: accounts[0],
-            user: accounts[2],
-            base_mint: accounts[3],
-            quote_mint: accounts[4],
-            user_base_token_account: accounts[6],
-            user_quote_token_account: accounts[7],
-            user_pool_token_account: accounts[8],
-            pool_base_token_account: accounts[9],
-            pool_quote_token_account: accounts[10],
+            pool: accounts[ix_accounts::withdraw::POOL],
+            user: accounts[ix_accounts::withdraw::USER],
+            base_mint: accounts[ix_accounts::withdraw::BASE_MINT],
+            quote_mint: accounts[ix_accounts::withdraw::QUOTE_MINT],
+            user_base_token_account: accounts[ix_accounts::withdraw::USER_BASE_TOKEN_ACCOUNT],
+            user_quote_token_account: accounts[ix_accounts::withdraw::USER_QUOTE_TOKEN_ACCOUNT],
+            user_pool_token_account: accounts[ix_accounts::withdraw::USER_POOL_TOKEN_ACCOUNT],
+            pool_base_token_account: accounts[ix_accounts::withdraw::POOL_BASE_TOKEN_ACCOUNT],
+            pool_quote_token_account: accounts[ix_accounts::withdraw::POOL_QUOTE_TOKEN_ACCOUNT],
             ..Default::default()
         }))
     }
@@ -403,6 +430,25 @@ impl EventParser for PumpSwapEventParser {
         )
     }
 
+    fn parse_events_from_inner_instruction_data(
+        &self,
+        data: &[u8],
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        self.inner.parse_events_from_inner_instruction_data(
+            data,
+            signature,
+            slot,
+            block_time,
+            program_received_time_ms,
+            index,
+        )
+    }
+
     fn parse_events_from_instruction(
         &self,
         instruction: &CompiledInstruction,
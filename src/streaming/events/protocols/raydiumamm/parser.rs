@@ -4,7 +4,6 @@ use prost_types::Timestamp;
 use solana_sdk::{instruction::CompiledInstruction, pubkey::Pubkey};
 use solana_transaction_status::UiCompiledInstruction;
 
-use crate::utils::scripts::Scripts;
 use crate::streaming::events::{
     common::{EventMetadata, EventType, ProtocolType},
     core::traits::{EventParser, GenericEventParseConfig, GenericEventParser, UnifiedEvent},
@@ -13,6 +12,7 @@ use crate::streaming::events::{
         RaydiumAmmV4SwapEvent, RaydiumAmmV4WithdrawEvent, RaydiumAmmV4WithdrawPnlEvent,
     },
 };
+use crate::streaming::events::protocols::idl::raydiumamm::{args, ix_accounts};
 
 /// Raydium CPMM程序ID
 pub const RAYDIUM_AMM_V4_PROGRAM_ID: Pubkey =
@@ -100,35 +100,38 @@ impl RaydiumAmmV4EventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if accounts.len() < 17 {
+        if accounts.len() < ix_accounts::withdraw_pnl::LEN {
             return None;
         }
 
         let mut metadata = metadata;
         metadata.set_id(format!(
             "{}-{}-{}-{}",
-            metadata.signature, accounts[3], accounts[10], accounts[11]
+            metadata.signature,
+            accounts[ix_accounts::withdraw_pnl::AMM_AUTHORITY],
+            accounts[ix_accounts::withdraw_pnl::AMM_TARGET_ORDERS],
+            accounts[ix_accounts::withdraw_pnl::SERUM_PROGRAM]
         ));
 
         Some(Box::new(RaydiumAmmV4WithdrawPnlEvent {
             metadata,
-            token_program: accounts[0],
-            amm: accounts[1],
-            amm_config: accounts[2],
-            amm_authority: accounts[3],
-            amm_open_orders: accounts[4],
-            pool_coin_token_account: accounts[5],
-            pool_pc_token_account: accounts[6],
-            coin_pnl_token_account: accounts[7],
-            pc_pnl_token_account: accounts[8],
-            pnl_owner_account: accounts[9],
-            amm_target_orders: accounts[10],
-            serum_program: accounts[11],
-            serum_market: accounts[12],
-            serum_event_queue: accounts[13],
-            serum_coin_vault_account: accounts[14],
-            serum_pc_vault_account: accounts[15],
-            serum_vault_signer: accounts[16],
+            token_program: accounts[ix_accounts::withdraw_pnl::TOKEN_PROGRAM],
+            amm: accounts[ix_accounts::withdraw_pnl::AMM],
+            amm_config: accounts[ix_accounts::withdraw_pnl::AMM_CONFIG],
+            amm_authority: accounts[ix_accounts::withdraw_pnl::AMM_AUTHORITY],
+            amm_open_orders: accounts[ix_accounts::withdraw_pnl::AMM_OPEN_ORDERS],
+            pool_coin_token_account: accounts[ix_accounts::withdraw_pnl::POOL_COIN_TOKEN_ACCOUNT],
+            pool_pc_token_account: accounts[ix_accounts::withdraw_pnl::POOL_PC_TOKEN_ACCOUNT],
+            coin_pnl_token_account: accounts[ix_accounts::withdraw_pnl::COIN_PNL_TOKEN_ACCOUNT],
+            pc_pnl_token_account: accounts[ix_accounts::withdraw_pnl::PC_PNL_TOKEN_ACCOUNT],
+            pnl_owner_account: accounts[ix_accounts::withdraw_pnl::PNL_OWNER_ACCOUNT],
+            amm_target_orders: accounts[ix_accounts::withdraw_pnl::AMM_TARGET_ORDERS],
+            serum_program: accounts[ix_accounts::withdraw_pnl::SERUM_PROGRAM],
+            serum_market: accounts[ix_accounts::withdraw_pnl::SERUM_MARKET],
+            serum_event_queue: accounts[ix_accounts::withdraw_pnl::SERUM_EVENT_QUEUE],
+            serum_coin_vault_account: accounts[ix_accounts::withdraw_pnl::SERUM_COIN_VAULT_ACCOUNT],
+            serum_pc_vault_account: accounts[ix_accounts::withdraw_pnl::SERUM_PC_VAULT_ACCOUNT],
+            serum_vault_signer: accounts[ix_accounts::withdraw_pnl::SERUM_VAULT_SIGNER],
         }))
     }
 
@@ -138,43 +141,46 @@ impl RaydiumAmmV4EventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 8 || accounts.len() < 22 {
+        if accounts.len() < ix_accounts::withdraw::LEN {
             return None;
         }
-        let amount = Scripts::readu64le(data, 0)?;
+        let args::Withdraw { amount } = args::Withdraw::decode(data)?;
 
         let mut metadata = metadata;
         metadata.set_id(format!(
             "{}-{}-{}-{}",
-            metadata.signature, accounts[3], accounts[10], accounts[11]
+            metadata.signature,
+            accounts[ix_accounts::withdraw::AMM_OPEN_ORDERS],
+            accounts[ix_accounts::withdraw::SERUM_PROGRAM],
+            accounts[ix_accounts::withdraw::SERUM_MARKET]
         ));
 
         Some(Box::new(RaydiumAmmV4WithdrawEvent {
             metadata,
             amount,
 
-            token_program: accounts[0],
-            amm: accounts[1],
-            amm_authority: accounts[2],
-            amm_open_orders: accounts[3],
-            amm_target_orders: accounts[4],
-            lp_mint_address: accounts[5],
-            pool_coin_token_account: accounts[6],
-            pool_pc_token_account: accounts[7],
-            pool_withdraw_queue: accounts[8],
-            pool_temp_lp_token_account: accounts[9],
-            serum_program: accounts[10],
-            serum_market: accounts[11],
-            serum_coin_vault_account: accounts[12],
-            serum_pc_vault_account: accounts[13],
-            serum_vault_signer: accounts[14],
-            user_lp_token_account: accounts[15],
-            user_coin_token_account: accounts[16],
-            user_pc_token_account: accounts[17],
-            user_owner: accounts[18],
-            serum_event_queue: accounts[19],
-            serum_bids: accounts[20],
-            serum_asks: accounts[21],
+            token_program: accounts[ix_accounts::withdraw::TOKEN_PROGRAM],
+            amm: accounts[ix_accounts::withdraw::AMM],
+            amm_authority: accounts[ix_accounts::withdraw::AMM_AUTHORITY],
+            amm_open_orders: accounts[ix_accounts::withdraw::AMM_OPEN_ORDERS],
+            amm_target_orders: accounts[ix_accounts::withdraw::AMM_TARGET_ORDERS],
+            lp_mint_address: accounts[ix_accounts::withdraw::LP_MINT_ADDRESS],
+            pool_coin_token_account: accounts[ix_accounts::withdraw::POOL_COIN_TOKEN_ACCOUNT],
+            pool_pc_token_account: accounts[ix_accounts::withdraw::POOL_PC_TOKEN_ACCOUNT],
+            pool_withdraw_queue: accounts[ix_accounts::withdraw::POOL_WITHDRAW_QUEUE],
+            pool_temp_lp_token_account: accounts[ix_accounts::withdraw::POOL_TEMP_LP_TOKEN_ACCOUNT],
+            serum_program: accounts[ix_accounts::withdraw::SERUM_PROGRAM],
+            serum_market: accounts[ix_accounts::withdraw::SERUM_MARKET],
+            serum_coin_vault_account: accounts[ix_accounts::withdraw::SERUM_COIN_VAULT_ACCOUNT],
+            serum_pc_vault_account: accounts[ix_accounts::withdraw::SERUM_PC_VAULT_ACCOUNT],
+            serum_vault_signer: accounts[ix_accounts::withdraw::SERUM_VAULT_SIGNER],
+            user_lp_token_account: accounts[ix_accounts::withdraw::USER_LP_TOKEN_ACCOUNT],
+            user_coin_token_account: accounts[ix_accounts::withdraw::UER_COIN_TOKEN_ACCOUNT],
+            user_pc_token_account: accounts[ix_accounts::withdraw::UER_PC_TOKEN_ACCOUNT],
+            user_owner: accounts[ix_accounts::withdraw::USER_OWNER],
+            serum_event_queue: accounts[ix_accounts::withdraw::SERUM_EVENT_Q],
+            serum_bids: accounts[ix_accounts::withdraw::SERUM_BIDS],
+            serum_asks: accounts[ix_accounts::withdraw::SERUM_ASKS],
         }))
     }
 
@@ -184,18 +190,19 @@ impl RaydiumAmmV4EventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 25 || accounts.len() < 21 {
+        if accounts.len() < ix_accounts::initialize2::LEN {
             return None;
         }
-        let nonce = data[0];
-        let open_time = Scripts::readu64le(data, 1)?;
-        let init_pc_amount = Scripts::readu64le(data, 9)?;
-        let init_coin_amount = Scripts::readu64le(data, 17)?;
+        let args::Initialize2 { nonce, open_time, init_pc_amount, init_coin_amount } =
+            args::Initialize2::decode(data)?;
 
         let mut metadata = metadata;
         metadata.set_id(format!(
             "{}-{}-{}-{}",
-            metadata.signature, accounts[3], accounts[10], accounts[11]
+            metadata.signature,
+            accounts[ix_accounts::initialize2::RENT],
+            accounts[ix_accounts::initialize2::POOL_COIN_TOKEN_ACCOUNT],
+            accounts[ix_accounts::initialize2::POOL_PC_TOKEN_ACCOUNT]
         ));
 
         Some(Box::new(RaydiumAmmV4Initialize2Event {
@@ -205,27 +212,28 @@ impl RaydiumAmmV4EventParser {
             init_pc_amount,
             init_coin_amount,
 
-            token_program: accounts[0],
-            spl_associated_token_account: accounts[1],
-            system_program: accounts[2],
-            rent: accounts[3],
-            amm: accounts[4],
-            amm_authority: accounts[5],
-            amm_open_orders: accounts[6],
-            lp_mint: accounts[7],
-            coin_mint: accounts[8],
-            pc_mint: accounts[9],
-            pool_coin_token_account: accounts[10],
-            pool_pc_token_account: accounts[11],
-            pool_withdraw_queue: accounts[12],
-            amm_target_orders: accounts[13],
-            pool_temp_lp: accounts[14],
-            serum_program: accounts[15],
-            serum_market: accounts[16],
-            user_wallet: accounts[17],
-            user_token_coin: accounts[18],
-            user_token_pc: accounts[19],
-            user_lp_token_account: accounts[20],
+            token_program: accounts[ix_accounts::initialize2::TOKEN_PROGRAM],
+            spl_associated_token_account: accounts
+                [ix_accounts::initialize2::SPL_ASSOCIATED_TOKEN_ACCOUNT],
+            system_program: accounts[ix_accounts::initialize2::SYSTEM_PROGRAM],
+            rent: accounts[ix_accounts::initialize2::RENT],
+            amm: accounts[ix_accounts::initialize2::AMM],
+            amm_authority: accounts[ix_accounts::initialize2::AMM_AUTHORITY],
+            amm_open_orders: accounts[ix_accounts::initialize2::AMM_OPEN_ORDERS],
+            lp_mint: accounts[ix_accounts::initialize2::LP_MINT],
+            coin_mint: accounts[ix_accounts::initialize2::COIN_MINT],
+            pc_mint: accounts[ix_accounts::initialize2::PC_MINT],
+            pool_coin_token_account: accounts[ix_accounts::initialize2::POOL_COIN_TOKEN_ACCOUNT],
+            pool_pc_token_account: accounts[ix_accounts::initialize2::POOL_PC_TOKEN_ACCOUNT],
+            pool_withdraw_queue: accounts[ix_accounts::initialize2::POOL_WITHDRAW_QUEUE],
+            amm_target_orders: accounts[ix_accounts::initialize2::AMM_TARGET_ORDERS],
+            pool_temp_lp: accounts[ix_accounts::initialize2::POOL_TEMP_LP],
+            serum_program: accounts[ix_accounts::initialize2::SERUM_PROGRAM],
+            serum_market: accounts[ix_accounts::initialize2::SERUM_MARKET],
+            user_wallet: accounts[ix_accounts::initialize2::USER_WALLET],
+            user_token_coin: accounts[ix_accounts::initialize2::USER_TOKEN_COIN],
+            user_token_pc: accounts[ix_accounts::initialize2::USER_TOKEN_PC],
+            user_lp_token_account: accounts[ix_accounts::initialize2::USER_LP_TOKEN_ACCOUNT],
         }))
     }
 
@@ -235,17 +243,19 @@ impl RaydiumAmmV4EventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 24 || accounts.len() < 14 {
+        if accounts.len() < ix_accounts::deposit::LEN {
             return None;
         }
-        let max_coin_amount = Scripts::readu64le(data, 0)?;
-        let max_pc_amount = Scripts::readu64le(data, 8)?;
-        let base_side = Scripts::readu64le(data, 16)?;
+        let args::Deposit { max_coin_amount, max_pc_amount, base_side } =
+            args::Deposit::decode(data)?;
 
         let mut metadata = metadata;
         metadata.set_id(format!(
             "{}-{}-{}-{}",
-            metadata.signature, accounts[3], accounts[10], accounts[11]
+            metadata.signature,
+            accounts[ix_accounts::deposit::AMM_OPEN_ORDERS],
+            accounts[ix_accounts::deposit::USER_PC_TOKEN_ACCOUNT],
+            accounts[ix_accounts::deposit::USER_LP_TOKEN_ACCOUNT]
         ));
 
         Some(Box::new(RaydiumAmmV4DepositEvent {
@@ -254,20 +264,20 @@ impl RaydiumAmmV4EventParser {
             max_pc_amount,
             base_side,
 
-            token_program: accounts[0],
-            amm: accounts[1],
-            amm_authority: accounts[2],
-            amm_open_orders: accounts[3],
-            amm_target_orders: accounts[4],
-            lp_mint_address: accounts[5],
-            pool_coin_token_account: accounts[6],
-            pool_pc_token_account: accounts[7],
-            serum_market: accounts[8],
-            user_coin_token_account: accounts[9],
-            user_pc_token_account: accounts[10],
-            user_lp_token_account: accounts[11],
-            user_owner: accounts[12],
-            serum_event_queue: accounts[13],
+            token_program: accounts[ix_accounts::deposit::TOKEN_PROGRAM],
+            amm: accounts[ix_accounts::deposit::AMM],
+            amm_authority: accounts[ix_accounts::deposit::AMM_AUTHORITY],
+            amm_open_orders: accounts[ix_accounts::deposit::AMM_OPEN_ORDERS],
+            amm_target_orders: accounts[ix_accounts::deposit::AMM_TARGET_ORDERS],
+            lp_mint_address: accounts[ix_accounts::deposit::LP_MINT_ADDRESS],
+            pool_coin_token_account: accounts[ix_accounts::deposit::POOL_COIN_TOKEN_ACCOUNT],
+            pool_pc_token_account: accounts[ix_accounts::deposit::POOL_PC_TOKEN_ACCOUNT],
+            serum_market: accounts[ix_accounts::deposit::SERUM_MARKET],
+            user_coin_token_account: accounts[ix_accounts::deposit::USER_COIN_TOKEN_ACCOUNT],
+            user_pc_token_account: accounts[ix_accounts::deposit::USER_PC_TOKEN_ACCOUNT],
+            user_lp_token_account: accounts[ix_accounts::deposit::USER_LP_TOKEN_ACCOUNT],
+            user_owner: accounts[ix_accounts::deposit::USER_OWNER],
+            serum_event_queue: accounts[ix_accounts::deposit::SERUM_EVENT_QUEUE],
         }))
     }
 
@@ -277,11 +287,10 @@ impl RaydiumAmmV4EventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 17 {
+        if accounts.len() < 17 {
             return None;
         }
-        let max_amount_in = Scripts::readu64le(data, 0)?;
-        let amount_out = Scripts::readu64le(data, 8)?;
+        let args::SwapBaseOut { max_amount_in, amount_out } = args::SwapBaseOut::decode(data)?;
 
         let mut metadata = metadata;
         metadata.set_id(format!(
@@ -293,7 +302,7 @@ impl RaydiumAmmV4EventParser {
         if accounts.len() == 17 {
             // 添加一个默认的 Pubkey 作为 amm_target_orders 的占位符
             // 因为在某些情况下，amm_target_orders 可能是可选的
-            accounts.insert(4, Pubkey::default());
+            accounts.insert(ix_accounts::swap_base_out::AMM_TARGET_ORDERS, Pubkey::default());
         }
 
         Some(Box::new(RaydiumAmmV4SwapEvent {
@@ -301,24 +310,27 @@ impl RaydiumAmmV4EventParser {
             max_amount_in,
             amount_out,
 
-            token_program: accounts[0],
-            amm: accounts[1],
-            amm_authority: accounts[2],
-            amm_open_orders: accounts[3],
-            amm_target_orders: Some(accounts[4]),
-            pool_coin_token_account: accounts[5],
-            pool_pc_token_account: accounts[6],
-            serum_program: accounts[7],
-            serum_market: accounts[8],
-            serum_bids: accounts[9],
-            serum_asks: accounts[10],
-            serum_event_queue: accounts[11],
-            serum_coin_vault_account: accounts[12],
-            serum_pc_vault_account: accounts[13],
-            serum_vault_signer: accounts[14],
-            user_source_token_account: accounts[15],
-            user_destination_token_account: accounts[16],
-            user_source_owner: accounts[17],
+            token_program: accounts[ix_accounts::swap_base_out::TOKEN_PROGRAM],
+            amm: accounts[ix_accounts::swap_base_out::AMM],
+            amm_authority: accounts[ix_accounts::swap_base_out::AMM_AUTHORITY],
+            amm_open_orders: accounts[ix_accounts::swap_base_out::AMM_OPEN_ORDERS],
+            amm_target_orders: Some(accounts[ix_accounts::swap_base_out::AMM_TARGET_ORDERS]),
+            pool_coin_token_account: accounts[ix_accounts::swap_base_out::POOL_COIN_TOKEN_ACCOUNT],
+            pool_pc_token_account: accounts[ix_accounts::swap_base_out::POOL_PC_TOKEN_ACCOUNT],
+            serum_program: accounts[ix_accounts::swap_base_out::SERUM_PROGRAM],
+            serum_market: accounts[ix_accounts::swap_base_out::SERUM_MARKET],
+            serum_bids: accounts[ix_accounts::swap_base_out::SERUM_BIDS],
+            serum_asks: accounts[ix_accounts::swap_base_out::SERUM_ASKS],
+            serum_event_queue: accounts[ix_accounts::swap_base_out::SERUM_EVENT_QUEUE],
+            serum_coin_vault_account: accounts
+                [ix_accounts::swap_base_out::SERUM_COIN_VAULT_ACCOUNT],
+            serum_pc_vault_account: accounts[ix_accounts::swap_base_out::SERUM_PC_VAULT_ACCOUNT],
+            serum_vault_signer: accounts[ix_accounts::swap_base_out::SERUM_VAULT_SIGNER],
+            user_source_token_account: accounts
+                [ix_accounts::swap_base_out::UER_SOURCE_TOKEN_ACCOUNT],
+            user_destination_token_account: accounts
+                [ix_accounts::swap_base_out::UER_DESTINATION_TOKEN_ACCOUNT],
+            user_source_owner: accounts[ix_accounts::swap_base_out::USER_SOURCE_OWNER],
 
             ..Default::default()
         }))
@@ -330,11 +342,10 @@ impl RaydiumAmmV4EventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 17 {
+        if accounts.len() < 17 {
             return None;
         }
-        let amount_in = Scripts::readu64le(data, 0)?;
-        let minimum_amount_out = Scripts::readu64le(data, 8)?;
+        let args::SwapBaseIn { amount_in, minimum_amount_out } = args::SwapBaseIn::decode(data)?;
 
         let mut metadata = metadata;
         metadata.set_id(format!(
@@ -346,7 +357,7 @@ impl RaydiumAmmV4EventParser {
         if accounts.len() == 17 {
             // 添加一个默认的 Pubkey 作为 amm_target_orders 的占位符
             // 因为在某些情况下，amm_target_orders 可能是可选的
-            accounts.insert(4, Pubkey::default());
+            accounts.insert(ix_accounts::swap_base_in::AMM_TARGET_ORDERS, Pubkey::default());
         }
 
         Some(Box::new(RaydiumAmmV4SwapEvent {
@@ -354,24 +365,26 @@ impl RaydiumAmmV4EventParser {
             amount_in,
             minimum_amount_out,
 
-            token_program: accounts[0],
-            amm: accounts[1],
-            amm_authority: accounts[2],
-            amm_open_orders: accounts[3],
-            amm_target_orders: Some(accounts[4]),
-            pool_coin_token_account: accounts[5],
-            pool_pc_token_account: accounts[6],
-            serum_program: accounts[7],
-            serum_market: accounts[8],
-            serum_bids: accounts[9],
-            serum_asks: accounts[10],
-            serum_event_queue: accounts[11],
-            serum_coin_vault_account: accounts[12],
-            serum_pc_vault_account: accounts[13],
-            serum_vault_signer: accounts[14],
-            user_source_token_account: accounts[15],
-            user_destination_token_account: accounts[16],
-            user_source_owner: accounts[17],
+            token_program: accounts[ix_accounts::swap_base_in::TOKEN_PROGRAM],
+            amm: accounts[ix_accounts::swap_base_in::AMM],
+            amm_authority: accounts[ix_accounts::swap_base_in::AMM_AUTHORITY],
+            amm_open_orders: accounts[ix_accounts::swap_base_in::AMM_OPEN_ORDERS],
+            amm_target_orders: Some(accounts[ix_accounts::swap_base_in::AMM_TARGET_ORDERS]),
+            pool_coin_token_account: accounts[ix_accounts::swap_base_in::POOL_COIN_TOKEN_ACCOUNT],
+            pool_pc_token_account: accounts[ix_accounts::swap_base_in::POOL_PC_TOKEN_ACCOUNT],
+            serum_program: accounts[ix_accounts::swap_base_in::SERUM_PROGRAM],
+            serum_market: accounts[ix_accounts::swap_base_in::SERUM_MARKET],
+            serum_bids: accounts[ix_accounts::swap_base_in::SERUM_BIDS],
+            serum_asks: accounts[ix_accounts::swap_base_in::SERUM_ASKS],
+            serum_event_queue: accounts[ix_accounts::swap_base_in::SERUM_EVENT_QUEUE],
+            serum_coin_vault_account: accounts[ix_accounts::swap_base_in::SERUM_COIN_VAULT_ACCOUNT],
+            serum_pc_vault_account: accounts[ix_accounts::swap_base_in::SERUM_PC_VAULT_ACCOUNT],
+            serum_vault_signer: accounts[ix_accounts::swap_base_in::SERUM_VAULT_SIGNER],
+            user_source_token_account: accounts
+                [ix_accounts::swap_base_in::UER_SOURCE_TOKEN_ACCOUNT],
+            user_destination_token_account: accounts
+                [ix_accounts::swap_base_in::UER_DESTINATION_TOKEN_ACCOUNT],
+            user_source_owner: accounts[ix_accounts::swap_base_in::USER_SOURCE_OWNER],
 
             ..Default::default()
         }))
@@ -405,6 +418,25 @@ impl EventParser for RaydiumAmmV4EventParser {
         )
     }
 
+    fn parse_events_from_inner_instruction_data(
+        &self,
+        data: &[u8],
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        self.inner.parse_events_from_inner_instruction_data(
+            data,
+            signature,
+            slot,
+            block_time,
+            program_received_time_ms,
+            index,
+        )
+    }
+
     fn parse_events_from_instruction(
         &self,
         instruction: &CompiledInstruction,
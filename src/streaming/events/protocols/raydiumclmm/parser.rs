@@ -4,7 +4,6 @@ use prost_types::Timestamp;
 use solana_sdk::{instruction::CompiledInstruction, pubkey::Pubkey};
 use solana_transaction_status::UiCompiledInstruction;
 
-use crate::utils::scripts::Scripts;
 use crate::streaming::events::{
     common::{
         EventMetadata,
@@ -18,6 +17,7 @@ use crate::streaming::events::{
         RaydiumClmmSwapEvent, RaydiumClmmSwapV2Event,
     },
 };
+use crate::streaming::events::protocols::idl::raydiumclmm::{args, ix_accounts};
 
 /// Raydium CLMM程序ID
 pub const RAYDIUM_CLMM_PROGRAM_ID: Pubkey =
@@ -123,45 +123,52 @@ impl RaydiumClmmEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 51 || accounts.len() < 22 {
+        if accounts.len() < ix_accounts::open_position_v2::LEN {
             return None;
         }
+        let args = args::OpenPositionV2::decode(data)?;
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}-{}", metadata.signature, accounts[0], accounts[1]));
+        metadata.set_id(format!(
+            "{}-{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::open_position_v2::PAYER],
+            accounts[ix_accounts::open_position_v2::POSITION_NFT_OWNER]
+        ));
         Some(Box::new(RaydiumClmmOpenPositionV2Event {
             metadata,
-            tick_lower_index: Scripts::readi32le(data, 0)?,
-            tick_upper_index: Scripts::readi32le(data, 4)?,
-            tick_array_lower_start_index: Scripts::readi32le(data, 8)?,
-            tick_array_upper_start_index: Scripts::readi32le(data, 12)?,
-            liquidity: Scripts::readu128le(data, 16)?,
-            amount0_max: Scripts::readu64le(data, 32)?,
-            amount1_max: Scripts::readu64le(data, 40)?,
-            with_metadata: Scripts::readu8le(data, 48)? == 1,
-            base_flag: Scripts::readoptbool(data, &mut 49)?,
-            payer: accounts[0],
-            position_nft_owner: accounts[1],
-            position_nft_mint: accounts[2],
-            position_nft_account: accounts[3],
-            metadata_account: accounts[4],
-            pool_state: accounts[5],
-            protocol_position: accounts[6],
-            tick_array_lower: accounts[7],
-            tick_array_upper: accounts[8],
-            personal_position: accounts[9],
-            token_account0: accounts[10],
-            token_account1: accounts[11],
-            token_vault0: accounts[12],
-            token_vault1: accounts[13],
-            rent: accounts[14],
-            system_program: accounts[15],
-            token_program: accounts[16],
-            associated_token_program: accounts[17],
-            metadata_program: accounts[18],
-            token_program2022: accounts[19],
-            vault0_mint: accounts[20],
-            vault1_mint: accounts[21],
-            remaining_accounts: accounts[22..].to_vec(),
+            tick_lower_index: args.tick_lower_index,
+            tick_upper_index: args.tick_upper_index,
+            tick_array_lower_start_index: args.tick_array_lower_start_index,
+            tick_array_upper_start_index: args.tick_array_upper_start_index,
+            liquidity: args.liquidity,
+            amount0_max: args.amount_0_max,
+            amount1_max: args.amount_1_max,
+            with_metadata: args.with_metadata,
+            base_flag: args.base_flag,
+            payer: accounts[ix_accounts::open_position_v2::PAYER],
+            position_nft_owner: accounts[ix_accounts::open_position_v2::POSITION_NFT_OWNER],
+            position_nft_mint: accounts[ix_accounts::open_position_v2::POSITION_NFT_MINT],
+            position_nft_account: accounts[ix_accounts::open_position_v2::POSITION_NFT_ACCOUNT],
+            metadata_account: accounts[ix_accounts::open_position_v2::METADATA_ACCOUNT],
+            pool_state: accounts[ix_accounts::open_position_v2::POOL_STATE],
+            protocol_position: accounts[ix_accounts::open_position_v2::PROTOCOL_POSITION],
+            tick_array_lower: accounts[ix_accounts::open_position_v2::TICK_ARRAY_LOWER],
+            tick_array_upper: accounts[ix_accounts::open_position_v2::TICK_ARRAY_UPPER],
+            personal_position: accounts[ix_accounts::open_position_v2::PERSONAL_POSITION],
+            token_account0: accounts[ix_accounts::open_position_v2::TOKEN_ACCOUNT_0],
+            token_account1: accounts[ix_accounts::open_position_v2::TOKEN_ACCOUNT_1],
+            token_vault0: accounts[ix_accounts::open_position_v2::TOKEN_VAULT_0],
+            token_vault1: accounts[ix_accounts::open_position_v2::TOKEN_VAULT_1],
+            rent: accounts[ix_accounts::open_position_v2::RENT],
+            system_program: accounts[ix_accounts::open_position_v2::SYSTEM_PROGRAM],
+            token_program: accounts[ix_accounts::open_position_v2::TOKEN_PROGRAM],
+            associated_token_program: accounts
+                [ix_accounts::open_position_v2::ASSOCIATED_TOKEN_PROGRAM],
+            metadata_program: accounts[ix_accounts::open_position_v2::METADATA_PROGRAM],
+            token_program2022: accounts[ix_accounts::open_position_v2::TOKEN_PROGRAM_2022],
+            vault0_mint: accounts[ix_accounts::open_position_v2::VAULT_0_MINT],
+            vault1_mint: accounts[ix_accounts::open_position_v2::VAULT_1_MINT],
+            remaining_accounts: accounts[ix_accounts::open_position_v2::LEN..].to_vec(),
         }))
     }
 
@@ -171,42 +178,57 @@ impl RaydiumClmmEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 51 || accounts.len() < 20 {
+        if accounts.len() < ix_accounts::open_position_with_token22_nft::LEN {
             return None;
         }
+        let args = args::OpenPositionWithToken22Nft::decode(data)?;
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}-{}", metadata.signature, accounts[0], accounts[1]));
+        metadata.set_id(format!(
+            "{}-{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::open_position_with_token22_nft::PAYER],
+            accounts[ix_accounts::open_position_with_token22_nft::POSITION_NFT_OWNER]
+        ));
         Some(Box::new(RaydiumClmmOpenPositionWithToken22NftEvent {
             metadata,
-            tick_lower_index: Scripts::readi32le(data, 0)?,
-            tick_upper_index: Scripts::readi32le(data, 4)?,
-            tick_array_lower_start_index: Scripts::readi32le(data, 8)?,
-            tick_array_upper_start_index: Scripts::readi32le(data, 12)?,
-            liquidity: Scripts::readu128le(data, 16)?,
-            amount0_max: Scripts::readu64le(data, 32)?,
-            amount1_max: Scripts::readu64le(data, 40)?,
-            with_metadata: Scripts::readu8le(data, 48)? == 1,
-            base_flag: Scripts::readoptbool(data, &mut 49)?,
-            payer: accounts[0],
-            position_nft_owner: accounts[1],
-            position_nft_mint: accounts[2],
-            position_nft_account: accounts[3],
-            pool_state: accounts[4],
-            protocol_position: accounts[5],
-            tick_array_lower: accounts[6],
-            tick_array_upper: accounts[7],
-            personal_position: accounts[8],
-            token_account0: accounts[9],
-            token_account1: accounts[10],
-            token_vault0: accounts[11],
-            token_vault1: accounts[12],
-            rent: accounts[13],
-            system_program: accounts[14],
-            token_program: accounts[15],
-            associated_token_program: accounts[16],
-            token_program2022: accounts[17],
-            vault0_mint: accounts[18],
-            vault1_mint: accounts[19],
+            tick_lower_index: args.tick_lower_index,
+            tick_upper_index: args.tick_upper_index,
+            tick_array_lower_start_index: args.tick_array_lower_start_index,
+            tick_array_upper_start_index: args.tick_array_upper_start_index,
+            liquidity: args.liquidity,
+            amount0_max: args.amount_0_max,
+            amount1_max: args.amount_1_max,
+            with_metadata: args.with_metadata,
+            base_flag: args.base_flag,
+            payer: accounts[ix_accounts::open_position_with_token22_nft::PAYER],
+            position_nft_owner: accounts
+                [ix_accounts::open_position_with_token22_nft::POSITION_NFT_OWNER],
+            position_nft_mint: accounts
+                [ix_accounts::open_position_with_token22_nft::POSITION_NFT_MINT],
+            position_nft_account: accounts
+                [ix_accounts::open_position_with_token22_nft::POSITION_NFT_ACCOUNT],
+            pool_state: accounts[ix_accounts::open_position_with_token22_nft::POOL_STATE],
+            protocol_position: accounts
+                [ix_accounts::open_position_with_token22_nft::PROTOCOL_POSITION],
+            tick_array_lower: accounts
+                [ix_accounts::open_position_with_token22_nft::TICK_ARRAY_LOWER],
+            tick_array_upper: accounts
+                [ix_accounts::open_position_with_token22_nft::TICK_ARRAY_UPPER],
+            personal_position: accounts
+                [ix_accounts::open_position_with_token22_nft::PERSONAL_POSITION],
+            token_account0: accounts[ix_accounts::open_position_with_token22_nft::TOKEN_ACCOUNT_0],
+            token_account1: accounts[ix_accounts::open_position_with_token22_nft::TOKEN_ACCOUNT_1],
+            token_vault0: accounts[ix_accounts::open_position_with_token22_nft::TOKEN_VAULT_0],
+            token_vault1: accounts[ix_accounts::open_position_with_token22_nft::TOKEN_VAULT_1],
+            rent: accounts[ix_accounts::open_position_with_token22_nft::RENT],
+            system_program: accounts[ix_accounts::open_position_with_token22_nft::SYSTEM_PROGRAM],
+            token_program: accounts[ix_accounts::open_position_with_token22_nft::TOKEN_PROGRAM],
+            associated_token_program: accounts
+                [ix_accounts::open_position_with_token22_nft::ASSOCIATED_TOKEN_PROGRAM],
+            token_program2022: accounts
+                [ix_accounts::open_position_with_token22_nft::TOKEN_PROGRAM_2022],
+            vault0_mint: accounts[ix_accounts::open_position_with_token22_nft::VAULT_0_MINT],
+            vault1_mint: accounts[ix_accounts::open_position_with_token22_nft::VAULT_1_MINT],
         }))
     }
 
@@ -216,32 +238,38 @@ impl RaydiumClmmEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 34 || accounts.len() < 15 {
+        if accounts.len() < ix_accounts::increase_liquidity_v2::LEN {
             return None;
         }
+        let args = args::IncreaseLiquidityV2::decode(data)?;
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}-{}", metadata.signature, accounts[0], accounts[1]));
+        metadata.set_id(format!(
+            "{}-{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::increase_liquidity_v2::NFT_OWNER],
+            accounts[ix_accounts::increase_liquidity_v2::NFT_ACCOUNT]
+        ));
         Some(Box::new(RaydiumClmmIncreaseLiquidityV2Event {
             metadata,
-            liquidity: Scripts::readu128le(data, 0)?,
-            amount0_max: Scripts::readu64le(data, 16)?,
-            amount1_max: Scripts::readu64le(data, 24)?,
-            base_flag: Scripts::readoptbool(data, &mut 32)?,
-            nft_owner: accounts[0],
-            nft_account: accounts[1],
-            pool_state: accounts[2],
-            protocol_position: accounts[3],
-            personal_position: accounts[4],
-            tick_array_lower: accounts[5],
-            tick_array_upper: accounts[6],
-            token_account0: accounts[7],
-            token_account1: accounts[8],
-            token_vault0: accounts[9],
-            token_vault1: accounts[10],
-            token_program: accounts[11],
-            token_program2022: accounts[12],
-            vault0_mint: accounts[13],
-            vault1_mint: accounts[14],
+            liquidity: args.liquidity,
+            amount0_max: args.amount_0_max,
+            amount1_max: args.amount_1_max,
+            base_flag: args.base_flag,
+            nft_owner: accounts[ix_accounts::increase_liquidity_v2::NFT_OWNER],
+            nft_account: accounts[ix_accounts::increase_liquidity_v2::NFT_ACCOUNT],
+            pool_state: accounts[ix_accounts::increase_liquidity_v2::POOL_STATE],
+            protocol_position: accounts[ix_accounts::increase_liquidity_v2::PROTOCOL_POSITION],
+            personal_position: accounts[ix_accounts::increase_liquidity_v2::PERSONAL_POSITION],
+            tick_array_lower: accounts[ix_accounts::increase_liquidity_v2::TICK_ARRAY_LOWER],
+            tick_array_upper: accounts[ix_accounts::increase_liquidity_v2::TICK_ARRAY_UPPER],
+            token_account0: accounts[ix_accounts::increase_liquidity_v2::TOKEN_ACCOUNT_0],
+            token_account1: accounts[ix_accounts::increase_liquidity_v2::TOKEN_ACCOUNT_1],
+            token_vault0: accounts[ix_accounts::increase_liquidity_v2::TOKEN_VAULT_0],
+            token_vault1: accounts[ix_accounts::increase_liquidity_v2::TOKEN_VAULT_1],
+            token_program: accounts[ix_accounts::increase_liquidity_v2::TOKEN_PROGRAM],
+            token_program2022: accounts[ix_accounts::increase_liquidity_v2::TOKEN_PROGRAM_2022],
+            vault0_mint: accounts[ix_accounts::increase_liquidity_v2::VAULT_0_MINT],
+            vault1_mint: accounts[ix_accounts::increase_liquidity_v2::VAULT_1_MINT],
         }))
     }
 
@@ -251,28 +279,34 @@ impl RaydiumClmmEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 24 || accounts.len() < 13 {
+        if accounts.len() < ix_accounts::create_pool::LEN {
             return None;
         }
+        let args = args::CreatePool::decode(data)?;
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}-{}", metadata.signature, accounts[0], accounts[1]));
+        metadata.set_id(format!(
+            "{}-{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::create_pool::POOL_CREATOR],
+            accounts[ix_accounts::create_pool::AMM_CONFIG]
+        ));
         Some(Box::new(RaydiumClmmCreatePoolEvent {
             metadata,
-            sqrt_price_x64: Scripts::readu128le(data, 0)?,
-            open_time: Scripts::readu64le(data, 16)?,
-            pool_creator: accounts[0],
-            amm_config: accounts[1],
-            pool_state: accounts[2],
-            token_mint0: accounts[3],
-            token_mint1: accounts[4],
-            token_vault0: accounts[5],
-            token_vault1: accounts[6],
-            observation_state: accounts[7],
-            tick_array_bitmap: accounts[8],
-            token_program0: accounts[9],
-            token_program1: accounts[10],
-            system_program: accounts[11],
-            rent: accounts[12],
+            sqrt_price_x64: args.sqrt_price_x64,
+            open_time: args.open_time,
+            pool_creator: accounts[ix_accounts::create_pool::POOL_CREATOR],
+            amm_config: accounts[ix_accounts::create_pool::AMM_CONFIG],
+            pool_state: accounts[ix_accounts::create_pool::POOL_STATE],
+            token_mint0: accounts[ix_accounts::create_pool::TOKEN_MINT_0],
+            token_mint1: accounts[ix_accounts::create_pool::TOKEN_MINT_1],
+            token_vault0: accounts[ix_accounts::create_pool::TOKEN_VAULT_0],
+            token_vault1: accounts[ix_accounts::create_pool::TOKEN_VAULT_1],
+            observation_state: accounts[ix_accounts::create_pool::OBSERVATION_STATE],
+            tick_array_bitmap: accounts[ix_accounts::create_pool::TICK_ARRAY_BITMAP],
+            token_program0: accounts[ix_accounts::create_pool::TOKEN_PROGRAM_0],
+            token_program1: accounts[ix_accounts::create_pool::TOKEN_PROGRAM_1],
+            system_program: accounts[ix_accounts::create_pool::SYSTEM_PROGRAM],
+            rent: accounts[ix_accounts::create_pool::RENT],
         }))
     }
 
@@ -282,33 +316,41 @@ impl RaydiumClmmEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 32 || accounts.len() < 16 {
+        if accounts.len() < ix_accounts::decrease_liquidity_v2::LEN {
             return None;
         }
+        let args = args::DecreaseLiquidityV2::decode(data)?;
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}-{}", metadata.signature, accounts[0], accounts[1]));
+        metadata.set_id(format!(
+            "{}-{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::decrease_liquidity_v2::NFT_OWNER],
+            accounts[ix_accounts::decrease_liquidity_v2::NFT_ACCOUNT]
+        ));
         Some(Box::new(RaydiumClmmDecreaseLiquidityV2Event {
             metadata,
-            liquidity: Scripts::readu128le(data, 0)?,
-            amount0_min: Scripts::readu64le(data, 16)?,
-            amount1_min: Scripts::readu64le(data, 24)?,
-            nft_owner: accounts[0],
-            nft_account: accounts[1],
-            personal_position: accounts[2],
-            pool_state: accounts[3],
-            protocol_position: accounts[4],
-            token_vault0: accounts[5],
-            token_vault1: accounts[6],
-            tick_array_lower: accounts[7],
-            tick_array_upper: accounts[8],
-            recipient_token_account0: accounts[9],
-            recipient_token_account1: accounts[10],
-            token_program: accounts[11],
-            token_program2022: accounts[12],
-            memo_program: accounts[13],
-            vault0_mint: accounts[14],
-            vault1_mint: accounts[15],
-            remaining_accounts: accounts[16..].to_vec(),
+            liquidity: args.liquidity,
+            amount0_min: args.amount_0_min,
+            amount1_min: args.amount_1_min,
+            nft_owner: accounts[ix_accounts::decrease_liquidity_v2::NFT_OWNER],
+            nft_account: accounts[ix_accounts::decrease_liquidity_v2::NFT_ACCOUNT],
+            personal_position: accounts[ix_accounts::decrease_liquidity_v2::PERSONAL_POSITION],
+            pool_state: accounts[ix_accounts::decrease_liquidity_v2::POOL_STATE],
+            protocol_position: accounts[ix_accounts::decrease_liquidity_v2::PROTOCOL_POSITION],
+            token_vault0: accounts[ix_accounts::decrease_liquidity_v2::TOKEN_VAULT_0],
+            token_vault1: accounts[ix_accounts::decrease_liquidity_v2::TOKEN_VAULT_1],
+            tick_array_lower: accounts[ix_accounts::decrease_liquidity_v2::TICK_ARRAY_LOWER],
+            tick_array_upper: accounts[ix_accounts::decrease_liquidity_v2::TICK_ARRAY_UPPER],
+            recipient_token_account0: accounts
+                [ix_accounts::decrease_liquidity_v2::RECIPIENT_TOKEN_ACCOUNT_0],
+            recipient_token_account1: accounts
+                [ix_accounts::decrease_liquidity_v2::RECIPIENT_TOKEN_ACCOUNT_1],
+            token_program: accounts[ix_accounts::decrease_liquidity_v2::TOKEN_PROGRAM],
+            token_program2022: accounts[ix_accounts::decrease_liquidity_v2::TOKEN_PROGRAM_2022],
+            memo_program: accounts[ix_accounts::decrease_liquidity_v2::MEMO_PROGRAM],
+            vault0_mint: accounts[ix_accounts::decrease_liquidity_v2::VAULT_0_MINT],
+            vault1_mint: accounts[ix_accounts::decrease_liquidity_v2::VAULT_1_MINT],
+            remaining_accounts: accounts[ix_accounts::decrease_liquidity_v2::LEN..].to_vec(),
         }))
     }
 
@@ -318,19 +360,24 @@ impl RaydiumClmmEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if accounts.len() < 6 {
+        if accounts.len() < ix_accounts::close_position::LEN {
             return None;
         }
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}-{}", metadata.signature, accounts[0], accounts[1]));
+        metadata.set_id(format!(
+            "{}-{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::close_position::NFT_OWNER],
+            accounts[ix_accounts::close_position::POSITION_NFT_MINT]
+        ));
         Some(Box::new(RaydiumClmmClosePositionEvent {
             metadata,
-            nft_owner: accounts[0],
-            position_nft_mint: accounts[1],
-            position_nft_account: accounts[2],
-            personal_position: accounts[3],
-            system_program: accounts[4],
-            token_program: accounts[5],
+            nft_owner: accounts[ix_accounts::close_position::NFT_OWNER],
+            position_nft_mint: accounts[ix_accounts::close_position::POSITION_NFT_MINT],
+            position_nft_account: accounts[ix_accounts::close_position::POSITION_NFT_ACCOUNT],
+            personal_position: accounts[ix_accounts::close_position::PERSONAL_POSITION],
+            system_program: accounts[ix_accounts::close_position::SYSTEM_PROGRAM],
+            token_program: accounts[ix_accounts::close_position::TOKEN_PROGRAM],
         }))
     }
 
@@ -340,19 +387,20 @@ impl RaydiumClmmEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 33 || accounts.len() < 10 {
+        if accounts.len() < ix_accounts::swap::LEN {
             return None;
         }
 
-        let amount = Scripts::readu64le(data, 0)?;
-        let other_amount_threshold = Scripts::readu64le(data, 8)?;
-        let sqrt_price_limit_x64 = Scripts::readu128le(data, 16)?;
-        let is_base_input = Scripts::readu8le(data, 32)?;
+        let args::Swap { amount, other_amount_threshold, sqrt_price_limit_x64, is_base_input } =
+            args::Swap::decode(data)?;
 
         let mut metadata = metadata;
         metadata.set_id(format!(
             "{}-{}-{}-{}",
-            metadata.signature, accounts[2], accounts[3], accounts[4]
+            metadata.signature,
+            accounts[ix_accounts::swap::POOL_STATE],
+            accounts[ix_accounts::swap::INPUT_TOKEN_ACCOUNT],
+            accounts[ix_accounts::swap::OUTPUT_TOKEN_ACCOUNT]
         ));
 
         Some(Box::new(RaydiumClmmSwapEvent {
@@ -360,18 +408,18 @@ impl RaydiumClmmEventParser {
             amount,
             other_amount_threshold,
             sqrt_price_limit_x64,
-            is_base_input: is_base_input == 1,
-            payer: accounts[0],
-            amm_config: accounts[1],
-            pool_state: accounts[2],
-            input_token_account: accounts[3],
-            output_token_account: accounts[4],
-            input_vault: accounts[5],
-            output_vault: accounts[6],
-            observation_state: accounts[7],
-            token_program: accounts[8],
-            tick_array: accounts[9],
-            remaining_accounts: accounts[10..].to_vec(),
+            is_base_input,
+            payer: accounts[ix_accounts::swap::PAYER],
+            amm_config: accounts[ix_accounts::swap::AMM_CONFIG],
+            pool_state: accounts[ix_accounts::swap::POOL_STATE],
+            input_token_account: accounts[ix_accounts::swap::INPUT_TOKEN_ACCOUNT],
+            output_token_account: accounts[ix_accounts::swap::OUTPUT_TOKEN_ACCOUNT],
+            input_vault: accounts[ix_accounts::swap::INPUT_VAULT],
+            output_vault: accounts[ix_accounts::swap::OUTPUT_VAULT],
+            observation_state: accounts[ix_accounts::swap::OBSERVATION_STATE],
+            token_program: accounts[ix_accounts::swap::TOKEN_PROGRAM],
+            tick_array: accounts[ix_accounts::swap::TICK_ARRAY],
+            remaining_accounts: accounts[ix_accounts::swap::LEN..].to_vec(),
         }))
     }
 
@@ -380,19 +428,20 @@ impl RaydiumClmmEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 33 || accounts.len() < 13 {
+        if accounts.len() < ix_accounts::swap_v2::LEN {
             return None;
         }
 
-        let amount = Scripts::readu64le(data, 0)?;
-        let other_amount_threshold = Scripts::readu64le(data, 8)?;
-        let sqrt_price_limit_x64 = Scripts::readu128le(data, 16)?;
-        let is_base_input = Scripts::readu8le(data, 32)?;
+        let args::SwapV2 { amount, other_amount_threshold, sqrt_price_limit_x64, is_base_input } =
+            args::SwapV2::decode(data)?;
 
         let mut metadata = metadata;
         metadata.set_id(format!(
             "{}-{}-{}-{}",
-            metadata.signature, accounts[2], accounts[3], accounts[4]
+            metadata.signature,
+            accounts[ix_accounts::swap_v2::POOL_STATE],
+            accounts[ix_accounts::swap_v2::INPUT_TOKEN_ACCOUNT],
+            accounts[ix_accounts::swap_v2::OUTPUT_TOKEN_ACCOUNT]
         ));
 
         Some(Box::new(RaydiumClmmSwapV2Event {
@@ -400,21 +449,21 @@ impl RaydiumClmmEventParser {
             amount,
             other_amount_threshold,
             sqrt_price_limit_x64,
-            is_base_input: is_base_input == 1,
-            payer: accounts[0],
-            amm_config: accounts[1],
-            pool_state: accounts[2],
-            input_token_account: accounts[3],
-            output_token_account: accounts[4],
-            input_vault: accounts[5],
-            output_vault: accounts[6],
-            observation_state: accounts[7],
-            token_program: accounts[8],
-            token_program2022: accounts[9],
-            memo_program: accounts[10],
-            input_vault_mint: accounts[11],
-            output_vault_mint: accounts[12],
-            remaining_accounts: accounts[13..].to_vec(),
+            is_base_input,
+            payer: accounts[ix_accounts::swap_v2::PAYER],
+            amm_config: accounts[ix_accounts::swap_v2::AMM_CONFIG],
+            pool_state: accounts[ix_accounts::swap_v2::POOL_STATE],
+            input_token_account: accounts[ix_accounts::swap_v2::INPUT_TOKEN_ACCOUNT],
+            output_token_account: accounts[ix_accounts::swap_v2::OUTPUT_TOKEN_ACCOUNT],
+            input_vault: accounts[ix_accounts::swap_v2::INPUT_VAULT],
+            output_vault: accounts[ix_accounts::swap_v2::OUTPUT_VAULT],
+            observation_state: accounts[ix_accounts::swap_v2::OBSERVATION_STATE],
+            token_program: accounts[ix_accounts::swap_v2::TOKEN_PROGRAM],
+            token_program2022: accounts[ix_accounts::swap_v2::TOKEN_PROGRAM_2022],
+            memo_program: accounts[ix_accounts::swap_v2::MEMO_PROGRAM],
+            input_vault_mint: accounts[ix_accounts::swap_v2::INPUT_VAULT_MINT],
+            output_vault_mint: accounts[ix_accounts::swap_v2::OUTPUT_VAULT_MINT],
+            remaining_accounts: accounts[ix_accounts::swap_v2::LEN..].to_vec(),
         }))
     }
 }
@@ -446,6 +495,25 @@ impl EventParser for RaydiumClmmEventParser {
         )
     }
 
+    fn parse_events_from_inner_instruction_data(
+        &self,
+        data: &[u8],
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        self.inner.parse_events_from_inner_instruction_data(
+            data,
+            signature,
+            slot,
+            block_time,
+            program_received_time_ms,
+            index,
+        )
+    }
+
     fn parse_events_from_instruction(
         &self,
         instruction: &CompiledInstruction,
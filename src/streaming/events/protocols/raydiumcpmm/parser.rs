@@ -4,7 +4,6 @@ use prost_types::Timestamp;
 use solana_sdk::{instruction::CompiledInstruction, pubkey::Pubkey};
 use solana_transaction_status::UiCompiledInstruction;
 
-use crate::utils::scripts::Scripts;
 use crate::streaming::events::{
     common::{EventMetadata, EventType, ProtocolType},
     core::traits::{EventParser, GenericEventParseConfig, GenericEventParser, UnifiedEvent},
@@ -13,6 +12,7 @@ use crate::streaming::events::{
         RaydiumCpmmWithdrawEvent,
     },
 };
+use crate::streaming::events::protocols::idl::raydiumcpmm::{args, ix_accounts};
 
 /// Raydium CPMM程序ID
 pub const RAYDIUM_CPMM_PROGRAM_ID: Pubkey =
@@ -91,30 +91,36 @@ impl RaydiumCpmmEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 24 || accounts.len() < 14 {
+        if accounts.len() < ix_accounts::withdraw::LEN {
             return None;
         }
+        let args = args::Withdraw::decode(data)?;
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}-{}", metadata.signature, accounts[0], accounts[1]));
+        metadata.set_id(format!(
+            "{}-{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::withdraw::OWNER],
+            accounts[ix_accounts::withdraw::AUTHORITY]
+        ));
         Some(Box::new(RaydiumCpmmWithdrawEvent {
             metadata,
-            lp_token_amount: Scripts::readu64le(data, 0)?,
-            minimum_token0_amount: Scripts::readu64le(data, 8)?,
-            minimum_token1_amount: Scripts::readu64le(data, 16)?,
-            owner: accounts[0],
-            authority: accounts[1],
-            pool_state: accounts[2],
-            owner_lp_token: accounts[3],
-            token0_account: accounts[4],
-            token1_account: accounts[5],
-            token0_vault: accounts[6],
-            token1_vault: accounts[7],
-            token_program: accounts[8],
-            token_program2022: accounts[9],
-            vault0_mint: accounts[10],
-            vault1_mint: accounts[11],
-            lp_mint: accounts[12],
-            memo_program: accounts[13],
+            lp_token_amount: args.lp_token_amount,
+            minimum_token0_amount: args.minimum_token0_amount,
+            minimum_token1_amount: args.minimum_token1_amount,
+            owner: accounts[ix_accounts::withdraw::OWNER],
+            authority: accounts[ix_accounts::withdraw::AUTHORITY],
+            pool_state: accounts[ix_accounts::withdraw::POOL_STATE],
+            owner_lp_token: accounts[ix_accounts::withdraw::OWNER_LP_TOKEN],
+            token0_account: accounts[ix_accounts::withdraw::TOKEN0_ACCOUNT],
+            token1_account: accounts[ix_accounts::withdraw::TOKEN1_ACCOUNT],
+            token0_vault: accounts[ix_accounts::withdraw::TOKEN0_VAULT],
+            token1_vault: accounts[ix_accounts::withdraw::TOKEN1_VAULT],
+            token_program: accounts[ix_accounts::withdraw::TOKEN_PROGRAM],
+            token_program2022: accounts[ix_accounts::withdraw::TOKEN_PROGRAM2022],
+            vault0_mint: accounts[ix_accounts::withdraw::VAULT0_MINT],
+            vault1_mint: accounts[ix_accounts::withdraw::VAULT1_MINT],
+            lp_mint: accounts[ix_accounts::withdraw::LP_MINT],
+            memo_program: accounts[ix_accounts::withdraw::MEMO_PROGRAM],
         }))
     }
 
@@ -124,36 +130,42 @@ impl RaydiumCpmmEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 24 || accounts.len() < 20 {
+        if accounts.len() < ix_accounts::initialize::LEN {
             return None;
         }
+        let args = args::Initialize::decode(data)?;
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}-{}", metadata.signature, accounts[0], accounts[1]));
+        metadata.set_id(format!(
+            "{}-{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::initialize::CREATOR],
+            accounts[ix_accounts::initialize::AMM_CONFIG]
+        ));
         Some(Box::new(RaydiumCpmmInitializeEvent {
             metadata,
-            init_amount0: Scripts::readu64le(data, 0)?,
-            init_amount1: Scripts::readu64le(data, 8)?,
-            open_time: Scripts::readu64le(data, 16)?,
-            creator: accounts[0],
-            amm_config: accounts[1],
-            authority: accounts[2],
-            pool_state: accounts[3],
-            token0_mint: accounts[4],
-            token1_mint: accounts[5],
-            lp_mint: accounts[6],
-            creator_token0: accounts[7],
-            creator_token1: accounts[8],
-            creator_lp_token: accounts[9],
-            token0_vault: accounts[10],
-            token1_vault: accounts[11],
-            create_pool_fee: accounts[12],
-            observation_state: accounts[13],
-            token_program: accounts[14],
-            token0_program: accounts[15],
-            token1_program: accounts[16],
-            associated_token_program: accounts[17],
-            system_program: accounts[18],
-            rent: accounts[19],
+            init_amount0: args.init_amount0,
+            init_amount1: args.init_amount1,
+            open_time: args.open_time,
+            creator: accounts[ix_accounts::initialize::CREATOR],
+            amm_config: accounts[ix_accounts::initialize::AMM_CONFIG],
+            authority: accounts[ix_accounts::initialize::AUTHORITY],
+            pool_state: accounts[ix_accounts::initialize::POOL_STATE],
+            token0_mint: accounts[ix_accounts::initialize::TOKEN0_MINT],
+            token1_mint: accounts[ix_accounts::initialize::TOKEN1_MINT],
+            lp_mint: accounts[ix_accounts::initialize::LP_MINT],
+            creator_token0: accounts[ix_accounts::initialize::CREATOR_TOKEN0],
+            creator_token1: accounts[ix_accounts::initialize::CREATOR_TOKEN1],
+            creator_lp_token: accounts[ix_accounts::initialize::CREATOR_LP_TOKEN],
+            token0_vault: accounts[ix_accounts::initialize::TOKEN0_VAULT],
+            token1_vault: accounts[ix_accounts::initialize::TOKEN1_VAULT],
+            create_pool_fee: accounts[ix_accounts::initialize::CREATE_POOL_FEE],
+            observation_state: accounts[ix_accounts::initialize::OBSERVATION_STATE],
+            token_program: accounts[ix_accounts::initialize::TOKEN_PROGRAM],
+            token0_program: accounts[ix_accounts::initialize::TOKEN0_PROGRAM],
+            token1_program: accounts[ix_accounts::initialize::TOKEN1_PROGRAM],
+            associated_token_program: accounts[ix_accounts::initialize::ASSOCIATED_TOKEN_PROGRAM],
+            system_program: accounts[ix_accounts::initialize::SYSTEM_PROGRAM],
+            rent: accounts[ix_accounts::initialize::RENT],
         }))
     }
 
@@ -163,29 +175,35 @@ impl RaydiumCpmmEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 24 || accounts.len() < 13 {
+        if accounts.len() < ix_accounts::deposit::LEN {
             return None;
         }
+        let args = args::Deposit::decode(data)?;
         let mut metadata = metadata;
-        metadata.set_id(format!("{}-{}-{}", metadata.signature, accounts[0], accounts[1]));
+        metadata.set_id(format!(
+            "{}-{}-{}",
+            metadata.signature,
+            accounts[ix_accounts::deposit::OWNER],
+            accounts[ix_accounts::deposit::AUTHORITY]
+        ));
         Some(Box::new(RaydiumCpmmDepositEvent {
             metadata,
-            lp_token_amount: Scripts::readu64le(data, 0)?,
-            maximum_token0_amount: Scripts::readu64le(data, 8)?,
-            maximum_token1_amount: Scripts::readu64le(data, 16)?,
-            owner: accounts[0],
-            authority: accounts[1],
-            pool_state: accounts[2],
-            owner_lp_token: accounts[3],
-            token0_account: accounts[4],
-            token1_account: accounts[5],
-            token0_vault: accounts[6],
-            token1_vault: accounts[7],
-            token_program: accounts[8],
-            token_program2022: accounts[9],
-            vault0_mint: accounts[10],
-            vault1_mint: accounts[11],
-            lp_mint: accounts[12],
+            lp_token_amount: args.lp_token_amount,
+            maximum_token0_amount: args.maximum_token0_amount,
+            maximum_token1_amount: args.maximum_token1_amount,
+            owner: accounts[ix_accounts::deposit::OWNER],
+            authority: accounts[ix_accounts::deposit::AUTHORITY],
+            pool_state: accounts[ix_accounts::deposit::POOL_STATE],
+            owner_lp_token: accounts[ix_accounts::deposit::OWNER_LP_TOKEN],
+            token0_account: accounts[ix_accounts::deposit::TOKEN0_ACCOUNT],
+            token1_account: accounts[ix_accounts::deposit::TOKEN1_ACCOUNT],
+            token0_vault: accounts[ix_accounts::deposit::TOKEN0_VAULT],
+            token1_vault: accounts[ix_accounts::deposit::TOKEN1_VAULT],
+            token_program: accounts[ix_accounts::deposit::TOKEN_PROGRAM],
+            token_program2022: accounts[ix_accounts::deposit::TOKEN_PROGRAM2022],
+            vault0_mint: accounts[ix_accounts::deposit::VAULT0_MINT],
+            vault1_mint: accounts[ix_accounts::deposit::VAULT1_MINT],
+            lp_mint: accounts[ix_accounts::deposit::LP_MINT],
         }))
     }
 
@@ -195,36 +213,39 @@ impl RaydiumCpmmEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 13 {
+        if accounts.len() < ix_accounts::swap_base_input::LEN {
             return None;
         }
 
-        let amount_in = Scripts::readu64le(data, 0)?;
-        let minimum_amount_out = Scripts::readu64le(data, 8)?;
+        let args::SwapBaseInput { amount_in, minimum_amount_out } =
+            args::SwapBaseInput::decode(data)?;
 
         let mut metadata = metadata;
         metadata.set_id(format!(
             "{}-{}-{}-{}",
-            metadata.signature, accounts[3], accounts[10], accounts[11]
+            metadata.signature,
+            accounts[ix_accounts::swap_base_input::POOL_STATE],
+            accounts[ix_accounts::swap_base_input::INPUT_TOKEN_MINT],
+            accounts[ix_accounts::swap_base_input::OUTPUT_TOKEN_MINT]
         ));
 
         Some(Box::new(RaydiumCpmmSwapEvent {
             metadata,
             amount_in,
             minimum_amount_out,
-            payer: accounts[0],
-            authority: accounts[1],
-            amm_config: accounts[2],
-            pool_state: accounts[3],
-            input_token_account: accounts[4],
-            output_token_account: accounts[5],
-            input_vault: accounts[6],
-            output_vault: accounts[7],
-            input_token_program: accounts[8],
-            output_token_program: accounts[9],
-            input_token_mint: accounts[10],
-            output_token_mint: accounts[11],
-            observation_state: accounts[12],
+            payer: accounts[ix_accounts::swap_base_input::PAYER],
+            authority: accounts[ix_accounts::swap_base_input::AUTHORITY],
+            amm_config: accounts[ix_accounts::swap_base_input::AMM_CONFIG],
+            pool_state: accounts[ix_accounts::swap_base_input::POOL_STATE],
+            input_token_account: accounts[ix_accounts::swap_base_input::INPUT_TOKEN_ACCOUNT],
+            output_token_account: accounts[ix_accounts::swap_base_input::OUTPUT_TOKEN_ACCOUNT],
+            input_vault: accounts[ix_accounts::swap_base_input::INPUT_VAULT],
+            output_vault: accounts[ix_accounts::swap_base_input::OUTPUT_VAULT],
+            input_token_program: accounts[ix_accounts::swap_base_input::INPUT_TOKEN_PROGRAM],
+            output_token_program: accounts[ix_accounts::swap_base_input::OUTPUT_TOKEN_PROGRAM],
+            input_token_mint: accounts[ix_accounts::swap_base_input::INPUT_TOKEN_MINT],
+            output_token_mint: accounts[ix_accounts::swap_base_input::OUTPUT_TOKEN_MINT],
+            observation_state: accounts[ix_accounts::swap_base_input::OBSERVATION_STATE],
             ..Default::default()
         }))
     }
@@ -234,36 +255,39 @@ impl RaydiumCpmmEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 13 {
+        if accounts.len() < ix_accounts::swap_base_output::LEN {
             return None;
         }
 
-        let max_amount_in = Scripts::readu64le(data, 0)?;
-        let amount_out = Scripts::readu64le(data, 8)?;
+        let args::SwapBaseOutput { max_amount_in, amount_out } =
+            args::SwapBaseOutput::decode(data)?;
 
         let mut metadata = metadata;
         metadata.set_id(format!(
             "{}-{}-{}-{}",
-            metadata.signature, accounts[3], accounts[10], accounts[11]
+            metadata.signature,
+            accounts[ix_accounts::swap_base_output::POOL_STATE],
+            accounts[ix_accounts::swap_base_output::INPUT_TOKEN_MINT],
+            accounts[ix_accounts::swap_base_output::OUTPUT_TOKEN_MINT]
         ));
 
         Some(Box::new(RaydiumCpmmSwapEvent {
             metadata,
             max_amount_in,
             amount_out,
-            payer: accounts[0],
-            authority: accounts[1],
-            amm_config: accounts[2],
-            pool_state: accounts[3],
-            input_token_account: accounts[4],
-            output_token_account: accounts[5],
-            input_vault: accounts[6],
-            output_vault: accounts[7],
-            input_token_program: accounts[8],
-            output_token_program: accounts[9],
-            input_token_mint: accounts[10],
-            output_token_mint: accounts[11],
-            observation_state: accounts[12],
+            payer: accounts[ix_accounts::swap_base_output::PAYER],
+            authority: accounts[ix_accounts::swap_base_output::AUTHORITY],
+            amm_config: accounts[ix_accounts::swap_base_output::AMM_CONFIG],
+            pool_state: accounts[ix_accounts::swap_base_output::POOL_STATE],
+            input_token_account: accounts[ix_accounts::swap_base_output::INPUT_TOKEN_ACCOUNT],
+            output_token_account: accounts[ix_accounts::swap_base_output::OUTPUT_TOKEN_ACCOUNT],
+            input_vault: accounts[ix_accounts::swap_base_output::INPUT_VAULT],
+            output_vault: accounts[ix_accounts::swap_base_output::OUTPUT_VAULT],
+            input_token_program: accounts[ix_accounts::swap_base_output::INPUT_TOKEN_PROGRAM],
+            output_token_program: accounts[ix_accounts::swap_base_output::OUTPUT_TOKEN_PROGRAM],
+            input_token_mint: accounts[ix_accounts::swap_base_output::INPUT_TOKEN_MINT],
+            output_token_mint: accounts[ix_accounts::swap_base_output::OUTPUT_TOKEN_MINT],
+            observation_state: accounts[ix_accounts::swap_base_output::OBSERVATION_STATE],
             ..Default::default()
         }))
     }
@@ -296,6 +320,25 @@ impl EventParser for RaydiumCpmmEventParser {
         )
     }
 
+    fn parse_events_from_inner_instruction_data(
+        &self,
+        data: &[u8],
+        signature: &str,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        program_received_time_ms: i64,
+        index: String,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        self.inner.parse_events_from_inner_instruction_data(
+            data,
+            signature,
+            slot,
+            block_time,
+            program_received_time_ms,
+            index,
+        )
+    }
+
     fn parse_events_from_instruction(
         &self,
         instruction: &CompiledInstruction,
@@ -0,0 +1,439 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self as std_mpsc, RecvTimeoutError, SyncSender, TrySendError};
+use std::sync::Mutex;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail};
+use futures::channel::mpsc as futures_mpsc;
+use log::{error, warn};
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use yellowstone_grpc_proto::geyser::{subscribe_update::UpdateOneof, SubscribeRequest, SubscribeUpdate};
+use yellowstone_grpc_proto::prost::Message;
+
+use super::streamhandler::StreamHandler;
+use super::supervisor::UpdateDeduper;
+use super::types::EventPretty;
+use crate::common::AnyResult;
+use crate::streaming::common::{BackpressureStrategy, CaptureConfig};
+
+/// 抓包文件头（明文），其后为 zstd 压缩的帧序列
+pub const CAPTURE_MAGIC: &[u8; 8] = b"GRCAP\x00\x00\x01";
+/// 写入线程在空闲时刷新压缩流的间隔，进程异常退出时最多丢失这段时间的数据
+const CAPTURE_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+/// 单帧上限，防止损坏的长度字段导致超大分配
+const CAPTURE_MAX_FRAME_LEN: usize = 512 * 1024 * 1024;
+
+/// 写入队列中的帧：到达时间（Unix 微秒）与已编码的 protobuf 字节
+type EncodedFrame = (i64, Vec<u8>);
+
+/// 抓包帧：本地到达时间（Unix 微秒）+ 原始 SubscribeUpdate
+#[derive(Debug, Clone)]
+pub struct CaptureFrame {
+    pub received_at_us: i64,
+    pub update: SubscribeUpdate,
+}
+
+/// 原始 Geyser 流量录制器
+///
+/// 帧格式：`i64 LE 到达时间 | u32 LE 长度 | protobuf 字节`，整个帧序列经 zstd 压缩。
+/// 编码在调用方线程完成，写盘在独立线程进行；队列满时丢弃帧而不阻塞读取器
+pub struct CaptureRecorder {
+    sender: Mutex<Option<SyncSender<EncodedFrame>>>,
+    writer: Mutex<Option<JoinHandle<()>>>,
+    recorded: AtomicU64,
+    dropped: AtomicU64,
+}
+
+impl CaptureRecorder {
+    /// 按配置创建录制器；未设置 path 时返回 None
+    pub fn from_config(config: &CaptureConfig) -> AnyResult<Option<Self>> {
+        match &config.path {
+            Some(path) => Ok(Some(Self::create(path, config.compression_level, config.queue_size)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// 启动写入线程；抓包文件在第一帧到达时才创建（覆盖），
+    /// 仅构造客户端而未开始订阅时不会清空已有的抓包
+    pub fn create(path: impl AsRef<Path>, compression_level: i32, queue_size: usize) -> AnyResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        let (sender, receiver) = std_mpsc::sync_channel::<EncodedFrame>(queue_size.max(1));
+        let writer = std::thread::Builder::new()
+            .name("geyser-capture".to_string())
+            .spawn(move || {
+                if let Err(e) = Self::write_loop(&path, compression_level, receiver) {
+                    error!("Capture writer for {} stopped: {e:?}", path.display());
+                }
+            })?;
+
+        Ok(Self {
+            sender: Mutex::new(Some(sender)),
+            writer: Mutex::new(Some(writer)),
+            recorded: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+        })
+    }
+
+    /// 记录一条收到的更新（非阻塞）
+    pub fn record(&self, update: &SubscribeUpdate) {
+        let received_at_us = chrono::Utc::now().timestamp_micros();
+        let sender = match self.sender.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let Some(sender) = sender.as_ref() else {
+            return;
+        };
+        match sender.try_send((received_at_us, update.encode_to_vec())) {
+            Ok(()) => {
+                self.recorded.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(TrySendError::Full(_)) | Err(TrySendError::Disconnected(_)) => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// 已写入队列的帧数
+    pub fn recorded(&self) -> u64 {
+        self.recorded.load(Ordering::Relaxed)
+    }
+
+    /// 因队列已满或写入线程退出而丢弃的帧数
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// 停止录制：写完队列中剩余的帧并结束压缩流
+    pub fn finish(&self) {
+        if let Ok(mut sender) = self.sender.lock() {
+            sender.take();
+        }
+        let writer = match self.writer.lock() {
+            Ok(mut guard) => guard.take(),
+            Err(poisoned) => poisoned.into_inner().take(),
+        };
+        if let Some(writer) = writer
+            && writer.join().is_err()
+        {
+            error!("Capture writer panicked");
+        }
+    }
+
+    /// 创建（覆盖）抓包文件，写入文件头并返回压缩流
+    fn open(
+        path: &Path,
+        compression_level: i32,
+    ) -> AnyResult<zstd::stream::write::Encoder<'static, BufWriter<File>>> {
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(CAPTURE_MAGIC)?;
+        Ok(zstd::stream::write::Encoder::new(file, compression_level)?)
+    }
+
+    fn write_loop(
+        path: &Path,
+        compression_level: i32,
+        receiver: std_mpsc::Receiver<EncodedFrame>,
+    ) -> AnyResult<()> {
+        let mut encoder = None;
+        let mut dirty = false;
+        loop {
+            match receiver.recv_timeout(CAPTURE_FLUSH_INTERVAL) {
+                Ok((received_at_us, bytes)) => {
+                    if encoder.is_none() {
+                        encoder = Some(Self::open(path, compression_level)?);
+                    }
+                    let Some(encoder) = encoder.as_mut() else {
+                        continue;
+                    };
+                    encoder.write_all(&received_at_us.to_le_bytes())?;
+                    encoder.write_all(&(bytes.len() as u32).to_le_bytes())?;
+                    encoder.write_all(&bytes)?;
+                    dirty = true;
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    if dirty && let Some(encoder) = encoder.as_mut() {
+                        encoder.flush()?;
+                        dirty = false;
+                    }
+                }
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+        if let Some(encoder) = encoder {
+            encoder.finish()?.flush()?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for CaptureRecorder {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+/// 抓包文件顺序读取器
+pub struct CaptureReader {
+    decoder: zstd::stream::read::Decoder<'static, BufReader<File>>,
+}
+
+impl CaptureReader {
+    pub fn open(path: impl AsRef<Path>) -> AnyResult<Self> {
+        let mut file = BufReader::new(File::open(path.as_ref())?);
+        let mut magic = [0u8; 8];
+        file.read_exact(&mut magic)?;
+        if &magic != CAPTURE_MAGIC {
+            bail!("{} is not a Geyser capture file", path.as_ref().display());
+        }
+        Ok(Self { decoder: zstd::stream::read::Decoder::with_buffer(file)? })
+    }
+
+    /// 读取下一帧；文件结束返回 None。录制被中断导致的尾部残帧视为结束
+    pub fn next_frame(&mut self) -> AnyResult<Option<CaptureFrame>> {
+        let mut header = [0u8; 12];
+        match read_full(&mut self.decoder, &mut header) {
+            Ok(true) => {}
+            Ok(false) => return Ok(None),
+            Err(e) => {
+                warn!("Capture ended with a truncated frame header: {e}");
+                return Ok(None);
+            }
+        }
+        let received_at_us = i64::from_le_bytes(header[..8].try_into()?);
+        let len = u32::from_le_bytes(header[8..].try_into()?) as usize;
+        if len > CAPTURE_MAX_FRAME_LEN {
+            return Err(anyhow!("Capture frame length {len} exceeds limit"));
+        }
+
+        let mut bytes = vec![0u8; len];
+        if let Err(e) = self.decoder.read_exact(&mut bytes) {
+            warn!("Capture ended with a truncated frame: {e}");
+            return Ok(None);
+        }
+        let update = SubscribeUpdate::decode(bytes.as_slice())?;
+        Ok(Some(CaptureFrame { received_at_us, update }))
+    }
+}
+
+impl Iterator for CaptureReader {
+    type Item = AnyResult<CaptureFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_frame().transpose()
+    }
+}
+
+/// 填满缓冲区；在帧边界处正常结束返回 false，帧内结束返回错误
+fn read_full(reader: &mut impl Read, buf: &mut [u8]) -> std::io::Result<bool> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) if filled == 0 => return Ok(false),
+            Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(true)
+}
+
+/// 回放节奏
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplayPacing {
+    /// 尽可能快
+    AsFastAsPossible,
+    /// 按录制时的到达间隔回放；speed 为倍速（1.0 为原速）
+    Original { speed: f64 },
+}
+
+/// 回放统计
+#[derive(Debug, Clone, Default)]
+pub struct ReplayStats {
+    pub frames: u64,
+    pub duplicates: u64,
+    /// 抓包覆盖的时长（首帧到末帧）
+    pub captured_span_ms: u64,
+}
+
+/// 抓包回放源：将抓包文件中的更新重新送入 StreamHandler（与实时读取器相同的下游）
+pub struct CaptureReplay {
+    path: String,
+    pacing: ReplayPacing,
+    dedup_capacity: usize,
+}
+
+impl CaptureReplay {
+    pub fn new(path: impl Into<String>, pacing: ReplayPacing) -> Self {
+        Self { path: path.into(), pacing, dedup_capacity: 0 }
+    }
+
+    /// 回放时按与实时读取器相同的方式去重（多端点录制的文件包含每个端点的副本）
+    pub fn with_dedup(mut self, capacity: usize) -> Self {
+        self.dedup_capacity = capacity;
+        self
+    }
+
+    /// 读取抓包并送入通道；文件读完后返回（通道随之由调用方释放）
+    pub async fn run(
+        self,
+        tx: mpsc::Sender<EventPretty>,
+        backpressure_strategy: BackpressureStrategy,
+    ) -> AnyResult<ReplayStats> {
+        let reader = CaptureReader::open(&self.path)?;
+
+        // 解压与解码在阻塞线程中进行
+        let (frame_tx, mut frame_rx) = mpsc::channel::<AnyResult<CaptureFrame>>(1024);
+        let read_task = tokio::task::spawn_blocking(move || {
+            for frame in reader {
+                let failed = frame.is_err();
+                if frame_tx.blocking_send(frame).is_err() || failed {
+                    break;
+                }
+            }
+        });
+
+        // 回放不存在真实连接，ping 应答写入一个无人读取的通道
+        let (mut subscribe_tx, _subscribe_rx) = futures_mpsc::unbounded::<SubscribeRequest>();
+        let mut deduper = UpdateDeduper::new(self.dedup_capacity);
+        let mut stats = ReplayStats::default();
+        let mut origin: Option<(i64, Instant)> = None;
+        let mut last_us = 0i64;
+
+        while let Some(frame) = frame_rx.recv().await {
+            let CaptureFrame { received_at_us, update } = frame?;
+
+            let (first_us, started) = *origin.get_or_insert((received_at_us, Instant::now()));
+            last_us = last_us.max(received_at_us);
+            if let ReplayPacing::Original { speed } = self.pacing {
+                let offset_us = (received_at_us - first_us).max(0) as f64 / speed.max(f64::EPSILON);
+                tokio::time::sleep_until(started + Duration::from_micros(offset_us as u64)).await;
+            }
+
+            stats.frames += 1;
+            if matches!(update.update_oneof, Some(UpdateOneof::Ping(_)) | Some(UpdateOneof::Pong(_))) {
+                continue;
+            }
+            if !deduper.check(&update) {
+                stats.duplicates += 1;
+                continue;
+            }
+
+            StreamHandler::handle_stream_message(update, &tx, &mut subscribe_tx, backpressure_strategy)
+                .await?;
+        }
+
+        if let Some((first_us, _)) = origin {
+            stats.captured_span_ms = ((last_us - first_us).max(0) / 1000) as u64;
+        }
+        let _ = read_task.await;
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use yellowstone_grpc_proto::geyser::{SlotStatus, SubscribeUpdatePing, SubscribeUpdateSlot};
+
+    /// 每个测试使用独立的临时文件
+    fn capture_path(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("ghostreaver-{}-{}.cap", name, std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn slot_update(slot: u64) -> SubscribeUpdate {
+        SubscribeUpdate {
+            update_oneof: Some(UpdateOneof::Slot(SubscribeUpdateSlot {
+                slot,
+                status: SlotStatus::SlotProcessed as i32,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+    }
+
+    fn ping_update() -> SubscribeUpdate {
+        SubscribeUpdate {
+            update_oneof: Some(UpdateOneof::Ping(SubscribeUpdatePing {})),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn recorded_updates_read_back_in_order() {
+        let path = capture_path("roundtrip");
+        let updates = vec![slot_update(1), ping_update(), slot_update(2)];
+
+        let recorder = CaptureRecorder::create(&path, 1, 16).unwrap();
+        for update in &updates {
+            recorder.record(update);
+        }
+        recorder.finish();
+        assert_eq!(recorder.recorded(), 3);
+        assert_eq!(recorder.dropped(), 0);
+
+        let frames: Vec<CaptureFrame> =
+            CaptureReader::open(&path).unwrap().collect::<AnyResult<_>>().unwrap();
+        let read: Vec<SubscribeUpdate> = frames.iter().map(|frame| frame.update.clone()).collect();
+        assert_eq!(read, updates);
+        assert!(frames.windows(2).all(|pair| pair[0].received_at_us <= pair[1].received_at_us));
+
+        // 录制结束后不再接受新帧
+        recorder.record(&slot_update(3));
+        assert_eq!(recorder.recorded(), 3);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn capture_file_is_created_on_first_frame() {
+        let path = capture_path("lazy");
+        std::fs::write(&path, b"previous capture").unwrap();
+
+        // 未收到任何帧时不覆盖已有文件
+        CaptureRecorder::create(&path, 1, 16).unwrap().finish();
+        assert_eq!(std::fs::read(&path).unwrap(), b"previous capture");
+        assert!(CaptureReader::open(&path).is_err());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn replay_skips_control_frames_and_duplicates() {
+        let path = capture_path("replay");
+        let recorder = CaptureRecorder::create(&path, 1, 16).unwrap();
+        for update in [slot_update(1), ping_update(), slot_update(1), slot_update(2)] {
+            recorder.record(&update);
+        }
+        recorder.finish();
+
+        let (tx, mut rx) = mpsc::channel(16);
+        let stats = CaptureReplay::new(path.to_string_lossy(), ReplayPacing::AsFastAsPossible)
+            .with_dedup(16)
+            .run(tx, BackpressureStrategy::Block)
+            .await
+            .unwrap();
+        assert_eq!(stats.frames, 4);
+        assert_eq!(stats.duplicates, 1);
+
+        let mut slots = Vec::new();
+        while let Some(event_pretty) = rx.recv().await {
+            if let EventPretty::Slot(slot) = event_pretty {
+                slots.push(slot.slot);
+            }
+        }
+        assert_eq!(slots, [1, 2]);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,286 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use crate::streaming::common::CommitmentTrackingConfig;
+use crate::streaming::events::common::types::{
+    EventType, BLOCK_EVENT_TYPES, COMMITMENT_EVENT_TYPES, SLOT_EVENT_TYPES,
+};
+use crate::streaming::events::core::traits::UnifiedEvent;
+use crate::streaming::events::protocols::block::commitment::{
+    EventConfirmedEvent, EventRolledBackEvent, RollbackReason,
+};
+use crate::streaming::events::protocols::block::slot::SlotStatus;
+
+/// 已发出、等待确认的事件
+#[derive(Debug, Clone)]
+struct TrackedEvent {
+    id: String,
+    event_type: EventType,
+    signature: String,
+}
+
+/// 提交级别跟踪器：记住以 processed 级别发出的事件，
+/// 在所属 slot 变为 confirmed / finalized 或 dead 时生成后续通知
+pub struct CommitmentTracker {
+    config: CommitmentTrackingConfig,
+    state: Mutex<TrackerState>,
+}
+
+#[derive(Default)]
+struct TrackerState {
+    /// slot -> 尚未确认的事件
+    pending: BTreeMap<u64, Vec<TrackedEvent>>,
+    /// 最近已确定状态（confirmed / finalized / dead）的 slot，
+    /// 状态先于事件到达时（confirmed 级别订阅、from_slot 重放、乱序处理）据此立即处理
+    resolved: BTreeMap<u64, SlotStatus>,
+}
+
+impl CommitmentTracker {
+    pub fn new(config: CommitmentTrackingConfig) -> Self {
+        Self { config, state: Mutex::new(TrackerState::default()) }
+    }
+
+    /// 记录一条已发出的事件；slot / 区块 / 提交通知本身不跟踪。
+    /// 所属 slot 的状态已知时不再等待，直接返回对应的确认 / 回滚通知
+    pub fn track(&self, event: &dyn UnifiedEvent) -> Option<Box<dyn UnifiedEvent>> {
+        let event_type = event.event_type();
+        if SLOT_EVENT_TYPES.contains(&event_type)
+            || BLOCK_EVENT_TYPES.contains(&event_type)
+            || COMMITMENT_EVENT_TYPES.contains(&event_type)
+        {
+            return None;
+        }
+
+        let slot = event.slot();
+        let tracked = TrackedEvent {
+            id: event.id().to_string(),
+            event_type,
+            signature: event.signature().to_string(),
+        };
+        let mut state = self.lock();
+        match state.resolved.get(&slot).copied() {
+            Some(SlotStatus::Dead) => {
+                return Self::rolled_back(slot, vec![tracked], RollbackReason::Dead).next();
+            }
+            Some(status @ (SlotStatus::Confirmed | SlotStatus::Finalized)) => {
+                return Some(Self::confirmed(slot, tracked, status));
+            }
+            _ => {}
+        }
+
+        state.pending.entry(slot).or_default().push(tracked);
+        // 超出容量时淘汰最旧的 slot
+        while state.pending.len() > self.config.max_tracked_slots.max(1) {
+            state.pending.pop_first();
+        }
+        None
+    }
+
+    /// 处理 slot 状态变化，返回需要发出的确认 / 回滚通知
+    pub fn on_slot(&self, slot: u64, status: SlotStatus) -> Vec<Box<dyn UnifiedEvent>> {
+        let mut notifications: Vec<Box<dyn UnifiedEvent>> = Vec::new();
+        let mut state = self.lock();
+        // finalized 之后的 confirmed 通知（乱序）不降级
+        if status != SlotStatus::Processed
+            && state.resolved.get(&slot) != Some(&SlotStatus::Finalized)
+        {
+            state.resolved.insert(slot, status);
+            while state.resolved.len() > self.config.max_tracked_slots.max(1) {
+                state.resolved.pop_first();
+            }
+        }
+
+        match status {
+            SlotStatus::Processed => {}
+            SlotStatus::Confirmed | SlotStatus::Finalized => {
+                if let Some(events) = state.pending.remove(&slot) {
+                    notifications.extend(
+                        events.into_iter().map(|tracked| Self::confirmed(slot, tracked, status)),
+                    );
+                }
+                // 更高的 slot 已 finalized：仍未确认的更低 slot 所在分叉已被放弃
+                if status == SlotStatus::Finalized && self.config.rollback_orphaned_on_finalized {
+                    let newer = state.pending.split_off(&slot);
+                    let orphaned = std::mem::replace(&mut state.pending, newer);
+                    for (orphan_slot, events) in orphaned {
+                        notifications.extend(Self::rolled_back(
+                            orphan_slot,
+                            events,
+                            RollbackReason::Orphaned,
+                        ));
+                    }
+                }
+            }
+            SlotStatus::Dead => {
+                if let Some(events) = state.pending.remove(&slot) {
+                    notifications.extend(Self::rolled_back(slot, events, RollbackReason::Dead));
+                }
+            }
+        }
+
+        notifications
+    }
+
+    /// 当前等待确认的事件数
+    pub fn pending_count(&self) -> usize {
+        self.lock().pending.values().map(Vec::len).sum()
+    }
+
+    fn confirmed(slot: u64, tracked: TrackedEvent, status: SlotStatus) -> Box<dyn UnifiedEvent> {
+        Box::new(EventConfirmedEvent::new(
+            tracked.id,
+            tracked.event_type,
+            tracked.signature,
+            slot,
+            status,
+        ))
+    }
+
+    fn rolled_back(
+        slot: u64,
+        events: Vec<TrackedEvent>,
+        reason: RollbackReason,
+    ) -> impl Iterator<Item = Box<dyn UnifiedEvent>> {
+        events.into_iter().map(move |tracked| {
+            Box::new(EventRolledBackEvent::new(
+                tracked.id,
+                tracked.event_type,
+                tracked.signature,
+                slot,
+                reason,
+            )) as Box<dyn UnifiedEvent>
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, TrackerState> {
+        match self.state.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::events::common::types::ProtocolType;
+    use crate::streaming::events::common::EventMetadata;
+    use crate::streaming::events::protocols::pumpfun::PumpFunTradeEvent;
+    use solana_sdk::pubkey::Pubkey;
+
+    fn tracker(max_tracked_slots: usize) -> CommitmentTracker {
+        CommitmentTracker::new(CommitmentTrackingConfig {
+            enabled: true,
+            max_tracked_slots,
+            rollback_orphaned_on_finalized: true,
+        })
+    }
+
+    fn trade(id: &str, slot: u64) -> PumpFunTradeEvent {
+        PumpFunTradeEvent {
+            metadata: EventMetadata::new(
+                id.to_string(),
+                format!("sig_{}", id),
+                slot,
+                0,
+                0,
+                ProtocolType::PumpFun,
+                EventType::PumpFunBuy,
+                Pubkey::default(),
+                "0".to_string(),
+                0,
+            ),
+            ..Default::default()
+        }
+    }
+
+    fn confirmed(event: &dyn UnifiedEvent) -> &EventConfirmedEvent {
+        event.as_any().downcast_ref::<EventConfirmedEvent>().expect("confirmed event")
+    }
+
+    fn rolled_back(event: &dyn UnifiedEvent) -> &EventRolledBackEvent {
+        event.as_any().downcast_ref::<EventRolledBackEvent>().expect("rolled back event")
+    }
+
+    #[test]
+    fn confirmed_slot_releases_its_events() {
+        let tracker = tracker(16);
+        assert!(tracker.track(&trade("a", 10)).is_none());
+        assert!(tracker.track(&trade("b", 10)).is_none());
+        assert!(tracker.track(&trade("c", 11)).is_none());
+        assert_eq!(tracker.pending_count(), 3);
+
+        assert!(tracker.on_slot(10, SlotStatus::Processed).is_empty());
+
+        let notifications = tracker.on_slot(10, SlotStatus::Confirmed);
+        let ids: Vec<&str> =
+            notifications.iter().map(|n| confirmed(n.as_ref()).original_id.as_str()).collect();
+        assert_eq!(ids, ["a", "b"]);
+        assert_eq!(confirmed(notifications[0].as_ref()).status, SlotStatus::Confirmed);
+        assert_eq!(confirmed(notifications[0].as_ref()).original_signature, "sig_a");
+        assert_eq!(tracker.pending_count(), 1);
+    }
+
+    #[test]
+    fn dead_slot_rolls_back_its_events() {
+        let tracker = tracker(16);
+        tracker.track(&trade("a", 10));
+
+        let notifications = tracker.on_slot(10, SlotStatus::Dead);
+        assert_eq!(notifications.len(), 1);
+        let event = rolled_back(notifications[0].as_ref());
+        assert_eq!(event.original_id, "a");
+        assert_eq!(event.reason, RollbackReason::Dead);
+        assert_eq!(tracker.pending_count(), 0);
+    }
+
+    #[test]
+    fn finalized_slot_orphans_older_pending_slots() {
+        let tracker = tracker(16);
+        tracker.track(&trade("old", 9));
+        tracker.track(&trade("final", 10));
+        tracker.track(&trade("new", 11));
+
+        let notifications = tracker.on_slot(10, SlotStatus::Finalized);
+        assert_eq!(notifications.len(), 2);
+        assert_eq!(confirmed(notifications[0].as_ref()).original_id, "final");
+        let orphan = rolled_back(notifications[1].as_ref());
+        assert_eq!(orphan.original_id, "old");
+        assert_eq!(orphan.reason, RollbackReason::Orphaned);
+
+        // 更高的 slot 不受影响
+        assert_eq!(tracker.pending_count(), 1);
+    }
+
+    #[test]
+    fn status_before_event_resolves_immediately() {
+        let tracker = tracker(16);
+        assert!(tracker.on_slot(10, SlotStatus::Confirmed).is_empty());
+        assert!(tracker.on_slot(11, SlotStatus::Dead).is_empty());
+
+        let notification = tracker.track(&trade("a", 10)).expect("already confirmed");
+        assert_eq!(confirmed(notification.as_ref()).status, SlotStatus::Confirmed);
+
+        let notification = tracker.track(&trade("b", 11)).expect("already dead");
+        assert_eq!(rolled_back(notification.as_ref()).reason, RollbackReason::Dead);
+        assert_eq!(tracker.pending_count(), 0);
+
+        // finalized 之后乱序到达的 confirmed 不降级
+        tracker.on_slot(12, SlotStatus::Finalized);
+        tracker.on_slot(12, SlotStatus::Confirmed);
+        let notification = tracker.track(&trade("c", 12)).expect("already finalized");
+        assert_eq!(confirmed(notification.as_ref()).status, SlotStatus::Finalized);
+    }
+
+    #[test]
+    fn oldest_slots_are_evicted_at_capacity() {
+        let tracker = tracker(2);
+        tracker.track(&trade("a", 10));
+        tracker.track(&trade("b", 11));
+        tracker.track(&trade("c", 12));
+        assert_eq!(tracker.pending_count(), 2);
+
+        assert!(tracker.on_slot(10, SlotStatus::Confirmed).is_empty());
+        assert_eq!(tracker.on_slot(12, SlotStatus::Confirmed).len(), 1);
+    }
+}
@@ -0,0 +1,279 @@
+use std::sync::Arc;
+
+use tokio::sync::watch;
+use yellowstone_grpc_proto::geyser::{
+    SubscribeRequest, SubscribeRequestFilterAccounts, SubscribeRequestFilterTransactions,
+};
+
+/// 账户过滤器在订阅请求中的键（与 subscribe_with_account_request 一致）
+pub const ACCOUNTS_FILTER_KEY: &str = "";
+/// 交易过滤器在订阅请求中的键（与 get_subscribe_request_filter 一致）
+pub const TRANSACTIONS_FILTER_KEY: &str = "client";
+/// 完整区块过滤器在订阅请求中的键（与 build_block_request 一致）
+pub const BLOCKS_FILTER_KEY: &str = "blocks";
+
+/// 运行时订阅控制器：修改后的 SubscribeRequest 会通过现有连接的 subscribe_tx 下发，
+/// 无需重连；重连时也会使用最新的请求
+#[derive(Clone)]
+pub struct SubscriptionController {
+    sender: Arc<watch::Sender<SubscribeRequest>>,
+    /// 初始请求，用于恢复为原始过滤器
+    baseline: Arc<SubscribeRequest>,
+}
+
+impl SubscriptionController {
+    pub fn new(request: SubscribeRequest) -> Self {
+        let (sender, _) = watch::channel(request.clone());
+        Self { sender: Arc::new(sender), baseline: Arc::new(request) }
+    }
+
+    /// 供读取器监听请求变化
+    pub fn receiver(&self) -> watch::Receiver<SubscribeRequest> {
+        self.sender.subscribe()
+    }
+
+    /// 当前生效的请求
+    pub fn current(&self) -> SubscribeRequest {
+        self.sender.borrow().clone()
+    }
+
+    /// 整体替换订阅请求
+    pub fn replace(&self, mut request: SubscribeRequest) {
+        request.from_slot = None;
+        self.sender.send_replace(request);
+    }
+
+    /// 就地修改订阅请求；仅在请求实际变化时下发，返回是否变化
+    pub fn update<F>(&self, modify: F) -> bool
+    where
+        F: FnOnce(&mut SubscribeRequest),
+    {
+        self.sender.send_if_modified(|request| {
+            let before = request.clone();
+            modify(request);
+            request.from_slot = None;
+            *request != before
+        })
+    }
+
+    /// 恢复为初始请求
+    pub fn reset(&self) {
+        self.replace((*self.baseline).clone());
+    }
+
+    /// 将现有的交易与账户过滤器收窄到给定账户（交易 account_include、账户 account，
+    /// 并清空 owner）；不会新增原本没有的过滤器。列表为空时恢复初始请求
+    pub fn narrow(&self, accounts: Vec<String>) -> bool {
+        if accounts.is_empty() {
+            return self.update(|request| *request = (*self.baseline).clone());
+        }
+        self.update(|request| {
+            if let Some(filter) = transactions_filter(request) {
+                filter.account_include = accounts.clone();
+            }
+            if let Some(filter) = request.accounts.get_mut(ACCOUNTS_FILTER_KEY) {
+                filter.account = accounts;
+                filter.owner.clear();
+            }
+        })
+    }
+
+    /// 添加账户公钥
+    pub fn add_accounts(&self, accounts: &[String]) -> bool {
+        self.update(|request| merge(&mut accounts_filter(request).account, accounts))
+    }
+
+    /// 移除账户公钥
+    pub fn remove_accounts(&self, accounts: &[String]) -> bool {
+        self.update(|request| {
+            if let Some(filter) = request.accounts.get_mut(ACCOUNTS_FILTER_KEY) {
+                filter.account.retain(|a| !accounts.contains(a));
+            }
+            prune_accounts_filter(request);
+        })
+    }
+
+    /// 添加账户 owner（程序 ID）
+    pub fn add_owners(&self, owners: &[String]) -> bool {
+        self.update(|request| merge(&mut accounts_filter(request).owner, owners))
+    }
+
+    /// 移除账户 owner
+    pub fn remove_owners(&self, owners: &[String]) -> bool {
+        self.update(|request| {
+            if let Some(filter) = request.accounts.get_mut(ACCOUNTS_FILTER_KEY) {
+                filter.owner.retain(|o| !owners.contains(o));
+            }
+            prune_accounts_filter(request);
+        })
+    }
+
+    /// 添加交易必须包含的账户
+    pub fn add_account_required(&self, accounts: &[String]) -> bool {
+        self.update(|request| {
+            if let Some(filter) = transactions_filter(request) {
+                merge(&mut filter.account_required, accounts);
+            }
+        })
+    }
+
+    /// 移除交易必须包含的账户
+    pub fn remove_account_required(&self, accounts: &[String]) -> bool {
+        self.update(|request| {
+            if let Some(filter) = transactions_filter(request) {
+                filter.account_required.retain(|a| !accounts.contains(a));
+            }
+        })
+    }
+}
+
+/// 取得（必要时创建）账户过滤器
+fn accounts_filter(request: &mut SubscribeRequest) -> &mut SubscribeRequestFilterAccounts {
+    request
+        .accounts
+        .entry(ACCOUNTS_FILTER_KEY.to_owned())
+        .or_insert_with(|| SubscribeRequestFilterAccounts {
+            account: vec![],
+            owner: vec![],
+            filters: vec![],
+            nonempty_txn_signature: None,
+        })
+}
+
+/// 取得交易过滤器；未订阅交易时返回 None（不因修改而意外订阅全部交易）
+fn transactions_filter(
+    request: &mut SubscribeRequest,
+) -> Option<&mut SubscribeRequestFilterTransactions> {
+    request.transactions.get_mut(TRANSACTIONS_FILTER_KEY)
+}
+
+/// 账户与 owner 都为空的过滤器会匹配全部账户，必须移除
+fn prune_accounts_filter(request: &mut SubscribeRequest) {
+    if request
+        .accounts
+        .get(ACCOUNTS_FILTER_KEY)
+        .is_some_and(|f| f.account.is_empty() && f.owner.is_empty())
+    {
+        request.accounts.remove(ACCOUNTS_FILTER_KEY);
+    }
+}
+
+fn merge(target: &mut Vec<String>, values: &[String]) {
+    for value in values {
+        if !target.contains(value) {
+            target.push(value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn baseline() -> SubscribeRequest {
+        let mut request = SubscribeRequest::default();
+        request.transactions.insert(
+            TRANSACTIONS_FILTER_KEY.to_owned(),
+            SubscribeRequestFilterTransactions {
+                account_include: vec!["program".to_owned()],
+                ..Default::default()
+            },
+        );
+        request.accounts.insert(
+            ACCOUNTS_FILTER_KEY.to_owned(),
+            SubscribeRequestFilterAccounts {
+                owner: vec!["program".to_owned()],
+                ..Default::default()
+            },
+        );
+        request
+    }
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn narrow_then_empty_restores_baseline() {
+        let controller = SubscriptionController::new(baseline());
+
+        assert!(controller.narrow(strings(&["mint", "pool"])));
+        let current = controller.current();
+        assert_eq!(
+            current.transactions[TRANSACTIONS_FILTER_KEY].account_include,
+            strings(&["mint", "pool"])
+        );
+        assert_eq!(
+            current.accounts[ACCOUNTS_FILTER_KEY].account,
+            strings(&["mint", "pool"])
+        );
+        assert!(current.accounts[ACCOUNTS_FILTER_KEY].owner.is_empty());
+
+        // 相同的收窄不再下发
+        assert!(!controller.narrow(strings(&["mint", "pool"])));
+
+        assert!(controller.narrow(Vec::new()));
+        assert_eq!(controller.current(), baseline());
+    }
+
+    #[test]
+    fn narrow_does_not_add_missing_filters() {
+        let controller = SubscriptionController::new(SubscribeRequest::default());
+
+        assert!(!controller.narrow(strings(&["mint"])));
+        let current = controller.current();
+        assert!(current.transactions.is_empty());
+        assert!(current.accounts.is_empty());
+    }
+
+    #[test]
+    fn remove_prunes_empty_accounts_filter() {
+        let controller = SubscriptionController::new(SubscribeRequest::default());
+
+        assert!(controller.add_accounts(&strings(&["a", "b"])));
+        assert!(!controller.add_accounts(&strings(&["a"])));
+        assert_eq!(
+            controller.current().accounts[ACCOUNTS_FILTER_KEY].account,
+            strings(&["a", "b"])
+        );
+
+        assert!(controller.remove_accounts(&strings(&["a"])));
+        assert_eq!(
+            controller.current().accounts[ACCOUNTS_FILTER_KEY].account,
+            strings(&["b"])
+        );
+
+        // 账户与 owner 都为空时过滤器会匹配全部账户，必须被移除
+        assert!(controller.remove_accounts(&strings(&["b"])));
+        assert!(
+            !controller
+                .current()
+                .accounts
+                .contains_key(ACCOUNTS_FILTER_KEY)
+        );
+    }
+
+    #[test]
+    fn updates_clear_from_slot() {
+        let mut request = baseline();
+        request.from_slot = Some(42);
+        let controller = SubscriptionController::new(request);
+        let mut receiver = controller.receiver();
+
+        assert!(controller.add_account_required(&strings(&["signer"])));
+        assert!(receiver.has_changed().unwrap());
+        let current = receiver.borrow_and_update().clone();
+        assert_eq!(current.from_slot, None);
+        assert_eq!(
+            current.transactions[TRANSACTIONS_FILTER_KEY].account_required,
+            strings(&["signer"])
+        );
+
+        assert!(controller.remove_account_required(&strings(&["signer"])));
+        assert!(
+            controller.current().transactions[TRANSACTIONS_FILTER_KEY]
+                .account_required
+                .is_empty()
+        );
+    }
+}
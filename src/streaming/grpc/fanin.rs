@@ -0,0 +1,216 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Instant;
+
+use yellowstone_grpc_proto::geyser::SubscribeUpdate;
+
+use super::supervisor::DedupKey;
+
+/// 单个端点的汇聚统计
+#[derive(Debug, Clone, Default)]
+pub struct EndpointStats {
+    pub endpoint: String,
+    /// 收到的可去重更新总数
+    pub received: u64,
+    /// 首个送达（胜出）的更新数
+    pub wins: u64,
+    /// 落后于其他端点的重复更新数
+    pub duplicates: u64,
+    /// 作为落后方时相对胜出方的累计/最大延迟
+    pub total_lag_ms: f64,
+    pub max_lag_ms: f64,
+    /// 基于 SubscribeUpdate.created_at 的累计送达延迟
+    pub total_latency_ms: f64,
+    pub latency_samples: u64,
+}
+
+impl EndpointStats {
+    pub fn new(endpoint: String) -> Self {
+        Self { endpoint, ..Default::default() }
+    }
+
+    /// 胜出率（0.0 - 1.0）
+    pub fn win_rate(&self) -> f64 {
+        if self.received == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.received as f64
+        }
+    }
+
+    /// 落后时的平均延迟
+    pub fn average_lag_ms(&self) -> f64 {
+        if self.duplicates == 0 {
+            0.0
+        } else {
+            self.total_lag_ms / self.duplicates as f64
+        }
+    }
+
+    /// 提供商时间戳到本地接收的平均延迟（受时钟偏差影响，仅供相对比较）
+    pub fn average_latency_ms(&self) -> f64 {
+        if self.latency_samples == 0 {
+            0.0
+        } else {
+            self.total_latency_ms / self.latency_samples as f64
+        }
+    }
+}
+
+struct FanInState {
+    /// 键 -> 首次到达时间
+    seen: HashMap<DedupKey, Instant>,
+    order: VecDeque<DedupKey>,
+    capacity: usize,
+    stats: Vec<EndpointStats>,
+}
+
+/// 多端点首达去重器：同一交易签名或 (pubkey, slot) 账户更新只放行最先到达的一份
+pub struct FanInDeduper {
+    state: Mutex<FanInState>,
+}
+
+impl FanInDeduper {
+    pub fn new(endpoints: Vec<String>, capacity: usize) -> Self {
+        Self {
+            state: Mutex::new(FanInState {
+                seen: HashMap::with_capacity(capacity),
+                order: VecDeque::with_capacity(capacity),
+                capacity: capacity.max(1),
+                stats: endpoints.into_iter().map(EndpointStats::new).collect(),
+            }),
+        }
+    }
+
+    /// 首次到达返回 true，并记录该端点的胜出/落后统计
+    pub fn accept(&self, endpoint_index: usize, msg: &SubscribeUpdate) -> bool {
+        let Some(key) = DedupKey::from_update(msg) else {
+            return true;
+        };
+        let now = Instant::now();
+        let latency_ms = msg.created_at.as_ref().map(|ts| {
+            let created_ms = ts.seconds * 1000 + ts.nanos as i64 / 1_000_000;
+            (chrono::Utc::now().timestamp_millis() - created_ms) as f64
+        });
+
+        let mut state = match self.state.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        let first_seen = state.seen.get(&key).copied();
+        let accepted = match first_seen {
+            Some(first) => {
+                let lag_ms = now.duration_since(first).as_secs_f64() * 1000.0;
+                if let Some(stats) = state.stats.get_mut(endpoint_index) {
+                    stats.duplicates += 1;
+                    stats.total_lag_ms += lag_ms;
+                    if lag_ms > stats.max_lag_ms {
+                        stats.max_lag_ms = lag_ms;
+                    }
+                }
+                false
+            }
+            None => {
+                state.seen.insert(key.clone(), now);
+                state.order.push_back(key);
+                if state.order.len() > state.capacity
+                    && let Some(oldest) = state.order.pop_front()
+                {
+                    state.seen.remove(&oldest);
+                }
+                if let Some(stats) = state.stats.get_mut(endpoint_index) {
+                    stats.wins += 1;
+                }
+                true
+            }
+        };
+
+        if let Some(stats) = state.stats.get_mut(endpoint_index) {
+            stats.received += 1;
+            if let Some(latency_ms) = latency_ms {
+                stats.total_latency_ms += latency_ms;
+                stats.latency_samples += 1;
+            }
+        }
+        accepted
+    }
+
+    /// 各端点统计快照
+    pub fn stats(&self) -> Vec<EndpointStats> {
+        match self.state.lock() {
+            Ok(guard) => guard.stats.clone(),
+            Err(poisoned) => poisoned.into_inner().stats.clone(),
+        }
+    }
+
+    /// 打印各端点胜出率与延迟
+    pub fn print_stats(&self) {
+        println!("\nEndpoint Fan-in Statistics");
+        println!("┌──────────────────────────────────┬──────────┬──────────┬───────────┬──────────────┐");
+        println!("│ Endpoint                         │ Received │ Win Rate │ Avg Lag ms│ Avg Latency  │");
+        println!("├──────────────────────────────────┼──────────┼──────────┼───────────┼──────────────┤");
+        for stats in self.stats() {
+            let name: String = stats.endpoint.chars().take(32).collect();
+            println!(
+                "│ {:32} │ {:8} │ {:7.2}% │ {:9.2} │ {:10.2}ms │",
+                name,
+                stats.received,
+                stats.win_rate() * 100.0,
+                stats.average_lag_ms(),
+                stats.average_latency_ms()
+            );
+        }
+        println!("└──────────────────────────────────┴──────────┴──────────┴───────────┴──────────────┘");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yellowstone_grpc_proto::geyser::{
+        subscribe_update::UpdateOneof, SubscribeUpdateTransaction, SubscribeUpdateTransactionInfo,
+    };
+
+    fn transaction_update(signature: &[u8]) -> SubscribeUpdate {
+        SubscribeUpdate {
+            update_oneof: Some(UpdateOneof::Transaction(SubscribeUpdateTransaction {
+                transaction: Some(SubscribeUpdateTransactionInfo {
+                    signature: signature.to_vec(),
+                    ..Default::default()
+                }),
+                slot: 1,
+            })),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn same_signature_from_two_endpoints_is_emitted_once() {
+        let fanin = FanInDeduper::new(vec!["a".to_string(), "b".to_string()], 16);
+        assert!(fanin.accept(0, &transaction_update(b"sig-1")));
+        assert!(!fanin.accept(1, &transaction_update(b"sig-1")));
+        assert!(fanin.accept(1, &transaction_update(b"sig-2")));
+        assert!(!fanin.accept(0, &transaction_update(b"sig-2")));
+        assert!(fanin.accept(0, &transaction_update(b"sig-3")));
+
+        let stats = fanin.stats();
+        assert_eq!((stats[0].received, stats[0].wins, stats[0].duplicates), (3, 2, 1));
+        assert_eq!((stats[1].received, stats[1].wins, stats[1].duplicates), (2, 1, 1));
+        assert!((stats[0].win_rate() - 2.0 / 3.0).abs() < f64::EPSILON);
+        assert!((stats[1].win_rate() - 0.5).abs() < f64::EPSILON);
+        assert!(stats[1].max_lag_ms >= 0.0);
+        assert!(stats[1].average_lag_ms() <= stats[1].max_lag_ms);
+    }
+
+    #[test]
+    fn evicted_keys_are_accepted_again() {
+        let fanin = FanInDeduper::new(vec!["a".to_string(), "b".to_string()], 1);
+        assert!(fanin.accept(0, &transaction_update(b"sig-1")));
+        assert!(fanin.accept(0, &transaction_update(b"sig-2")));
+        assert!(fanin.accept(1, &transaction_update(b"sig-1")));
+        // 未知端点序号不影响去重
+        assert!(!fanin.accept(7, &transaction_update(b"sig-1")));
+        assert_eq!(fanin.stats()[1].wins, 1);
+    }
+}
@@ -4,6 +4,12 @@ pub mod types;
 pub mod subscription;
 pub mod streamhandler;
 pub mod processor;
+pub mod supervisor;
+pub mod fanin;
+pub mod control;
+pub mod commitment;
+pub mod capture;
+pub mod priority;
 
 // 重新导出主要类型
 pub use connection::*;
@@ -11,6 +17,12 @@ pub use types::*;
 pub use subscription::*;
 pub use streamhandler::*;
 pub use processor::*;
+pub use supervisor::*;
+pub use fanin::*;
+pub use control::*;
+pub use commitment::*;
+pub use capture::*;
+pub use priority::*;
 
 // 从公用模块重新导出
 pub use crate::streaming::common::{
@@ -22,4 +34,8 @@ pub use crate::streaming::common::{
     BatchConfig,
     BackpressureConfig,
     ConnectionConfig,
+    ReconnectConfig,
+    CommitmentTrackingConfig,
+    CaptureConfig,
+    PrometheusConfig,
 };
\ No newline at end of file
@@ -0,0 +1,469 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::mpsc;
+
+use super::types::{EventPretty, TransactionPayload, TransactionPretty};
+use crate::globals::constants::*;
+use crate::streaming::common::{MetricsEventType, MetricsManager};
+use crate::streaming::events::common::EventType;
+use crate::streaming::events::{EventParserFactory, Protocol};
+
+/// 优先级类别（数值越小越先被丢弃）；Critical 永不丢弃
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PriorityClass {
+    Account,
+    BlockMeta,
+    Transaction,
+    Slot,
+    Critical,
+}
+
+impl PriorityClass {
+    /// 可丢弃类别（按丢弃顺序）
+    const EVICTABLE: [PriorityClass; 4] = [Self::Account, Self::BlockMeta, Self::Transaction, Self::Slot];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// 丢弃计数归入的指标类别
+    fn metrics_event_type(self) -> MetricsEventType {
+        match self {
+            Self::Account => MetricsEventType::Account,
+            Self::BlockMeta => MetricsEventType::BlockMeta,
+            Self::Transaction | Self::Critical => MetricsEventType::Tx,
+            Self::Slot => MetricsEventType::Slot,
+        }
+    }
+}
+
+/// 关键交易识别：解析前无法得知事件类型，按配置的关键 EventType 反查各协议的
+/// (program_id, 指令 discriminator)，匹配交易的外层与内联指令
+pub struct CriticalMatcher {
+    discriminators: HashMap<Pubkey, Vec<&'static [u8]>>,
+}
+
+impl CriticalMatcher {
+    pub fn new(protocols: &[Protocol], critical_event_types: &[EventType]) -> Self {
+        let mut discriminators: HashMap<Pubkey, Vec<&'static [u8]>> = HashMap::new();
+        for protocol in protocols {
+            let parser = EventParserFactory::create_parser(protocol.clone());
+            for configs in parser.instruction_configs().into_values() {
+                for config in configs {
+                    if config.instruction_discriminator.is_empty()
+                        || !critical_event_types.contains(&config.event_type)
+                    {
+                        continue;
+                    }
+                    let entry = discriminators.entry(config.program_id).or_default();
+                    if !entry.contains(&config.instruction_discriminator) {
+                        entry.push(config.instruction_discriminator);
+                    }
+                }
+            }
+        }
+        Self { discriminators }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.discriminators.is_empty()
+    }
+
+    /// 交易是否包含关键指令（UI 编码交易只检查静态账户表中的程序）
+    pub fn is_critical(&self, transaction_pretty: &TransactionPretty) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        match &transaction_pretty.tx {
+            TransactionPayload::Proto(info) => {
+                let Some(message) = info.transaction.as_ref().and_then(|tx| tx.message.as_ref()) else {
+                    return false;
+                };
+                let mut accounts: Vec<&[u8]> =
+                    message.account_keys.iter().map(|key| key.as_slice()).collect();
+                if let Some(meta) = info.meta.as_ref() {
+                    accounts.extend(meta.loaded_writable_addresses.iter().map(|key| key.as_slice()));
+                    accounts.extend(meta.loaded_readonly_addresses.iter().map(|key| key.as_slice()));
+                }
+
+                let outer = message
+                    .instructions
+                    .iter()
+                    .map(|ix| (ix.program_id_index as usize, ix.data.as_slice()));
+                let inner = info.meta.iter().flat_map(|meta| {
+                    meta.inner_instructions.iter().flat_map(|inner| {
+                        inner
+                            .instructions
+                            .iter()
+                            .map(|ix| (ix.program_id_index as usize, ix.data.as_slice()))
+                    })
+                });
+                outer.chain(inner).any(|(program_index, data)| {
+                    accounts
+                        .get(program_index)
+                        .and_then(|key| Pubkey::try_from(*key).ok())
+                        .is_some_and(|program_id| self.matches(&program_id, data))
+                })
+            }
+            TransactionPayload::Encoded(tx) => {
+                let Some(versioned) = tx.transaction.decode() else {
+                    return false;
+                };
+                let keys = versioned.message.static_account_keys();
+                versioned.message.instructions().iter().any(|ix| {
+                    keys.get(ix.program_id_index as usize)
+                        .is_some_and(|program_id| self.matches(program_id, &ix.data))
+                })
+            }
+        }
+    }
+
+    fn matches(&self, program_id: &Pubkey, data: &[u8]) -> bool {
+        self.discriminators
+            .get(program_id)
+            .is_some_and(|discriminators| discriminators.iter().any(|disc| data.starts_with(disc)))
+    }
+}
+
+/// 入队结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushOutcome {
+    Queued,
+    /// 替换了同一 pubkey 尚未处理的账户更新
+    Coalesced,
+    /// 新消息被丢弃（其类别不高于队列中最低的可丢弃类别）
+    Dropped(PriorityClass),
+    /// 为新消息腾出空间，丢弃了队列中较低类别最旧的一条
+    Evicted(PriorityClass),
+}
+
+/// 优先级缓冲区：按到达顺序出队；满时先丢低优先级类别最旧的消息，
+/// 同一账户的多次更新只保留最新一条（位置沿用最早那条）
+pub struct PriorityBuffer {
+    entries: BTreeMap<u64, (PriorityClass, EventPretty)>,
+    by_class: [BTreeSet<u64>; 4],
+    accounts: HashMap<String, u64>,
+    next_seq: u64,
+    capacity: usize,
+}
+
+impl PriorityBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            by_class: Default::default(),
+            accounts: HashMap::new(),
+            next_seq: 0,
+            capacity: capacity.max(1),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn push(&mut self, class: PriorityClass, event_pretty: EventPretty) -> PushOutcome {
+        let account_key = match &event_pretty {
+            EventPretty::Account(account) => Some(account.pubkey.clone()),
+            _ => None,
+        };
+        if let Some(seq) = account_key.as_ref().and_then(|key| self.accounts.get(key))
+            && let Some(entry) = self.entries.get_mut(seq)
+        {
+            entry.1 = event_pretty;
+            return PushOutcome::Coalesced;
+        }
+
+        let mut outcome = PushOutcome::Queued;
+        if self.entries.len() >= self.capacity && class != PriorityClass::Critical {
+            let lowest = PriorityClass::EVICTABLE
+                .into_iter()
+                .find(|queued| !self.by_class[queued.index()].is_empty());
+            match lowest {
+                Some(lowest) if lowest < class => {
+                    self.evict_oldest(lowest);
+                    outcome = PushOutcome::Evicted(lowest);
+                }
+                _ => return PushOutcome::Dropped(class),
+            }
+        }
+
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        if class != PriorityClass::Critical {
+            self.by_class[class.index()].insert(seq);
+        }
+        if let Some(key) = account_key {
+            self.accounts.insert(key, seq);
+        }
+        self.entries.insert(seq, (class, event_pretty));
+        outcome
+    }
+
+    /// 取出最早到达的消息
+    pub fn pop(&mut self) -> Option<EventPretty> {
+        let (seq, (class, event_pretty)) = self.entries.pop_first()?;
+        self.forget(seq, class, &event_pretty);
+        Some(event_pretty)
+    }
+
+    fn evict_oldest(&mut self, class: PriorityClass) {
+        let Some(seq) = self.by_class[class.index()].first().copied() else {
+            return;
+        };
+        if let Some((class, event_pretty)) = self.entries.remove(&seq) {
+            self.forget(seq, class, &event_pretty);
+        }
+    }
+
+    fn forget(&mut self, seq: u64, class: PriorityClass, event_pretty: &EventPretty) {
+        if class != PriorityClass::Critical {
+            self.by_class[class.index()].remove(&seq);
+        }
+        if let EventPretty::Account(account) = event_pretty
+            && self.accounts.get(&account.pubkey) == Some(&seq)
+        {
+            self.accounts.remove(&account.pubkey);
+        }
+    }
+}
+
+/// BackpressureStrategy::Priority 的实现：位于读取器与处理器通道之间。
+/// 读取器以阻塞方式写入入口通道，闸门持续将消息收入 PriorityBuffer，
+/// 下游有容量时按到达顺序转发；下游跟不上时在缓冲区内合并与按优先级丢弃
+pub struct PriorityGate {
+    capacity: usize,
+    matcher: CriticalMatcher,
+    metrics_manager: MetricsManager,
+}
+
+impl PriorityGate {
+    pub fn new(capacity: usize, matcher: CriticalMatcher, metrics_manager: MetricsManager) -> Self {
+        Self { capacity, matcher, metrics_manager }
+    }
+
+    /// 启动闸门任务，返回供读取器写入的入口；入口全部释放且缓冲区清空后任务结束
+    pub fn spawn(self, downstream: mpsc::Sender<EventPretty>) -> mpsc::Sender<EventPretty> {
+        let (ingress_tx, mut ingress_rx) = mpsc::channel::<EventPretty>(DEFPRIORITYINGRESSSIZE);
+
+        tokio::spawn(async move {
+            let mut buffer = PriorityBuffer::new(self.capacity);
+            let mut ingress_open = true;
+
+            loop {
+                if buffer.is_empty() {
+                    if !ingress_open {
+                        break;
+                    }
+                    match ingress_rx.recv().await {
+                        Some(event_pretty) => self.push(&mut buffer, event_pretty),
+                        None => break,
+                    }
+                    continue;
+                }
+
+                tokio::select! {
+                    event_pretty = ingress_rx.recv(), if ingress_open => match event_pretty {
+                        Some(event_pretty) => self.push(&mut buffer, event_pretty),
+                        None => ingress_open = false,
+                    },
+                    permit = downstream.reserve() => match permit {
+                        Ok(permit) => {
+                            if let Some(event_pretty) = buffer.pop() {
+                                permit.send(event_pretty);
+                            }
+                        }
+                        // 处理器已停止
+                        Err(_) => break,
+                    },
+                }
+            }
+        });
+
+        ingress_tx
+    }
+
+    fn classify(&self, event_pretty: &EventPretty) -> PriorityClass {
+        match event_pretty {
+            EventPretty::Account(_) => PriorityClass::Account,
+            EventPretty::BlockMeta(_) => PriorityClass::BlockMeta,
+            // 完整区块由 subscribe_blocks 以阻塞背压读取，不经过闸门；其他路径按普通交易处理
+            EventPretty::Block(_) => PriorityClass::Transaction,
+            EventPretty::Slot(_) => PriorityClass::Slot,
+            EventPretty::Transaction(transaction_pretty) => {
+                if self.matcher.is_critical(transaction_pretty) {
+                    PriorityClass::Critical
+                } else {
+                    PriorityClass::Transaction
+                }
+            }
+        }
+    }
+
+    fn push(&self, buffer: &mut PriorityBuffer, event_pretty: EventPretty) {
+        let class = self.classify(&event_pretty);
+        match buffer.push(class, event_pretty) {
+            PushOutcome::Queued => {}
+            PushOutcome::Coalesced => self.metrics_manager.add_coalesced_count(),
+            PushOutcome::Dropped(dropped) | PushOutcome::Evicted(dropped) => {
+                log::debug!("Priority backpressure dropped a {dropped:?} update");
+                self.metrics_manager.record_priority_drop(dropped.metrics_event_type());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::common::{PerformanceMetrics, StreamClientConfig};
+    use crate::streaming::events::protocols::block::slot::SlotStatus;
+    use crate::streaming::grpc::types::{AccountPretty, SlotPretty};
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    /// 用 slot 号标记消息，便于检查出队顺序；类别由 push 的参数决定
+    fn marker(slot: u64) -> EventPretty {
+        EventPretty::Slot(SlotPretty {
+            slot,
+            parent: None,
+            status: SlotStatus::Processed,
+            dead_error: None,
+            created_at: None,
+            timings: Default::default(),
+        })
+    }
+
+    fn account(pubkey: &str, slot: u64) -> EventPretty {
+        EventPretty::Account(AccountPretty {
+            slot,
+            signature: String::new(),
+            pubkey: pubkey.to_string(),
+            executable: false,
+            lamports: 0,
+            owner: String::new(),
+            rent_epoch: 0,
+            data: Vec::new(),
+            timings: Default::default(),
+            filters: Vec::new(),
+        })
+    }
+
+    fn slot_of(event_pretty: &EventPretty) -> u64 {
+        match event_pretty {
+            EventPretty::Slot(slot) => slot.slot,
+            EventPretty::Account(account) => account.slot,
+            _ => unreachable!(),
+        }
+    }
+
+    fn drain(buffer: &mut PriorityBuffer) -> Vec<u64> {
+        std::iter::from_fn(|| buffer.pop()).map(|event_pretty| slot_of(&event_pretty)).collect()
+    }
+
+    #[test]
+    fn full_buffer_evicts_lowest_class_oldest_first() {
+        let mut buffer = PriorityBuffer::new(3);
+        assert_eq!(buffer.push(PriorityClass::Transaction, marker(1)), PushOutcome::Queued);
+        assert_eq!(buffer.push(PriorityClass::BlockMeta, marker(2)), PushOutcome::Queued);
+        assert_eq!(buffer.push(PriorityClass::BlockMeta, marker(3)), PushOutcome::Queued);
+
+        assert_eq!(
+            buffer.push(PriorityClass::Slot, marker(4)),
+            PushOutcome::Evicted(PriorityClass::BlockMeta)
+        );
+        assert_eq!(
+            buffer.push(PriorityClass::Slot, marker(5)),
+            PushOutcome::Evicted(PriorityClass::BlockMeta)
+        );
+        assert_eq!(
+            buffer.push(PriorityClass::Slot, marker(6)),
+            PushOutcome::Evicted(PriorityClass::Transaction)
+        );
+        // 队列中没有更低的类别时新消息被丢弃
+        assert_eq!(
+            buffer.push(PriorityClass::Transaction, marker(7)),
+            PushOutcome::Dropped(PriorityClass::Transaction)
+        );
+        assert_eq!(
+            buffer.push(PriorityClass::Slot, marker(8)),
+            PushOutcome::Dropped(PriorityClass::Slot)
+        );
+
+        assert_eq!(drain(&mut buffer), [4, 5, 6]);
+    }
+
+    #[test]
+    fn critical_is_never_dropped() {
+        let mut buffer = PriorityBuffer::new(1);
+        assert_eq!(buffer.push(PriorityClass::Critical, marker(1)), PushOutcome::Queued);
+        assert_eq!(buffer.push(PriorityClass::Critical, marker(2)), PushOutcome::Queued);
+        // 关键消息不能被淘汰
+        assert_eq!(
+            buffer.push(PriorityClass::Slot, marker(3)),
+            PushOutcome::Dropped(PriorityClass::Slot)
+        );
+
+        assert_eq!(drain(&mut buffer), [1, 2]);
+    }
+
+    #[test]
+    fn account_updates_coalesce_in_place() {
+        let mut buffer = PriorityBuffer::new(8);
+        buffer.push(PriorityClass::Account, account("pool", 1));
+        buffer.push(PriorityClass::Transaction, marker(2));
+        assert_eq!(buffer.push(PriorityClass::Account, account("pool", 3)), PushOutcome::Coalesced);
+        assert_eq!(buffer.len(), 2);
+
+        // 保留最新内容，位置沿用最早那条
+        assert_eq!(drain(&mut buffer), [3, 2]);
+
+        // 出队后同一账户重新入队
+        assert_eq!(buffer.push(PriorityClass::Account, account("pool", 4)), PushOutcome::Queued);
+    }
+
+    #[test]
+    fn evicted_account_is_not_coalesced() {
+        let mut buffer = PriorityBuffer::new(1);
+        buffer.push(PriorityClass::Account, account("pool", 1));
+        assert_eq!(
+            buffer.push(PriorityClass::Transaction, marker(2)),
+            PushOutcome::Evicted(PriorityClass::Account)
+        );
+
+        // 被淘汰的账户不再作为合并目标
+        assert_eq!(
+            buffer.push(PriorityClass::Account, account("pool", 3)),
+            PushOutcome::Dropped(PriorityClass::Account)
+        );
+        assert_eq!(drain(&mut buffer), [2]);
+    }
+
+    #[tokio::test]
+    async fn gate_forwards_in_arrival_order_and_stops_with_ingress() {
+        let metrics_manager = MetricsManager::new(
+            Arc::new(Mutex::new(PerformanceMetrics::new())),
+            Arc::new(StreamClientConfig::default()),
+            "test".to_string(),
+        );
+        let gate = PriorityGate::new(8, CriticalMatcher::new(&[], &[]), metrics_manager);
+        let (downstream_tx, mut downstream_rx) = mpsc::channel(8);
+        let ingress = gate.spawn(downstream_tx);
+
+        for slot in 1..=3 {
+            ingress.send(marker(slot)).await.unwrap();
+        }
+        drop(ingress);
+
+        let mut forwarded = Vec::new();
+        while let Some(event_pretty) = downstream_rx.recv().await {
+            forwarded.push(slot_of(&event_pretty));
+        }
+        assert_eq!(forwarded, [1, 2, 3]);
+    }
+}
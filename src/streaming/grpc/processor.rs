@@ -3,15 +3,19 @@ use std::sync::Arc;
 use once_cell::sync::OnceCell;
 use solana_sdk::pubkey::Pubkey;
 
-use super::types::EventPretty;
+use super::commitment::CommitmentTracker;
+use super::types::{AccountPretty, BlockPretty, EventPretty, TransactionPayload, TransactionPretty};
 use crate::common::AnyResult;
 use crate::streaming::common::{
     EventBatchProcessor as EventBatchCollector, MetricsEventType, MetricsManager,
     StreamClientConfig as ClientConfig,
 };
 use crate::streaming::events::common::filter::EventTypeFilter;
+use crate::streaming::events::common::types::{EventTimings, EventType};
 use crate::streaming::events::core::eventparser::{AccountEventParser, CommonEventParser};
 use crate::streaming::events::EventParser;
+use crate::streaming::events::protocols::block::slot::SlotStatus;
+use crate::streaming::events::protocols::dynamic::DynamicIdlParser;
 use crate::streaming::events::{
     core::traits::UnifiedEvent, protocols::mutil::parser::MutilEventParser, Protocol,
 };
@@ -22,18 +26,86 @@ pub struct EventProcessor {
     pub(crate) config: ClientConfig,
     /// 解析器缓存（首次使用时按传入 protocols/event_type_filter 构建一次）
     parser_cache: OnceCell<Arc<dyn EventParser>>,
+    /// 提交级别跟踪（config.commitment_tracking.enabled 时启用）
+    commitment_tracker: Option<Arc<CommitmentTracker>>,
+    /// 运行时 IDL 解析器，与 protocols 对应的内置解析器一并使用
+    idl_parsers: Vec<Arc<DynamicIdlParser>>,
 }
 
 impl EventProcessor {
     /// 创建新的事件处理器
     pub fn new(metrics_manager: MetricsManager, config: ClientConfig) -> Self {
+        let commitment_tracker = config
+            .commitment_tracking
+            .enabled
+            .then(|| Arc::new(CommitmentTracker::new(config.commitment_tracking.clone())));
         Self {
             metrics_manager,
             config,
             parser_cache: OnceCell::new(),
+            commitment_tracker,
+            idl_parsers: Vec::new(),
         }
     }
 
+    /// 设置运行时 IDL 解析器（须在首次解析前设置，解析器构建后即被缓存）
+    pub fn with_idl_parsers(mut self, idl_parsers: Vec<Arc<DynamicIdlParser>>) -> Self {
+        self.idl_parsers = idl_parsers;
+        self
+    }
+
+    pub fn idl_parsers(&self) -> &[Arc<DynamicIdlParser>] {
+        &self.idl_parsers
+    }
+
+    /// 提交级别跟踪器（未启用时为 None）
+    pub fn commitment_tracker(&self) -> Option<&Arc<CommitmentTracker>> {
+        self.commitment_tracker.as_ref()
+    }
+
+    /// 即将发出的解析事件：计入按 EventType 的解析计数、写入阶段时间戳与命中的过滤器名称，
+    /// 并等待其所属 slot 的后续状态；slot 状态已知时返回应紧随该事件发出的确认 / 回滚通知
+    #[inline]
+    fn observe_event(
+        &self,
+        event: &mut dyn UnifiedEvent,
+        timings: EventTimings,
+        filters: &[String],
+        dispatch: bool,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        self.metrics_manager.record_parsed_event(event.event_type());
+        if !filters.is_empty() {
+            event.set_filters(filters.to_vec());
+        }
+        self.stamp_event(event, timings, dispatch);
+        self.commitment_tracker.as_ref().and_then(|tracker| tracker.track(event))
+    }
+
+    /// 写入阶段时间戳；立即回调的事件在此记录回调开始并计入延迟直方图，
+    /// 进入批处理的事件由 EventBatchProcessor 在批次回调时补记
+    #[inline]
+    fn stamp_event(&self, event: &mut dyn UnifiedEvent, mut timings: EventTimings, dispatch: bool) {
+        if dispatch {
+            timings.callback_us = EventTimings::now_us();
+            self.metrics_manager.record_latency(&timings);
+        }
+        event.set_timings(timings);
+    }
+
+    /// 根据 slot 状态生成确认 / 回滚通知（启用跟踪即发出，不受事件类型过滤器影响）
+    fn commitment_notifications(&self, slot: u64, status: SlotStatus) -> Vec<Box<dyn UnifiedEvent>> {
+        match &self.commitment_tracker {
+            Some(tracker) => tracker.on_slot(slot, status),
+            None => Vec::new(),
+        }
+    }
+
+    /// 过滤器为空或显式包含 Slot 时才发出 SlotEvent（仅订阅提交通知时不发出）
+    #[inline]
+    fn include_slot_event(event_type_filter: Option<&EventTypeFilter>) -> bool {
+        event_type_filter.is_none_or(|filter| filter.include.contains(&EventType::Slot))
+    }
+
     /// 获取或创建解析器（首次调用时根据传入的 protocols / filter 构建并缓存）
     #[inline]
     fn get_or_create_parser(
@@ -42,10 +114,148 @@ impl EventProcessor {
         event_type_filter: Option<EventTypeFilter>,
     ) -> Arc<dyn EventParser> {
         self.parser_cache
-            .get_or_init(|| Arc::new(MutilEventParser::new(protocols, event_type_filter)))
+            .get_or_init(|| {
+                let parser = self.idl_parsers.iter().fold(
+                    MutilEventParser::new(protocols, event_type_filter),
+                    |parser, idl_parser| parser.with_parser(idl_parser.clone()),
+                );
+                Arc::new(parser)
+            })
             .clone()
     }
 
+    /// 解析账户更新：owner 为已注册 IDL 程序的账户交由对应 DynamicIdlParser，其余走内置账户解析
+    fn parse_account_event(
+        &self,
+        protocols: Vec<Protocol>,
+        account_pretty: AccountPretty,
+        program_received_time_ms: i64,
+        event_type_filter: Option<EventTypeFilter>,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let owner = account_pretty.owner.parse().unwrap_or_default();
+        match self.idl_parsers.iter().find(|parser| parser.should_handle(&owner)) {
+            Some(parser) => {
+                let include = event_type_filter
+                    .as_ref()
+                    .is_none_or(|filter| filter.include.contains(&EventType::IdlAccount));
+                if !include {
+                    return None;
+                }
+                parser
+                    .parse_account_event(&account_pretty, program_received_time_ms)
+                    .map(|event| Box::new(event) as Box<dyn UnifiedEvent>)
+            }
+            None => AccountEventParser::parse_account_event(
+                protocols,
+                account_pretty,
+                program_received_time_ms,
+                event_type_filter,
+            ),
+        }
+    }
+
+    /// 按交易来源选择解析路径：Geyser protobuf 走快速路径，回放 / RPC 的 UI 编码走原路径
+    async fn parse_transaction_pretty(
+        parser: &dyn EventParser,
+        transaction_pretty: &TransactionPretty,
+        signature: &str,
+        slot: Option<u64>,
+        program_received_time_ms: i64,
+        bot_wallet: Option<Pubkey>,
+    ) -> AnyResult<Vec<Box<dyn UnifiedEvent>>> {
+        let block_time = transaction_pretty.block_time.map(|ts| prost_types::Timestamp {
+            seconds: ts.seconds,
+            nanos: ts.nanos,
+        });
+        match &transaction_pretty.tx {
+            TransactionPayload::Proto(info) => {
+                parser
+                    .parse_proto_transaction(
+                        info,
+                        signature,
+                        slot,
+                        block_time,
+                        program_received_time_ms,
+                        bot_wallet,
+                    )
+                    .await
+            }
+            TransactionPayload::Encoded(tx) => {
+                parser
+                    .parse_transaction(
+                        tx,
+                        signature,
+                        slot,
+                        block_time,
+                        program_received_time_ms,
+                        bot_wallet,
+                    )
+                    .await
+            }
+        }
+    }
+
+    /// 按区块内顺序解析全部非投票交易，返回的事件保持交易顺序与指令顺序；
+    /// 单笔交易解析失败时记录警告并跳过该交易
+    pub async fn parse_block(
+        &self,
+        block_pretty: &BlockPretty,
+        mut timings: EventTimings,
+        filters: &[String],
+        bot_wallet: Option<Pubkey>,
+        protocols: Vec<Protocol>,
+        event_type_filter: Option<EventTypeFilter>,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        let start_time = std::time::Instant::now();
+        let program_received_time_ms = chrono::Utc::now().timestamp_millis();
+        let slot = block_pretty.slot;
+        let block_time = block_pretty.block_time.map(|ts| prost_types::Timestamp {
+            seconds: ts.seconds,
+            nanos: ts.nanos,
+        });
+
+        // 获取解析器（缓存）
+        let parser = self.get_or_create_parser(protocols, event_type_filter);
+
+        let mut events = Vec::new();
+        // 跳过投票交易与执行失败的交易（与实时交易订阅的默认行为一致）
+        let transactions = block_pretty.transactions.iter().filter(|info| {
+            !info.is_vote && info.meta.as_ref().is_none_or(|meta| meta.err.is_none())
+        });
+        for info in transactions {
+            let signature = bs58::encode(&info.signature).into_string();
+            match parser
+                .parse_proto_transaction(
+                    info,
+                    &signature,
+                    Some(slot),
+                    block_time,
+                    program_received_time_ms,
+                    bot_wallet,
+                )
+                .await
+            {
+                Ok(parsed) => events.extend(parsed),
+                Err(e) => log::warn!("Failed to parse transaction {signature} in block {slot}: {e:?}"),
+            }
+        }
+        timings.parsed_us = EventTimings::now_us();
+
+        let mut notifications = Vec::new();
+        for event in events.iter_mut() {
+            notifications.extend(self.observe_event(event.as_mut(), timings, filters, true));
+        }
+        events.extend(notifications);
+
+        let processing_time_ms = start_time.elapsed().as_millis() as f64;
+        self.metrics_manager
+            .update_metrics(MetricsEventType::Tx, events.len() as u64, processing_time_ms)
+            .await;
+        self.metrics_manager
+            .log_slow_processing(processing_time_ms, events.len());
+        events
+    }
+
     /// 使用性能监控处理事件交易（逐条回调）
     pub async fn process_event_transaction_with_metrics<F>(
         &self,
@@ -58,6 +268,9 @@ impl EventProcessor {
     where
         F: Fn(Box<dyn UnifiedEvent>) + Send + Sync,
     {
+        let mut timings = event_pretty.timings();
+        timings.dequeued_us = EventTimings::now_us();
+        let filters = event_pretty.filters().to_vec();
         match event_pretty {
             EventPretty::Account(account_pretty) => {
                 self.metrics_manager.add_account_process_count().await;
@@ -65,13 +278,18 @@ impl EventProcessor {
                 let start_time = std::time::Instant::now();
                 let program_received_time_ms = chrono::Utc::now().timestamp_millis();
 
-                if let Some(event) = AccountEventParser::parse_account_event(
+                if let Some(mut event) = self.parse_account_event(
                     protocols,
                     account_pretty,
                     program_received_time_ms,
                     event_type_filter,
                 ) {
+                    timings.parsed_us = EventTimings::now_us();
+                    let notification = self.observe_event(event.as_mut(), timings, &filters, true);
                     callback(event);
+                    if let Some(notification) = notification {
+                        callback(notification);
+                    }
 
                     let processing_time_ms = start_time.elapsed().as_millis() as f64;
                     self.metrics_manager
@@ -93,27 +311,29 @@ impl EventProcessor {
                 let parser = self.get_or_create_parser(protocols, event_type_filter);
 
                 // 按引用传递，避免克隆大型交易对象
-                let all_events = parser
-                    .parse_transaction(
-                        &transaction_pretty.tx,
-                        &signature,
-                        Some(slot),
-                        transaction_pretty.block_time.map(|ts| prost_types::Timestamp {
-                            seconds: ts.seconds,
-                            nanos: ts.nanos,
-                        }),
-                        program_received_time_ms,
-                        bot_wallet,
-                    )
-                    .await
-                    .unwrap_or_else(|_| Vec::new());
+                let all_events = Self::parse_transaction_pretty(
+                    parser.as_ref(),
+                    &transaction_pretty,
+                    &signature,
+                    Some(slot),
+                    program_received_time_ms,
+                    bot_wallet,
+                )
+                .await
+                .unwrap_or_else(|_| Vec::new());
+                timings.parsed_us = EventTimings::now_us();
 
                 let event_count = all_events.len();
 
                 // 逐条回调（或在上层改为批处理）
                 if event_count > 0 {
-                    for event in all_events {
+                    for mut event in all_events {
+                        let notification =
+                            self.observe_event(event.as_mut(), timings, &filters, true);
                         callback(event);
+                        if let Some(notification) = notification {
+                            callback(notification);
+                        }
                     }
                 }
 
@@ -124,6 +344,15 @@ impl EventProcessor {
                 self.metrics_manager
                     .log_slow_processing(processing_time_ms, event_count);
             }
+            EventPretty::Block(block_pretty) => {
+                // 完整区块：按区块内顺序逐条回调
+                let events = self
+                    .parse_block(&block_pretty, timings, &filters, bot_wallet, protocols, event_type_filter)
+                    .await;
+                for event in events {
+                    callback(event);
+                }
+            }
             EventPretty::BlockMeta(block_meta_pretty) => {
                 let start_time = std::time::Instant::now();
                 self.metrics_manager.add_block_meta_process_count().await;
@@ -133,11 +362,13 @@ impl EventProcessor {
                     .map(|ts| ts.seconds * 1000 + ts.nanos as i64 / 1_000_000)
                     .unwrap_or_else(|| chrono::Utc::now().timestamp_millis());
 
-                let block_meta_event = CommonEventParser::generate_block_meta_event(
+                let mut block_meta_event = CommonEventParser::generate_block_meta_event(
                     block_meta_pretty.slot,
                     &block_meta_pretty.block_hash,
                     block_time_ms,
                 );
+                timings.parsed_us = EventTimings::now_us();
+                self.stamp_event(block_meta_event.as_mut(), timings, true);
                 callback(block_meta_event);
 
                 let processing_time_ms = start_time.elapsed().as_millis() as f64;
@@ -147,6 +378,40 @@ impl EventProcessor {
                 self.metrics_manager
                     .log_slow_processing(processing_time_ms, 1);
             }
+            EventPretty::Slot(slot_pretty) => {
+                let start_time = std::time::Instant::now();
+                self.metrics_manager.add_slot_process_count().await;
+
+                let created_at_ms = slot_pretty
+                    .created_at
+                    .map(|ts| ts.seconds * 1000 + ts.nanos as i64 / 1_000_000)
+                    .unwrap_or_else(|| chrono::Utc::now().timestamp_millis());
+
+                let notifications =
+                    self.commitment_notifications(slot_pretty.slot, slot_pretty.status);
+                if Self::include_slot_event(event_type_filter.as_ref()) {
+                    let mut slot_event = CommonEventParser::generate_slot_event(
+                        slot_pretty.slot,
+                        slot_pretty.parent,
+                        slot_pretty.status,
+                        slot_pretty.dead_error,
+                        created_at_ms,
+                    );
+                    timings.parsed_us = EventTimings::now_us();
+                    self.stamp_event(slot_event.as_mut(), timings, true);
+                    callback(slot_event);
+                }
+                for notification in notifications {
+                    callback(notification);
+                }
+
+                let processing_time_ms = start_time.elapsed().as_millis() as f64;
+                self.metrics_manager
+                    .update_metrics(MetricsEventType::Slot, 1, processing_time_ms)
+                    .await;
+                self.metrics_manager
+                    .log_slow_processing(processing_time_ms, 1);
+            }
         }
 
         Ok(())
@@ -164,6 +429,9 @@ impl EventProcessor {
     where
         F: Fn(Vec<Box<dyn UnifiedEvent>>) + Send + Sync + 'static,
     {
+        let mut timings = event_pretty.timings();
+        timings.dequeued_us = EventTimings::now_us();
+        let filters = event_pretty.filters().to_vec();
         match event_pretty {
             EventPretty::Account(account_pretty) => {
                 self.metrics_manager.add_account_process_count().await;
@@ -171,13 +439,15 @@ impl EventProcessor {
                 let start_time = std::time::Instant::now();
                 let program_received_time_ms = chrono::Utc::now().timestamp_millis();
 
-                if let Some(event) = AccountEventParser::parse_account_event(
+                if let Some(mut event) = self.parse_account_event(
                     protocols,
                     account_pretty,
                     program_received_time_ms,
                     event_type_filter,
                 ) {
-                    (batch_processor.callback)(vec![event]);
+                    timings.parsed_us = EventTimings::now_us();
+                    let notification = self.observe_event(event.as_mut(), timings, &filters, true);
+                    (batch_processor.callback)(std::iter::once(event).chain(notification).collect());
 
                     let processing_time_ms = start_time.elapsed().as_millis() as f64;
                     self.metrics_manager
@@ -199,19 +469,16 @@ impl EventProcessor {
                 let parser = self.get_or_create_parser(protocols, event_type_filter);
 
                 // 解析（按引用传递）
-                let result = parser
-                    .parse_transaction(
-                        &transaction_pretty.tx,
-                        &signature,
-                        Some(slot),
-                        transaction_pretty.block_time.map(|ts| prost_types::Timestamp {
-                            seconds: ts.seconds,
-                            nanos: ts.nanos,
-                        }),
-                        program_received_time_ms,
-                        bot_wallet,
-                    )
-                    .await;
+                let result = Self::parse_transaction_pretty(
+                    parser.as_ref(),
+                    &transaction_pretty,
+                    &signature,
+                    Some(slot),
+                    program_received_time_ms,
+                    bot_wallet,
+                )
+                .await;
+                timings.parsed_us = EventTimings::now_us();
 
                 // 处理解析结果并进入批处理
                 let total_events = match result {
@@ -220,13 +487,22 @@ impl EventProcessor {
                         if count > 0 {
                             log::debug!("Parsed {} events; enqueueing to batch", count);
                             if self.config.batch.enabled {
-                                for event in events {
+                                for mut event in events {
+                                    let notification =
+                                        self.observe_event(event.as_mut(), timings, &filters, false);
                                     batch_processor.add_event(event);
+                                    if let Some(notification) = notification {
+                                        batch_processor.add_event(notification);
+                                    }
                                 }
                             } else {
                                 // 批处理禁用：直接逐条触发回调（用 Vec 包裹以复用接口）
-                                for event in events {
-                                    (batch_processor.callback)(vec![event]);
+                                for mut event in events {
+                                    let notification =
+                                        self.observe_event(event.as_mut(), timings, &filters, true);
+                                    (batch_processor.callback)(
+                                        std::iter::once(event).chain(notification).collect(),
+                                    );
                                 }
                             }
                         }
@@ -245,6 +521,15 @@ impl EventProcessor {
                 self.metrics_manager
                     .log_slow_processing(processing_time_ms, total_events);
             }
+            EventPretty::Block(block_pretty) => {
+                // 完整区块：整块作为一个批次回调，保持区块内顺序
+                let events = self
+                    .parse_block(&block_pretty, timings, &filters, bot_wallet, protocols, event_type_filter)
+                    .await;
+                if !events.is_empty() {
+                    (batch_processor.callback)(events);
+                }
+            }
             EventPretty::BlockMeta(block_meta_pretty) => {
                 let start_time = std::time::Instant::now();
                 self.metrics_manager.add_block_meta_process_count().await;
@@ -254,11 +539,13 @@ impl EventProcessor {
                     .map(|ts| ts.seconds * 1000 + ts.nanos as i64 / 1_000_000)
                     .unwrap_or_else(|| chrono::Utc::now().timestamp_millis());
 
-                let block_meta_event = CommonEventParser::generate_block_meta_event(
+                let mut block_meta_event = CommonEventParser::generate_block_meta_event(
                     block_meta_pretty.slot,
                     &block_meta_pretty.block_hash,
                     block_time_ms,
                 );
+                timings.parsed_us = EventTimings::now_us();
+                self.stamp_event(block_meta_event.as_mut(), timings, true);
                 (batch_processor.callback)(vec![block_meta_event]);
 
                 let processing_time_ms = start_time.elapsed().as_millis() as f64;
@@ -268,8 +555,123 @@ impl EventProcessor {
                 self.metrics_manager
                     .log_slow_processing(processing_time_ms, 1);
             }
+            EventPretty::Slot(slot_pretty) => {
+                let start_time = std::time::Instant::now();
+                self.metrics_manager.add_slot_process_count().await;
+
+                let created_at_ms = slot_pretty
+                    .created_at
+                    .map(|ts| ts.seconds * 1000 + ts.nanos as i64 / 1_000_000)
+                    .unwrap_or_else(|| chrono::Utc::now().timestamp_millis());
+
+                let notifications =
+                    self.commitment_notifications(slot_pretty.slot, slot_pretty.status);
+                if Self::include_slot_event(event_type_filter.as_ref()) {
+                    let mut slot_event = CommonEventParser::generate_slot_event(
+                        slot_pretty.slot,
+                        slot_pretty.parent,
+                        slot_pretty.status,
+                        slot_pretty.dead_error,
+                        created_at_ms,
+                    );
+                    timings.parsed_us = EventTimings::now_us();
+                    self.stamp_event(slot_event.as_mut(), timings, true);
+                    (batch_processor.callback)(vec![slot_event]);
+                }
+                // 通知走批处理队列，保证排在原事件之后
+                if self.config.batch.enabled {
+                    for notification in notifications {
+                        batch_processor.add_event(notification);
+                    }
+                } else if !notifications.is_empty() {
+                    (batch_processor.callback)(notifications);
+                }
+
+                let processing_time_ms = start_time.elapsed().as_millis() as f64;
+                self.metrics_manager
+                    .update_metrics(MetricsEventType::Slot, 1, processing_time_ms)
+                    .await;
+                self.metrics_manager
+                    .log_slow_processing(processing_time_ms, 1);
+            }
         }
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::common::PerformanceMetrics;
+    use crate::streaming::events::core::traits::tests::pumpfun_sell_fixture;
+    use yellowstone_grpc_proto::prelude::{SubscribeUpdateTransactionInfo, TransactionError};
+
+    fn processor() -> EventProcessor {
+        let config = ClientConfig::default();
+        let metrics_manager = MetricsManager::new(
+            Arc::new(tokio::sync::Mutex::new(PerformanceMetrics::new())),
+            Arc::new(config.clone()),
+            "test".to_string(),
+        );
+        EventProcessor::new(metrics_manager, config)
+    }
+
+    /// 以 index 区分签名的区块内交易
+    fn block_transaction(
+        index: u64,
+        is_vote: bool,
+        failed: bool,
+    ) -> SubscribeUpdateTransactionInfo {
+        let mut info = pumpfun_sell_fixture();
+        let signature = vec![index as u8 + 1; 64];
+        info.signature = signature.clone();
+        if let Some(transaction) = info.transaction.as_mut() {
+            transaction.signatures = vec![signature];
+        }
+        info.index = index;
+        info.is_vote = is_vote;
+        if failed && let Some(meta) = info.meta.as_mut() {
+            meta.err = Some(TransactionError { err: vec![1] });
+        }
+        info
+    }
+
+    #[tokio::test]
+    async fn parse_block_skips_vote_and_failed_transactions() {
+        let block_pretty = BlockPretty {
+            slot: 42,
+            parent_slot: 41,
+            block_hash: String::new(),
+            parent_block_hash: String::new(),
+            block_time: None,
+            block_height: None,
+            transactions: vec![
+                block_transaction(0, false, false),
+                block_transaction(1, true, false),
+                block_transaction(2, false, true),
+                block_transaction(3, false, false),
+            ],
+            timings: EventTimings::default(),
+            filters: Vec::new(),
+        };
+
+        let events = processor()
+            .parse_block(
+                &block_pretty,
+                EventTimings::default(),
+                &[],
+                None,
+                vec![Protocol::PumpFun],
+                None,
+            )
+            .await;
+
+        // 仅保留成功的非投票交易，且保持区块内顺序
+        let signatures: Vec<String> =
+            events.iter().map(|event| event.signature().to_string()).collect();
+        let expected: Vec<String> =
+            [1u8, 4].iter().map(|byte| bs58::encode([*byte; 64]).into_string()).collect();
+        assert_eq!(signatures, expected);
+        assert!(events.iter().all(|event| event.slot() == 42));
+    }
+}
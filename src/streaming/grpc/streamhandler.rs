@@ -8,11 +8,15 @@ use yellowstone_grpc_proto::geyser::{
     subscribe_update::UpdateOneof, SubscribeRequest, SubscribeRequestPing, SubscribeUpdate,
 };
 
-use super::types::{BlockMetaPretty, EventPretty, TransactionPretty};
+use super::types::{BlockMetaPretty, BlockPretty, EventPretty, SlotPretty, TransactionPretty};
 use crate::common::AnyResult;
 use crate::streaming::common::BackpressureStrategy;
+use crate::streaming::events::common::EventTimings;
 use crate::streaming::grpc::AccountPretty;
 
+/// 应答服务端 ping 时使用的 id；看门狗主动 ping 跳过该值以免误配 pong
+pub(crate) const SERVER_PING_REPLY_ID: i32 = 1;
+
 /// 流消息处理器
 pub struct StreamHandler;
 
@@ -25,26 +29,27 @@ impl StreamHandler {
         backpressure_strategy: BackpressureStrategy,
     ) -> AnyResult<()> {
         let created_at = msg.created_at;
-        match msg.update_oneof {
+        // 命中的过滤器名称，随更新一起传给回调用于路由
+        let filters = msg.filters;
+        // 接收时间戳与 Geyser 创建时间（各类更新均保留，用于分阶段延迟统计）
+        let timings = EventTimings::received(
+            created_at.map(|ts| ts.seconds * 1_000_000 + ts.nanos as i64 / 1_000).unwrap_or(0),
+        );
+        let mut event_pretty = match msg.update_oneof {
             Some(UpdateOneof::Account(account)) => {
                 let account_pretty = AccountPretty::from(account);
                 log::debug!("Received account: {:?}", account_pretty);
-                Self::handle_backpressure(
-                    tx,
-                    EventPretty::Account(account_pretty),
-                    backpressure_strategy,
-                )
-                    .await?;
+                EventPretty::Account(account_pretty)
+            }
+            Some(UpdateOneof::Block(block)) => {
+                let block_pretty = BlockPretty::from(block);
+                log::debug!("Received block: {:?}", block_pretty);
+                EventPretty::Block(block_pretty)
             }
             Some(UpdateOneof::BlockMeta(sut)) => {
                 let block_meta_pretty = BlockMetaPretty::from((sut, created_at));
                 log::debug!("Received block meta: {:?}", block_meta_pretty);
-                Self::handle_backpressure(
-                    tx,
-                    EventPretty::BlockMeta(block_meta_pretty),
-                    backpressure_strategy,
-                )
-                    .await?;
+                EventPretty::BlockMeta(block_meta_pretty)
             }
             Some(UpdateOneof::Transaction(sut)) => {
                 let transaction_pretty = TransactionPretty::from((sut, created_at));
@@ -53,48 +58,58 @@ impl StreamHandler {
                     transaction_pretty.signature,
                     transaction_pretty.slot
                 );
-                Self::handle_backpressure(
-                    tx,
-                    EventPretty::Transaction(transaction_pretty),
-                    backpressure_strategy,
-                )
-                    .await?;
+                EventPretty::Transaction(transaction_pretty)
+            }
+            Some(UpdateOneof::Slot(sut)) => {
+                let Some(slot_pretty) = SlotPretty::from_update(sut, created_at) else {
+                    return Ok(());
+                };
+                log::debug!("Received slot: {:?}", slot_pretty);
+                EventPretty::Slot(slot_pretty)
             }
             Some(UpdateOneof::Ping(_)) => {
                 subscribe_tx
                     .send(SubscribeRequest {
-                        ping: Some(SubscribeRequestPing { id: 1 }),
+                        ping: Some(SubscribeRequestPing { id: SERVER_PING_REPLY_ID }),
                         ..Default::default()
                     })
                     .await?;
                 log::debug!("service is ping: {}", Local::now());
+                return Ok(());
             }
             Some(UpdateOneof::Pong(_)) => {
                 log::debug!("service is pong: {}", Local::now());
+                return Ok(());
             }
             _ => {
                 log::debug!("Received other message type");
+                return Ok(());
             }
-        }
-        Ok(())
+        };
+
+        event_pretty.set_timings(timings);
+        event_pretty.set_filters(filters);
+        Self::handle_backpressure(tx, event_pretty, backpressure_strategy).await
     }
 
-    /// 处理背压策略（tokio::mpsc 版本；无克隆重试）
-    async fn handle_backpressure(
-        tx: &mpsc::Sender<EventPretty>,
-        event_pretty: EventPretty,
+    /// 处理背压策略（tokio::mpsc 版本；无克隆重试）。
+    /// 同时用于读取器 → 处理器通道与事件流 API 的输出通道
+    pub(crate) async fn handle_backpressure<T>(
+        tx: &mpsc::Sender<T>,
+        item: T,
         backpressure_strategy: BackpressureStrategy,
     ) -> AnyResult<()> {
         match backpressure_strategy {
-            BackpressureStrategy::Block => {
+            // Priority：写入 PriorityGate 的入口通道，合并与按优先级丢弃由闸门完成
+            BackpressureStrategy::Block | BackpressureStrategy::Priority => {
                 // 阻塞直到有容量
-                if let Err(e) = tx.send(event_pretty).await {
+                if let Err(e) = tx.send(item).await {
                     log::error!("Channel send failed: {}", e);
                     return Err(anyhow::anyhow!("Channel send failed: {}", e));
                 }
             }
             BackpressureStrategy::Drop => {
-                if let Err(e) = tx.try_send(event_pretty) {
+                if let Err(e) = tx.try_send(item) {
                     match e {
                         TrySendError::Full(_) => {
                             log::warn!("Channel is full, dropping event");
@@ -109,7 +124,7 @@ impl StreamHandler {
             BackpressureStrategy::Retry { max_attempts, wait_ms } => {
                 let mut attempts = 0usize;
                 // 持有同一个值重试，不进行 clone
-                let mut ev = Some(event_pretty);
+                let mut ev = Some(item);
                 loop {
                     match tx.try_send(ev.take().expect("event consumed")) {
                         Ok(()) => break,
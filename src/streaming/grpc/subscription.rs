@@ -1,48 +1,182 @@
-use futures::{channel::mpsc, sink::Sink, Stream};
+use anyhow::{bail, Context};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use futures::{
+    channel::mpsc,
+    future::{BoxFuture, FutureExt},
+    sink::Sink,
+    Stream,
+};
+use hyper_util::client::legacy::connect::{
+    proxy::{SocksV5, Tunnel},
+    HttpConnector,
+};
 use maplit::hashmap;
-use std::{collections::HashMap, pin::Pin, time::Duration};
+use std::{collections::HashMap, fs, pin::Pin, sync::Arc, time::Duration};
+use tokio::sync::Mutex;
+use tonic::{
+    codegen::http::{HeaderValue, Uri},
+    transport::{Certificate, Channel, Endpoint, Identity},
+};
+use tonic_health::pb::health_client::HealthClient;
 
-use yellowstone_grpc_client::{ClientTlsConfig, GeyserGrpcClient, Interceptor};
+use yellowstone_grpc_client::{
+    ClientTlsConfig, GeyserGrpcBuilder, GeyserGrpcClient, Interceptor, InterceptorXToken,
+};
 use yellowstone_grpc_proto::{
     self as proto,
     geyser::{
+        geyser_client::GeyserClient,
         CommitmentLevel, SubscribeRequest, SubscribeRequestFilterAccounts,
-        SubscribeRequestFilterBlocksMeta, SubscribeRequestFilterTransactions, SubscribeUpdate,
+        SubscribeRequestFilterBlocks, SubscribeRequestFilterBlocksMeta, SubscribeRequestFilterSlots,
+        SubscribeRequestFilterTransactions, SubscribeUpdate,
     },
 };
 
 // 👇 Fix the tonic::Status version mismatch
 use proto::tonic::Status;
 
-use super::types::{AccountsFilterMap, TransactionsFilterMap};
+use super::control::{ACCOUNTS_FILTER_KEY, BLOCKS_FILTER_KEY, TRANSACTIONS_FILTER_KEY};
+use super::types::{AccountDataFilter, AccountsFilterMap, TransactionsFilterMap};
 use crate::common::AnyResult;
-use crate::streaming::common::StreamClientConfig as ClientConfig;
+use crate::streaming::common::{ProxyConfig, StreamClientConfig as ClientConfig};
 use crate::streaming::events::common::filter::EventTypeFilter;
 
+/// 订阅请求发送端
+pub type SubscribeSink =
+    Pin<Box<dyn Sink<SubscribeRequest, Error = mpsc::SendError> + Send + 'static>>;
+
+/// 订阅更新流
+pub type SubscribeStream =
+    Pin<Box<dyn Stream<Item = Result<SubscribeUpdate, Status>> + Send + 'static>>;
+
+/// 共享连接上的订阅函数（GeyserGrpcClient 的拦截器类型不可命名，故以闭包擦除）
+type SharedSubscribe = Arc<
+    dyn Fn(SubscribeRequest) -> BoxFuture<'static, AnyResult<(SubscribeSink, SubscribeStream)>>
+        + Send
+        + Sync,
+>;
+
 /// 订阅管理器
 #[derive(Clone)]
 pub struct SubscriptionManager {
     endpoint: String,
     x_token: Option<String>,
     config: ClientConfig,
+    /// 共享连接（仅在 `connection.share_connection` 开启时使用，克隆之间共享）
+    shared: Arc<Mutex<Option<SharedSubscribe>>>,
 }
 
 impl SubscriptionManager {
     /// 创建新的订阅管理器
     pub fn new(endpoint: String, x_token: Option<String>, config: ClientConfig) -> Self {
-        Self { endpoint, x_token, config }
+        Self { endpoint, x_token, config, shared: Arc::new(Mutex::new(None)) }
     }
 
-    /// 创建 gRPC 连接
+    /// 创建 gRPC 连接（TLS / mTLS、代理与压缩取自 `config.connection`）
     pub async fn connect(&self) -> AnyResult<GeyserGrpcClient<impl Interceptor + 'static>> {
-        let builder = GeyserGrpcClient::build_from_shared(self.endpoint.clone())?
+        let connection = &self.config.connection;
+        let mut builder = GeyserGrpcClient::build_from_shared(self.endpoint.clone())?
             .x_token(self.x_token.clone())?
-            .tls_config(ClientTlsConfig::new().with_native_roots())?
-            .max_decoding_message_size(self.config.connection.max_decoding_message_size)
-            .connect_timeout(Duration::from_secs(self.config.connection.connect_timeout))
-            .timeout(Duration::from_secs(self.config.connection.request_timeout));
+            .tls_config(self.tls_config()?)?
+            .max_decoding_message_size(connection.max_decoding_message_size)
+            .connect_timeout(Duration::from_secs(connection.connect_timeout))
+            .timeout(Duration::from_secs(connection.request_timeout));
+        if let Some(encoding) = connection.compression.encoding() {
+            builder = builder.accept_compressed(encoding);
+        }
+
+        let channel = match &connection.proxy {
+            Some(proxy) => Self::connect_via_proxy(&builder.endpoint, proxy).await?,
+            None => builder.endpoint.connect().await?,
+        };
+        Ok(Self::client_from_channel(builder, channel))
+    }
+
+    /// 按配置构建 TLS：系统根证书、自定义 CA、客户端证书（mTLS）与 SNI 域名
+    fn tls_config(&self) -> AnyResult<ClientTlsConfig> {
+        let tls = &self.config.connection.tls;
+        let mut config = ClientTlsConfig::new();
+        if tls.native_roots {
+            config = config.with_native_roots();
+        }
+        if let Some(path) = &tls.ca_cert_path {
+            let pem = fs::read(path).with_context(|| format!("reading CA bundle {path}"))?;
+            config = config.ca_certificate(Certificate::from_pem(pem));
+        }
+        match (&tls.client_cert_path, &tls.client_key_path) {
+            (Some(cert_path), Some(key_path)) => {
+                let cert = fs::read(cert_path)
+                    .with_context(|| format!("reading client certificate {cert_path}"))?;
+                let key =
+                    fs::read(key_path).with_context(|| format!("reading client key {key_path}"))?;
+                config = config.identity(Identity::from_pem(cert, key));
+            }
+            (None, None) => {}
+            _ => bail!("TLS client certificate and key must be configured together"),
+        }
+        if let Some(domain) = &tls.domain_name {
+            config = config.domain_name(domain.clone());
+        }
+        Ok(config)
+    }
+
+    /// 经代理建立通道：http:// 使用 HTTP CONNECT 隧道，socks5:// 本地解析域名，socks5h:// 由代理解析；
+    /// TLS 仍由 tonic 在隧道之上完成
+    async fn connect_via_proxy(endpoint: &Endpoint, proxy: &ProxyConfig) -> AnyResult<Channel> {
+        let proxy_uri: Uri =
+            proxy.url.parse().with_context(|| format!("invalid proxy URL {}", proxy.url))?;
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        http.set_nodelay(true);
+
+        let credentials = proxy.username.clone().zip(proxy.password.clone());
+        let channel = match proxy_uri.scheme_str() {
+            Some("http") => {
+                let mut tunnel = Tunnel::new(proxy_uri, http);
+                if let Some((username, password)) = credentials {
+                    let token = STANDARD.encode(format!("{username}:{password}"));
+                    tunnel = tunnel.with_auth(HeaderValue::from_str(&format!("Basic {token}"))?);
+                }
+                endpoint.connect_with_connector(tunnel).await?
+            }
+            Some(scheme @ ("socks5" | "socks5h")) => {
+                let local_dns = scheme == "socks5";
+                let mut socks = SocksV5::new(proxy_uri, http).local_dns(local_dns);
+                if let Some((username, password)) = credentials {
+                    socks = socks.with_auth(username, password);
+                }
+                endpoint.connect_with_connector(socks).await?
+            }
+            _ => bail!("Unsupported proxy scheme in {} (expected http, socks5 or socks5h)", proxy.url),
+        };
+        Ok(channel)
+    }
+
+    /// 在已建立的通道上构建客户端（与 GeyserGrpcBuilder::connect 等价，便于接入自定义连接器）
+    fn client_from_channel(
+        builder: GeyserGrpcBuilder,
+        channel: Channel,
+    ) -> GeyserGrpcClient<InterceptorXToken> {
+        let interceptor = InterceptorXToken {
+            x_token: builder.x_token,
+            x_request_snapshot: builder.x_request_snapshot,
+        };
+
+        let mut geyser = GeyserClient::with_interceptor(channel.clone(), interceptor.clone());
+        if let Some(encoding) = builder.send_compressed {
+            geyser = geyser.send_compressed(encoding);
+        }
+        if let Some(encoding) = builder.accept_compressed {
+            geyser = geyser.accept_compressed(encoding);
+        }
+        if let Some(limit) = builder.max_decoding_message_size {
+            geyser = geyser.max_decoding_message_size(limit);
+        }
+        if let Some(limit) = builder.max_encoding_message_size {
+            geyser = geyser.max_encoding_message_size(limit);
+        }
 
-        Ok(builder.connect().await?)
+        GeyserGrpcClient::new(HealthClient::with_interceptor(channel, interceptor), geyser)
     }
 
     /// 创建订阅请求并返回流
@@ -52,10 +186,20 @@ impl SubscriptionManager {
         accounts: Option<AccountsFilterMap>,
         commitment: Option<CommitmentLevel>,
         event_type_filter: Option<EventTypeFilter>,
-    ) -> AnyResult<(
-        Pin<Box<dyn Sink<SubscribeRequest, Error = mpsc::SendError> + Send + 'static>>,
-        Pin<Box<dyn Stream<Item = Result<SubscribeUpdate, Status>> + Send + 'static>>,
-    )> {
+    ) -> AnyResult<(SubscribeSink, SubscribeStream)> {
+        let subscribe_request =
+            self.build_subscribe_request(transactions, accounts, commitment, event_type_filter);
+        self.subscribe(subscribe_request).await
+    }
+
+    /// 构建订阅请求（不建立连接，便于重连时复用）
+    pub fn build_subscribe_request(
+        &self,
+        transactions: Option<TransactionsFilterMap>,
+        accounts: Option<AccountsFilterMap>,
+        commitment: Option<CommitmentLevel>,
+        event_type_filter: Option<EventTypeFilter>,
+    ) -> SubscribeRequest {
         let blocks_meta = if event_type_filter.as_ref().is_some_and(|f| f.include_block_event()) {
             hashmap! { "".to_owned() => SubscribeRequestFilterBlocksMeta {} }
         } else if event_type_filter.is_none() {
@@ -64,25 +208,108 @@ impl SubscriptionManager {
             hashmap! {}
         };
 
-        let subscribe_request = SubscribeRequest {
+        // slot 状态：不按提交级别过滤，才能收到 confirmed / finalized / dead 全部状态；
+        // 提交级别跟踪也依赖 slot 状态
+        let slots = if event_type_filter.as_ref().is_none_or(|f| f.include_slot_event())
+            || self.config.commitment_tracking.enabled
+        {
+            hashmap! {
+                "".to_owned() => SubscribeRequestFilterSlots {
+                    filter_by_commitment: Some(false),
+                    interslot_updates: Some(false),
+                }
+            }
+        } else {
+            hashmap! {}
+        };
+
+        SubscribeRequest {
             accounts: accounts.unwrap_or_default(),
+            slots,
             transactions: transactions.unwrap_or_default(),
             blocks_meta,
             commitment: commitment
                 .map(|c| c as i32)
                 .or_else(|| Some(CommitmentLevel::Processed.into())),
             ..Default::default()
-        };
+        }
+    }
+
+    /// 构建完整区块订阅请求：包含交易，不含账户与 entries；account_include 为空时推送全部区块。
+    /// 同时订阅 slot 状态，使 account_include 长时间未命中时流上仍有更新，不触发空闲重连
+    pub fn build_block_request(
+        &self,
+        account_include: Vec<String>,
+        commitment: Option<CommitmentLevel>,
+    ) -> SubscribeRequest {
+        SubscribeRequest {
+            slots: hashmap! {
+                "".to_owned() => SubscribeRequestFilterSlots {
+                    filter_by_commitment: Some(false),
+                    interslot_updates: Some(false),
+                }
+            },
+            blocks: hashmap! {
+                BLOCKS_FILTER_KEY.to_owned() => SubscribeRequestFilterBlocks {
+                    account_include,
+                    include_transactions: Some(true),
+                    include_accounts: Some(false),
+                    include_entries: Some(false),
+                }
+            },
+            commitment: commitment
+                .map(|c| c as i32)
+                .or_else(|| Some(CommitmentLevel::Processed.into())),
+            ..Default::default()
+        }
+    }
+
+    /// 建立新连接并发送给定的订阅请求
+    pub async fn subscribe(
+        &self,
+        subscribe_request: SubscribeRequest,
+    ) -> AnyResult<(SubscribeSink, SubscribeStream)> {
+        if self.config.connection.share_connection {
+            return self.subscribe_shared(subscribe_request).await;
+        }
 
         let mut client = self.connect().await?;
         let (sink, stream) = client.subscribe_with_request(Some(subscribe_request)).await?;
 
-        Ok((
-            Box::pin(sink)
-                as Pin<Box<dyn Sink<SubscribeRequest, Error = mpsc::SendError> + Send + 'static>>,
-            Box::pin(stream)
-                as Pin<Box<dyn Stream<Item = Result<SubscribeUpdate, Status>> + Send + 'static>>,
-        ))
+        Ok((Box::pin(sink) as SubscribeSink, Box::pin(stream) as SubscribeStream))
+    }
+
+    /// 在共享连接上新开一个订阅流（HTTP/2 多路复用），失败时丢弃共享连接以便下次重建
+    async fn subscribe_shared(
+        &self,
+        subscribe_request: SubscribeRequest,
+    ) -> AnyResult<(SubscribeSink, SubscribeStream)> {
+        let subscribe = {
+            let mut shared = self.shared.lock().await;
+            match shared.as_ref() {
+                Some(subscribe) => Arc::clone(subscribe),
+                None => {
+                    let client = Arc::new(Mutex::new(self.connect().await?));
+                    let subscribe: SharedSubscribe = Arc::new(move |request| {
+                        let client = Arc::clone(&client);
+                        async move {
+                            let (sink, stream) =
+                                client.lock().await.subscribe_with_request(Some(request)).await?;
+                            Ok((Box::pin(sink) as SubscribeSink, Box::pin(stream) as SubscribeStream))
+                        }
+                        .boxed()
+                    });
+                    *shared = Some(Arc::clone(&subscribe));
+                    subscribe
+                }
+            }
+        };
+
+        let result = subscribe(subscribe_request).await;
+        if result.is_err() {
+            self.shared.lock().await.take();
+        }
+        result
     }
 
     /// 创建账户订阅请求并返回账户过滤器
@@ -90,8 +317,26 @@ impl SubscriptionManager {
         &self,
         account: Vec<String>,
         owner: Vec<String>,
+        filters: &[AccountDataFilter],
+        nonempty_txn_signature: Option<bool>,
         event_type_filter: Option<EventTypeFilter>,
     ) -> Option<AccountsFilterMap> {
+        let entry = self.account_filter_entry(account, owner, filters, nonempty_txn_signature, event_type_filter)?;
+
+        let mut accounts = HashMap::new();
+        accounts.insert(ACCOUNTS_FILTER_KEY.to_owned(), entry);
+        Some(accounts)
+    }
+
+    /// 构建单个账户过滤条目（账户与 owner 均为空、或事件类型过滤器不含账户事件时返回 None）
+    pub fn account_filter_entry(
+        &self,
+        account: Vec<String>,
+        owner: Vec<String>,
+        filters: &[AccountDataFilter],
+        nonempty_txn_signature: Option<bool>,
+        event_type_filter: Option<EventTypeFilter>,
+    ) -> Option<SubscribeRequestFilterAccounts> {
         if account.is_empty() && owner.is_empty() {
             return None;
         }
@@ -99,17 +344,12 @@ impl SubscriptionManager {
             return None;
         }
 
-        let mut accounts = HashMap::new();
-        accounts.insert(
-            "".to_owned(),
-            SubscribeRequestFilterAccounts {
-                account,
-                owner,
-                filters: vec![],
-                nonempty_txn_signature: None,
-            },
-        );
-        Some(accounts)
+        Some(SubscribeRequestFilterAccounts {
+            account,
+            owner,
+            filters: filters.iter().map(Into::into).collect(),
+            nonempty_txn_signature,
+        })
     }
 
     /// 生成订阅请求过滤器
@@ -120,27 +360,79 @@ impl SubscriptionManager {
         account_required: Vec<String>,
         event_type_filter: Option<EventTypeFilter>,
     ) -> Option<TransactionsFilterMap> {
+        let entry = self.transaction_filter_entry(
+            account_include,
+            account_exclude,
+            account_required,
+            Some(false),
+            event_type_filter,
+        )?;
+
+        let mut transactions = HashMap::new();
+        transactions.insert(TRANSACTIONS_FILTER_KEY.to_owned(), entry);
+        Some(transactions)
+    }
+
+    /// 构建单个交易过滤条目（事件类型过滤器不含交易事件时返回 None）；
+    /// failed 为 None 时成功与失败交易都推送，Some(true) 仅推送失败交易
+    pub fn transaction_filter_entry(
+        &self,
+        account_include: Vec<String>,
+        account_exclude: Vec<String>,
+        account_required: Vec<String>,
+        failed: Option<bool>,
+        event_type_filter: Option<EventTypeFilter>,
+    ) -> Option<SubscribeRequestFilterTransactions> {
         if event_type_filter.as_ref().is_some_and(|f| !f.include_transaction_event()) {
             return None;
         }
 
-        let mut transactions = HashMap::new();
-        transactions.insert(
-            "client".to_string(),
-            SubscribeRequestFilterTransactions {
-                vote: Some(false),
-                failed: Some(false),
-                signature: None,
-                account_include,
-                account_exclude,
-                account_required,
-            },
-        );
-        Some(transactions)
+        Some(SubscribeRequestFilterTransactions {
+            vote: Some(false),
+            failed,
+            signature: None,
+            account_include,
+            account_exclude,
+            account_required,
+        })
     }
 
     /// 获取配置
     pub fn get_config(&self) -> &ClientConfig {
         &self.config
     }
-}
\ No newline at end of file
+
+    /// 获取端点地址
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_request_includes_transactions_and_slots() {
+        let manager = SubscriptionManager::new(
+            "http://127.0.0.1:10000".to_string(),
+            None,
+            ClientConfig::default(),
+        );
+        let request = manager.build_block_request(vec!["program".to_string()], None);
+
+        let blocks = &request.blocks[BLOCKS_FILTER_KEY];
+        assert_eq!(blocks.account_include, ["program"]);
+        assert_eq!(blocks.include_transactions, Some(true));
+        assert_eq!(blocks.include_accounts, Some(false));
+        assert_eq!(blocks.include_entries, Some(false));
+
+        // slot 更新使长时间未命中的区块订阅不被判定为空闲
+        assert_eq!(request.slots.len(), 1);
+        assert!(request.transactions.is_empty() && request.accounts.is_empty());
+        assert_eq!(request.commitment, Some(CommitmentLevel::Processed as i32));
+
+        let confirmed = manager.build_block_request(Vec::new(), Some(CommitmentLevel::Confirmed));
+        assert!(confirmed.blocks[BLOCKS_FILTER_KEY].account_include.is_empty());
+        assert_eq!(confirmed.commitment, Some(CommitmentLevel::Confirmed as i32));
+    }
+}
@@ -0,0 +1,560 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures::{SinkExt, StreamExt};
+use log::{error, info, warn};
+use tokio::sync::{mpsc, watch};
+use tokio::time::MissedTickBehavior;
+use tonic::Code;
+use yellowstone_grpc_proto::geyser::{
+    subscribe_update::UpdateOneof, SubscribeRequest, SubscribeRequestPing, SubscribeUpdate,
+};
+
+use super::capture::CaptureRecorder;
+use super::fanin::FanInDeduper;
+use super::streamhandler::{StreamHandler, SERVER_PING_REPLY_ID};
+use super::subscription::{SubscribeSink, SubscribeStream, SubscriptionManager};
+use super::types::EventPretty;
+use crate::streaming::common::{BackpressureStrategy, MetricsManager, ReconnectConfig, WatchdogConfig};
+
+/// 去重键：交易按签名，账户按 (pubkey, slot, txn_signature)，完整区块与区块元数据按 slot，
+/// slot 状态按 (slot, status)
+/// 注意不使用 write_version —— 它是节点本地计数器，不同提供商之间不一致
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum DedupKey {
+    Transaction(Vec<u8>),
+    Account(Vec<u8>, u64, Vec<u8>),
+    Block(u64),
+    BlockMeta(u64),
+    Slot(u64, i32),
+}
+
+impl DedupKey {
+    /// 提取可去重的键；ping/pong 等控制消息返回 None
+    pub fn from_update(msg: &SubscribeUpdate) -> Option<Self> {
+        match &msg.update_oneof {
+            Some(UpdateOneof::Transaction(sut)) => {
+                sut.transaction.as_ref().map(|info| Self::Transaction(info.signature.clone()))
+            }
+            Some(UpdateOneof::Account(account)) => account.account.as_ref().map(|info| {
+                Self::Account(
+                    info.pubkey.clone(),
+                    account.slot,
+                    info.txn_signature.clone().unwrap_or_default(),
+                )
+            }),
+            Some(UpdateOneof::Block(block)) => Some(Self::Block(block.slot)),
+            Some(UpdateOneof::BlockMeta(block_meta)) => Some(Self::BlockMeta(block_meta.slot)),
+            Some(UpdateOneof::Slot(slot)) => Some(Self::Slot(slot.slot, slot.status)),
+            _ => None,
+        }
+    }
+}
+
+/// 有界去重窗口（FIFO 淘汰最旧的键）
+pub struct UpdateDeduper {
+    seen: HashSet<DedupKey>,
+    order: VecDeque<DedupKey>,
+    capacity: usize,
+}
+
+impl UpdateDeduper {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            seen: HashSet::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// 首次出现返回 true；重复（例如重连后 from_slot 回放）返回 false
+    pub fn check(&mut self, msg: &SubscribeUpdate) -> bool {
+        if self.capacity == 0 {
+            return true;
+        }
+        let Some(key) = DedupKey::from_update(msg) else {
+            return true;
+        };
+
+        if !self.seen.insert(key.clone()) {
+            return false;
+        }
+        self.order.push_back(key);
+        if self.order.len() > self.capacity
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.seen.remove(&oldest);
+        }
+        true
+    }
+}
+
+/// 提取更新所属的 slot（ping/pong 没有 slot）
+pub fn update_slot(msg: &SubscribeUpdate) -> Option<u64> {
+    match &msg.update_oneof {
+        Some(UpdateOneof::Account(account)) => Some(account.slot),
+        Some(UpdateOneof::Slot(slot)) => Some(slot.slot),
+        Some(UpdateOneof::Transaction(sut)) => Some(sut.slot),
+        Some(UpdateOneof::TransactionStatus(status)) => Some(status.slot),
+        Some(UpdateOneof::Block(block)) => Some(block.slot),
+        Some(UpdateOneof::BlockMeta(block_meta)) => Some(block_meta.slot),
+        Some(UpdateOneof::Entry(entry)) => Some(entry.slot),
+        _ => None,
+    }
+}
+
+/// 同时等待应答的 ping 上限；超出时丢弃最早发出的 ping
+const MAX_PENDING_PINGS: usize = 16;
+
+/// 已发出、尚未收到 pong 的看门狗 ping（id -> 发送时间）。
+/// pong 可能晚于下一次 ping 到达，按 id 匹配才不会丢失往返时间
+#[derive(Default)]
+pub struct PendingPings {
+    next_id: i32,
+    sent: HashMap<i32, Instant>,
+}
+
+impl PendingPings {
+    /// 生成下一个 ping id 并记录发送时间（跳过服务端 ping 应答使用的保留 id）
+    pub fn send(&mut self, sent_at: Instant) -> i32 {
+        self.next_id = match self.next_id.wrapping_add(1) {
+            SERVER_PING_REPLY_ID => SERVER_PING_REPLY_ID + 1,
+            id => id,
+        };
+        if self.sent.len() >= MAX_PENDING_PINGS
+            && let Some(oldest) = self.sent.iter().min_by_key(|(_, at)| **at).map(|(id, _)| *id)
+        {
+            self.sent.remove(&oldest);
+        }
+        self.sent.insert(self.next_id, sent_at);
+        self.next_id
+    }
+
+    /// 匹配 pong，返回往返时间；未知 id（服务端 ping 应答或已淘汰）返回 None
+    pub fn answer(&mut self, id: i32) -> Option<Duration> {
+        self.sent.remove(&id).map(|sent_at| sent_at.elapsed())
+    }
+
+    pub fn len(&self) -> usize {
+        self.sent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sent.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.sent.clear();
+    }
+}
+
+/// 重连退避状态：只在新连接收到第一条真实更新后复位，
+/// 避免订阅被接受后随即被拒绝（例如 from_slot 已过期）时无退避地反复重连
+struct Backoff {
+    delay_ms: u64,
+    attempts: usize,
+    /// 连续未收到任何更新的连接数
+    unproductive: usize,
+}
+
+impl Backoff {
+    fn new(config: &ReconnectConfig) -> Self {
+        Self { delay_ms: config.initial_backoff_ms, attempts: 0, unproductive: 0 }
+    }
+}
+
+/// 受监督的 Yellowstone 读取器：断线后按指数退避重连，
+/// 以最后看到的 slot 作为 from_slot 续订，并对回放的更新去重；
+/// 看门狗定时发送 ping 测量往返时间，并以 pong 作为心跳：
+/// 空闲超时内既无更新也无 pong 时视为假死（含半开连接）并重新订阅
+pub struct StreamSupervisor {
+    subscription_manager: SubscriptionManager,
+    request: SubscribeRequest,
+    config: ReconnectConfig,
+    watchdog: WatchdogConfig,
+    metrics_manager: MetricsManager,
+    backpressure_strategy: BackpressureStrategy,
+    /// 多端点汇聚时共享的首达去重器及本端点序号
+    fanin: Option<(Arc<FanInDeduper>, usize)>,
+    /// 运行时订阅变更（来自 SubscriptionController）
+    control: Option<watch::Receiver<SubscribeRequest>>,
+    /// 原始流量录制（去重前，保留每个端点收到的全部更新）
+    recorder: Option<Arc<CaptureRecorder>>,
+}
+
+impl StreamSupervisor {
+    pub fn new(
+        subscription_manager: SubscriptionManager,
+        request: SubscribeRequest,
+        config: ReconnectConfig,
+        metrics_manager: MetricsManager,
+        backpressure_strategy: BackpressureStrategy,
+    ) -> Self {
+        Self {
+            subscription_manager,
+            request,
+            config,
+            watchdog: WatchdogConfig::default(),
+            metrics_manager,
+            backpressure_strategy,
+            fanin: None,
+            control: None,
+            recorder: None,
+        }
+    }
+
+    /// 作为多端点汇聚的一员运行：去重改由共享的 FanInDeduper 负责（先到者胜出）
+    pub fn with_fanin(mut self, fanin: Arc<FanInDeduper>, endpoint_index: usize) -> Self {
+        self.fanin = Some((fanin, endpoint_index));
+        self
+    }
+
+    /// 监听运行时订阅变更：新请求经当前连接的 subscribe_tx 下发，并在重连时沿用
+    pub fn with_control(mut self, control: watch::Receiver<SubscribeRequest>) -> Self {
+        self.control = Some(control);
+        self
+    }
+
+    /// 设置看门狗（主动 ping 间隔与空闲超时）
+    pub fn with_watchdog(mut self, watchdog: WatchdogConfig) -> Self {
+        self.watchdog = watchdog;
+        self
+    }
+
+    /// 将收到的每条原始更新写入抓包文件
+    pub fn with_recorder(mut self, recorder: Option<Arc<CaptureRecorder>>) -> Self {
+        self.recorder = recorder;
+        self
+    }
+
+    /// 首次连接失败时使用：先走重连流程建立订阅，再进入读取循环
+    pub async fn connect_and_run(self, tx: mpsc::Sender<EventPretty>) {
+        let mut backoff = Backoff::new(&self.config);
+        if let Some((subscribe_tx, stream)) = self.reconnect(None, &mut backoff, &tx).await {
+            self.run(subscribe_tx, stream, tx).await;
+        }
+    }
+
+    /// 读取循环；仅在下游通道关闭或重连次数耗尽时返回
+    pub async fn run(
+        mut self,
+        mut subscribe_tx: SubscribeSink,
+        mut stream: SubscribeStream,
+        tx: mpsc::Sender<EventPretty>,
+    ) {
+        let mut deduper = UpdateDeduper::new(self.config.dedup_capacity);
+        let mut last_slot: Option<u64> = None;
+        // 重连后等待第一条带 slot 的更新，用于计算缺口
+        let mut gap_from: Option<u64> = None;
+        let mut control = self.control.take();
+
+        let liveness = Arc::clone(self.metrics_manager.liveness());
+        let watchdog = self.watchdog.clone();
+        let idle_timeout = Duration::from_millis(watchdog.idle_timeout_ms);
+        let mut ping_tick = tokio::time::interval(Duration::from_millis(watchdog.ping_interval_ms.max(1)));
+        ping_tick.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut pending_pings = PendingPings::default();
+        let mut backoff = Backoff::new(&self.config);
+
+        loop {
+            liveness.reader_connected();
+            let mut received_update = false;
+            let idle = tokio::time::sleep(idle_timeout);
+            tokio::pin!(idle);
+
+            loop {
+                let message = tokio::select! {
+                    request = next_request(&mut control) => {
+                        self.request = request.clone();
+                        if let Err(e) = subscribe_tx.send(request).await {
+                            // 旧连接已不可用，重连时会使用新请求
+                            warn!("Failed to push subscription update: {e:?}");
+                            break;
+                        }
+                        info!("Subscription filters updated");
+                        continue;
+                    }
+                    _ = ping_tick.tick(), if watchdog.enabled => {
+                        let ping_id = pending_pings.send(Instant::now());
+                        let ping = SubscribeRequest {
+                            ping: Some(SubscribeRequestPing { id: ping_id }),
+                            ..Default::default()
+                        };
+                        if let Err(e) = subscribe_tx.send(ping).await {
+                            warn!("Failed to send watchdog ping: {e:?}");
+                            break;
+                        }
+                        liveness.record_ping();
+                        continue;
+                    }
+                    _ = &mut idle, if watchdog.enabled => {
+                        warn!(
+                            "No update or pong received for {}ms, tearing down the subscription",
+                            watchdog.idle_timeout_ms
+                        );
+                        liveness.record_stall();
+                        break;
+                    }
+                    message = stream.next() => message,
+                };
+                let Some(message) = message else {
+                    break;
+                };
+
+                match message {
+                    Ok(msg) => {
+                        if let Some(recorder) = &self.recorder {
+                            recorder.record(&msg);
+                        }
+                        match &msg.update_oneof {
+                            // 应答本连接发出的 ping：连接存活，重置空闲计时
+                            Some(UpdateOneof::Pong(pong)) => {
+                                if let Some(rtt) = pending_pings.answer(pong.id) {
+                                    liveness.record_pong(rtt);
+                                    idle.as_mut().reset(tokio::time::Instant::now() + idle_timeout);
+                                }
+                            }
+                            // 服务端 ping 不代表有数据，不重置空闲计时
+                            Some(UpdateOneof::Ping(_)) => {}
+                            _ => {
+                                liveness.record_message();
+                                idle.as_mut().reset(tokio::time::Instant::now() + idle_timeout);
+                                if !received_update {
+                                    received_update = true;
+                                    backoff = Backoff::new(&self.config);
+                                }
+                            }
+                        }
+                        if let Some(slot) = update_slot(&msg) {
+                            if let Some(previous) = gap_from.take() {
+                                let gap_slots = slot.saturating_sub(previous);
+                                if gap_slots > 1 {
+                                    warn!("Resumed with a gap of {gap_slots} slots ({previous} -> {slot})");
+                                }
+                                self.metrics_manager.record_slot_gap(gap_slots).await;
+                            }
+                            last_slot = Some(last_slot.map_or(slot, |s| s.max(slot)));
+                        }
+
+                        let first_arrival = match &self.fanin {
+                            Some((fanin, endpoint_index)) => fanin.accept(*endpoint_index, &msg),
+                            None => deduper.check(&msg),
+                        };
+                        if !first_arrival {
+                            self.metrics_manager.add_duplicate_count().await;
+                            continue;
+                        }
+
+                        if let Err(e) = StreamHandler::handle_stream_message(
+                            msg,
+                            &tx,
+                            &mut subscribe_tx,
+                            self.backpressure_strategy,
+                        )
+                        .await
+                        {
+                            error!("Error handling message: {e:?}");
+                            break;
+                        }
+                    }
+                    Err(error) => {
+                        error!("Stream error: {error:?}");
+                        // 尚未收到更新即被拒绝为参数错误：from_slot 不可用，下次不再续订
+                        if !received_update
+                            && matches!(error.code(), Code::InvalidArgument | Code::OutOfRange)
+                        {
+                            backoff.unproductive = self.config.max_resume_attempts;
+                        }
+                        break;
+                    }
+                }
+            }
+            liveness.reader_disconnected();
+            pending_pings.clear();
+
+            if tx.is_closed() {
+                return;
+            }
+            if !self.config.enabled {
+                error!("Stream ended and reconnect is disabled");
+                return;
+            }
+
+            if !received_update {
+                backoff.unproductive = backoff.unproductive.saturating_add(1);
+            }
+            let from_slot = if backoff.unproductive >= self.config.max_resume_attempts.max(1) {
+                warn!("Resuming from slot {last_slot:?} keeps failing, resubscribing from the tip");
+                None
+            } else {
+                last_slot
+            };
+
+            let disconnected_at = Instant::now();
+            warn!("Stream disconnected at slot {last_slot:?}, reconnecting");
+            match self.reconnect(from_slot, &mut backoff, &tx).await {
+                Some((new_subscribe_tx, new_stream)) => {
+                    subscribe_tx = new_subscribe_tx;
+                    stream = new_stream;
+                    gap_from = last_slot;
+
+                    let downtime_ms = disconnected_at.elapsed().as_millis() as u64;
+                    info!("Stream reconnected after {downtime_ms}ms");
+                    self.metrics_manager.record_reconnect(downtime_ms).await;
+                }
+                None => return,
+            }
+        }
+    }
+
+    /// 指数退避重连；退避在连接收到第一条更新前持续累积，返回 None 表示放弃
+    async fn reconnect(
+        &self,
+        from_slot: Option<u64>,
+        backoff: &mut Backoff,
+        tx: &mpsc::Sender<EventPretty>,
+    ) -> Option<(SubscribeSink, SubscribeStream)> {
+        loop {
+            if tx.is_closed() {
+                return None;
+            }
+            backoff.attempts += 1;
+            if self.config.max_attempts.is_some_and(|max| backoff.attempts > max) {
+                error!("Giving up after {} reconnect attempts", backoff.attempts - 1);
+                return None;
+            }
+
+            tokio::time::sleep(Duration::from_millis(backoff.delay_ms)).await;
+            let attempt = backoff.attempts;
+            backoff.delay_ms = self.config.next_backoff_ms(backoff.delay_ms);
+
+            let mut request = self.request.clone();
+            if self.config.resume_from_slot {
+                request.from_slot = from_slot;
+            }
+
+            match self.subscription_manager.subscribe(request).await {
+                Ok(pair) => return Some(pair),
+                Err(e) => warn!("Reconnect attempt {attempt} failed: {e:?}"),
+            }
+        }
+    }
+}
+
+/// 等待下一次订阅变更；未设置控制器或控制器已释放时永远挂起
+async fn next_request(control: &mut Option<watch::Receiver<SubscribeRequest>>) -> SubscribeRequest {
+    if let Some(receiver) = control.as_mut()
+        && receiver.changed().await.is_ok()
+    {
+        return receiver.borrow_and_update().clone();
+    }
+    *control = None;
+    futures::future::pending().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yellowstone_grpc_proto::geyser::{
+        SubscribeUpdatePing, SubscribeUpdateSlot, SubscribeUpdateTransaction,
+        SubscribeUpdateTransactionInfo,
+    };
+
+    fn transaction_update(signature: &[u8], slot: u64) -> SubscribeUpdate {
+        SubscribeUpdate {
+            update_oneof: Some(UpdateOneof::Transaction(SubscribeUpdateTransaction {
+                transaction: Some(SubscribeUpdateTransactionInfo {
+                    signature: signature.to_vec(),
+                    ..Default::default()
+                }),
+                slot,
+            })),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn replayed_updates_are_dropped() {
+        let mut deduper = UpdateDeduper::new(2);
+        let first = transaction_update(b"sig-1", 10);
+        assert!(deduper.check(&first));
+        // from_slot 回放同一笔交易
+        assert!(!deduper.check(&transaction_update(b"sig-1", 10)));
+
+        // ping 没有去重键，总是放行
+        let ping = SubscribeUpdate {
+            update_oneof: Some(UpdateOneof::Ping(SubscribeUpdatePing {})),
+            ..Default::default()
+        };
+        assert!(deduper.check(&ping));
+        assert!(deduper.check(&ping));
+
+        // 超出容量后最旧的键被淘汰
+        assert!(deduper.check(&transaction_update(b"sig-2", 11)));
+        assert!(deduper.check(&transaction_update(b"sig-3", 12)));
+        assert!(deduper.check(&first));
+    }
+
+    #[test]
+    fn slot_statuses_dedup_per_status() {
+        let slot = |status: i32| SubscribeUpdate {
+            update_oneof: Some(UpdateOneof::Slot(SubscribeUpdateSlot {
+                slot: 42,
+                status,
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        let mut deduper = UpdateDeduper::new(16);
+        assert!(deduper.check(&slot(0)));
+        assert!(deduper.check(&slot(1)));
+        assert!(!deduper.check(&slot(1)));
+        assert_eq!(update_slot(&slot(1)), Some(42));
+        assert_eq!(update_slot(&transaction_update(b"sig", 7)), Some(7));
+    }
+
+    #[test]
+    fn backoff_grows_to_the_cap() {
+        let config = ReconnectConfig {
+            initial_backoff_ms: 100,
+            max_backoff_ms: 250,
+            backoff_multiplier: 2.0,
+            ..Default::default()
+        };
+        let backoff = Backoff::new(&config);
+        assert_eq!(backoff.delay_ms, 100);
+        assert_eq!(backoff.attempts, 0);
+        assert_eq!(config.next_backoff_ms(backoff.delay_ms), 200);
+        assert_eq!(config.next_backoff_ms(200), 250);
+        assert_eq!(config.next_backoff_ms(250), 250);
+    }
+
+    #[test]
+    fn late_pongs_keep_their_round_trip() {
+        let mut pings = PendingPings::default();
+        let first = pings.send(Instant::now());
+        let second = pings.send(Instant::now());
+        assert_ne!(first, SERVER_PING_REPLY_ID);
+        assert_ne!(first, second);
+
+        // 第一个 ping 的 pong 晚于第二个 ping 才到达
+        assert!(pings.answer(second).is_some());
+        assert!(pings.answer(first).is_some());
+        assert!(pings.answer(first).is_none());
+        assert!(pings.answer(SERVER_PING_REPLY_ID).is_none());
+        assert!(pings.is_empty());
+    }
+
+    #[test]
+    fn pending_pings_are_bounded() {
+        let mut pings = PendingPings::default();
+        let start = Instant::now();
+        let ids: Vec<i32> = (0..MAX_PENDING_PINGS as u64 + 4)
+            .map(|i| pings.send(start + Duration::from_millis(i)))
+            .collect();
+        assert_eq!(pings.len(), MAX_PENDING_PINGS);
+        assert!(ids[..4].iter().all(|id| pings.answer(*id).is_none()));
+        assert!(ids[4..].iter().all(|id| pings.answer(*id).is_some()));
+    }
+}
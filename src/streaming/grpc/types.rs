@@ -1,22 +1,130 @@
-use solana_sdk::signature::Signature;
-use solana_transaction_status::{EncodedTransactionWithStatusMeta, UiTransactionEncoding};
+use solana_sdk::{signature::Signature, transaction::VersionedTransaction};
+use solana_transaction_status::{
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransactionWithStatusMeta, UiTransactionEncoding,
+};
 use std::{collections::HashMap, fmt};
 use yellowstone_grpc_proto::{
     geyser::{
-        SubscribeRequestFilterAccounts, SubscribeRequestFilterTransactions, SubscribeUpdateAccount,
-        SubscribeUpdateBlockMeta, SubscribeUpdateTransaction,
+        subscribe_request_filter_accounts_filter::Filter as AccountsFilterKind,
+        subscribe_request_filter_accounts_filter_memcmp::Data as MemcmpData,
+        SubscribeRequestFilterAccounts, SubscribeRequestFilterAccountsFilter,
+        SubscribeRequestFilterAccountsFilterMemcmp, SubscribeRequestFilterTransactions,
+        SubscribeUpdateAccount, SubscribeUpdateBlock, SubscribeUpdateBlockMeta, SubscribeUpdateSlot,
+        SubscribeUpdateTransaction, SubscribeUpdateTransactionInfo,
     },
     prost_types::Timestamp,
 };
 
+use crate::streaming::events::common::EventTimings;
+use crate::streaming::events::protocols::block::slot::SlotStatus;
+
 pub type TransactionsFilterMap = HashMap<String, SubscribeRequestFilterTransactions>;
 pub type AccountsFilterMap = HashMap<String, SubscribeRequestFilterAccounts>;
 
+/// 账户数据过滤条件（由 Geyser 服务端执行；同一账户过滤器内的多个条件为 AND 关系）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountDataFilter {
+    /// 在 offset 处比较原始字节
+    Memcmp { offset: u64, bytes: Vec<u8> },
+    /// 在 offset 处比较 base58 编码的字节
+    MemcmpBase58 { offset: u64, base58: String },
+    /// 账户数据长度
+    Datasize(u64),
+}
+
+impl From<&AccountDataFilter> for SubscribeRequestFilterAccountsFilter {
+    fn from(filter: &AccountDataFilter) -> Self {
+        let filter = match filter {
+            AccountDataFilter::Memcmp { offset, bytes } => {
+                AccountsFilterKind::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
+                    offset: *offset,
+                    data: Some(MemcmpData::Bytes(bytes.clone())),
+                })
+            }
+            AccountDataFilter::MemcmpBase58 { offset, base58 } => {
+                AccountsFilterKind::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
+                    offset: *offset,
+                    data: Some(MemcmpData::Base58(base58.clone())),
+                })
+            }
+            AccountDataFilter::Datasize(size) => AccountsFilterKind::Datasize(*size),
+        };
+        Self { filter: Some(filter) }
+    }
+}
+
+/// 交易过滤器对失败交易的处理方式（对应 SubscribeRequestFilterTransactions.failed）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FailedTransactions {
+    /// 仅推送成功交易
+    #[default]
+    Exclude,
+    /// 成功与失败交易都推送
+    Include,
+    /// 仅推送失败交易（例如统计 PumpFun 上失败的狙击交易以估计竞争）
+    Only,
+}
+
+impl FailedTransactions {
+    /// 转换为 Geyser 的 failed 字段：None 表示不按成功 / 失败过滤
+    pub fn as_filter(&self) -> Option<bool> {
+        match self {
+            Self::Exclude => Some(false),
+            Self::Include => None,
+            Self::Only => Some(true),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub enum EventPretty {
+    Block(BlockPretty),
     BlockMeta(BlockMetaPretty),
     Transaction(TransactionPretty),
     Account(AccountPretty),
+    Slot(SlotPretty),
+}
+
+impl EventPretty {
+    /// 读取器写入的阶段时间戳（Geyser 创建、接收）
+    pub fn timings(&self) -> EventTimings {
+        match self {
+            Self::Block(block) => block.timings,
+            Self::BlockMeta(block_meta) => block_meta.timings,
+            Self::Transaction(transaction) => transaction.timings,
+            Self::Account(account) => account.timings,
+            Self::Slot(slot) => slot.timings,
+        }
+    }
+
+    pub fn set_timings(&mut self, timings: EventTimings) {
+        match self {
+            Self::Block(block) => block.timings = timings,
+            Self::BlockMeta(block_meta) => block_meta.timings = timings,
+            Self::Transaction(transaction) => transaction.timings = timings,
+            Self::Account(account) => account.timings = timings,
+            Self::Slot(slot) => slot.timings = timings,
+        }
+    }
+
+    /// 命中的过滤器名称（SubscribeUpdate.filters），仅交易、账户与完整区块更新携带
+    pub fn filters(&self) -> &[String] {
+        match self {
+            Self::Block(block) => &block.filters,
+            Self::Transaction(transaction) => &transaction.filters,
+            Self::Account(account) => &account.filters,
+            Self::BlockMeta(_) | Self::Slot(_) => &[],
+        }
+    }
+
+    pub fn set_filters(&mut self, filters: Vec<String>) {
+        match self {
+            Self::Block(block) => block.filters = filters,
+            Self::Transaction(transaction) => transaction.filters = filters,
+            Self::Account(account) => account.filters = filters,
+            Self::BlockMeta(_) | Self::Slot(_) => {}
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -29,6 +137,9 @@ pub struct AccountPretty {
     pub owner: String,
     pub rent_epoch: u64,
     pub data: Vec<u8>,
+    pub timings: EventTimings,
+    /// 命中的账户过滤器名称
+    pub filters: Vec<String>,
 }
 
 impl fmt::Debug for AccountPretty {
@@ -42,6 +153,7 @@ impl fmt::Debug for AccountPretty {
             .field("owner", &self.owner)
             .field("rent_epoch", &self.rent_epoch)
             .field("data", &self.data)
+            .field("filters", &self.filters)
             .finish()
     }
 }
@@ -51,6 +163,7 @@ pub struct BlockMetaPretty {
     pub slot: u64,
     pub block_hash: String,
     pub block_time: Option<Timestamp>,
+    pub timings: EventTimings,
 }
 
 impl fmt::Debug for BlockMetaPretty {
@@ -63,6 +176,110 @@ impl fmt::Debug for BlockMetaPretty {
     }
 }
 
+/// 完整区块（含交易，不含账户与 entries）
+#[derive(Clone)]
+pub struct BlockPretty {
+    pub slot: u64,
+    pub parent_slot: u64,
+    pub block_hash: String,
+    pub parent_block_hash: String,
+    pub block_time: Option<Timestamp>,
+    pub block_height: Option<u64>,
+    /// 区块内全部交易（含投票交易），按区块内序号 index 排序
+    pub transactions: Vec<SubscribeUpdateTransactionInfo>,
+    pub timings: EventTimings,
+    /// 命中的区块过滤器名称
+    pub filters: Vec<String>,
+}
+
+impl fmt::Debug for BlockPretty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockPretty")
+            .field("slot", &self.slot)
+            .field("parent_slot", &self.parent_slot)
+            .field("block_hash", &self.block_hash)
+            .field("block_time", &self.block_time)
+            .field("block_height", &self.block_height)
+            .field("transactions", &self.transactions.len())
+            .field("filters", &self.filters)
+            .finish()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SlotPretty {
+    pub slot: u64,
+    pub parent: Option<u64>,
+    pub status: SlotStatus,
+    pub dead_error: Option<String>,
+    pub created_at: Option<Timestamp>,
+    pub timings: EventTimings,
+}
+
+impl SlotPretty {
+    /// 仅转换 processed/confirmed/finalized/dead，其余中间状态返回 None
+    pub fn from_update(update: SubscribeUpdateSlot, created_at: Option<Timestamp>) -> Option<Self> {
+        let status = SlotStatus::from_geyser(update.status)?;
+        Some(Self {
+            slot: update.slot,
+            parent: update.parent,
+            status,
+            dead_error: update.dead_error,
+            created_at,
+            timings: EventTimings::default(),
+        })
+    }
+}
+
+/// 交易内容：Geyser 直接给出 protobuf，回放 / RPC 来源给出 UI 编码
+#[derive(Clone)]
+pub enum TransactionPayload {
+    /// 原始 protobuf（快速路径：直接读取指令字节与公钥字节）
+    Proto(SubscribeUpdateTransactionInfo),
+    /// UI 编码（回放与 RPC 来源）
+    Encoded(EncodedTransactionWithStatusMeta),
+}
+
+impl TransactionPayload {
+    /// 是否带有 meta
+    pub fn has_meta(&self) -> bool {
+        match self {
+            Self::Proto(info) => info.meta.is_some(),
+            Self::Encoded(tx) => tx.meta.is_some(),
+        }
+    }
+
+    /// 交易是否执行失败（缺少 meta 时视为未失败）
+    pub fn is_failed(&self) -> bool {
+        match self {
+            Self::Proto(info) => info.meta.as_ref().is_some_and(|meta| meta.err.is_some()),
+            Self::Encoded(tx) => tx.meta.as_ref().is_some_and(|meta| meta.err.is_some()),
+        }
+    }
+
+    /// 解码为 VersionedTransaction
+    pub fn versioned_transaction(&self) -> Option<VersionedTransaction> {
+        match self {
+            Self::Proto(info) => info
+                .transaction
+                .clone()
+                .and_then(|tx| yellowstone_grpc_proto::convert_from::create_tx_versioned(tx).ok()),
+            Self::Encoded(tx) => tx.transaction.decode(),
+        }
+    }
+
+    /// 转换为 UI 编码（仅用于展示 / 导出，热路径请勿调用）
+    pub fn to_encoded(&self) -> Option<EncodedTransactionWithStatusMeta> {
+        match self {
+            Self::Proto(info) => yellowstone_grpc_proto::convert_from::create_tx_with_meta(info.clone())
+                .ok()?
+                .encode(UiTransactionEncoding::Base64, Some(u8::MAX), true)
+                .ok(),
+            Self::Encoded(tx) => Some(tx.clone()),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct TransactionPretty {
     pub slot: u64,
@@ -70,16 +287,24 @@ pub struct TransactionPretty {
     pub block_time: Option<Timestamp>,
     pub signature: Signature,
     pub is_vote: bool,
-    pub tx: EncodedTransactionWithStatusMeta,
+    pub tx: TransactionPayload,
+    pub timings: EventTimings,
+    /// 命中的交易过滤器名称
+    pub filters: Vec<String>,
 }
 
 impl fmt::Debug for TransactionPretty {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        struct TxWrap<'a>(&'a EncodedTransactionWithStatusMeta);
+        struct TxWrap<'a>(&'a TransactionPayload);
         impl<'a> fmt::Debug for TxWrap<'a> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                let serialized = serde_json::to_string(self.0).expect("failed to serialize");
-                fmt::Display::fmt(&serialized, f)
+                match self.0 {
+                    TransactionPayload::Proto(info) => fmt::Debug::fmt(info, f),
+                    TransactionPayload::Encoded(tx) => {
+                        let serialized = serde_json::to_string(tx).expect("failed to serialize");
+                        fmt::Display::fmt(&serialized, f)
+                    }
+                }
             }
         }
 
@@ -87,11 +312,29 @@ impl fmt::Debug for TransactionPretty {
             .field("slot", &self.slot)
             .field("signature", &self.signature)
             .field("is_vote", &self.is_vote)
+            .field("filters", &self.filters)
             .field("tx", &TxWrap(&self.tx))
             .finish()
     }
 }
 
+impl TransactionPretty {
+    /// 由 RPC getTransaction 结果构造（RPC 回退源 / 历史回填）
+    pub fn from_rpc(signature: Signature, confirmed: EncodedConfirmedTransactionWithStatusMeta) -> Self {
+        Self {
+            slot: confirmed.slot,
+            block_hash: "".to_string(),
+            block_time: confirmed.block_time.map(|seconds| Timestamp { seconds, nanos: 0 }),
+            signature,
+            is_vote: false,
+            tx: TransactionPayload::Encoded(confirmed.transaction),
+            // RPC 来源没有 Geyser 创建时间
+            timings: EventTimings::received(0),
+            filters: Vec::new(),
+        }
+    }
+}
+
 impl From<SubscribeUpdateAccount> for AccountPretty {
     fn from(account: SubscribeUpdateAccount) -> Self {
         let account_info = account.account.unwrap();
@@ -104,6 +347,8 @@ impl From<SubscribeUpdateAccount> for AccountPretty {
             owner: bs58::encode(&account_info.owner).into_string(),
             rent_epoch: account_info.rent_epoch,
             data: account_info.data,
+            timings: EventTimings::default(),
+            filters: Vec::new(),
         }
     }
 }
@@ -115,7 +360,25 @@ impl From<(SubscribeUpdateBlockMeta, Option<Timestamp>)> for BlockMetaPretty {
             Option<Timestamp>,
         ),
     ) -> Self {
-        Self { block_hash: blockhash.to_string(), block_time, slot }
+        Self { block_hash: blockhash.to_string(), block_time, slot, timings: EventTimings::default() }
+    }
+}
+
+impl From<SubscribeUpdateBlock> for BlockPretty {
+    fn from(block: SubscribeUpdateBlock) -> Self {
+        let mut transactions = block.transactions;
+        transactions.sort_by_key(|transaction| transaction.index);
+        Self {
+            slot: block.slot,
+            parent_slot: block.parent_slot,
+            block_hash: block.blockhash,
+            parent_block_hash: block.parent_blockhash,
+            block_time: block.block_time.map(|ts| Timestamp { seconds: ts.timestamp, nanos: 0 }),
+            block_height: block.block_height.map(|height| height.block_height),
+            transactions,
+            timings: EventTimings::default(),
+            filters: Vec::new(),
+        }
     }
 }
 
@@ -133,10 +396,10 @@ impl From<(SubscribeUpdateTransaction, Option<Timestamp>)> for TransactionPretty
             block_hash: "".to_string(),
             signature: Signature::try_from(tx.signature.as_slice()).expect("valid signature"),
             is_vote: tx.is_vote,
-            tx: yellowstone_grpc_proto::convert_from::create_tx_with_meta(tx)
-                .expect("valid tx with meta")
-                .encode(UiTransactionEncoding::Base64, Some(u8::MAX), true)
-                .expect("failed to encode"),
+            // 保留 protobuf，不再转换为 UI 编码
+            tx: TransactionPayload::Proto(tx),
+            timings: EventTimings::default(),
+            filters: Vec::new(),
         }
     }
 }
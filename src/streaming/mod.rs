@@ -3,6 +3,16 @@ pub mod events;
 pub mod grpc;
 pub mod yellowstone;
 pub mod subsystem;
+pub mod source;
+pub mod backfill;
+pub mod bus;
+pub mod blocks;
 
-pub use yellowstone::YellowstoneGrpc;
-pub use subsystem::{SystemEvent, TransferInfo};
\ No newline at end of file
+pub use yellowstone::{SubscriptionInfo, YellowstoneGrpc};
+pub use subsystem::{
+    parse_system_events, AccountCreated, InstructionContext, SolTransfer, SystemEvent, TokenTransfer,
+};
+pub use bus::{BusSubscription, EventBus, SubscriberStats};
+pub use blocks::BlockEvents;
+pub use backfill::{Backfill, BackfillRange, BackfillStats};
+pub use source::{CaptureSource, EventSource, RpcWebSocketSource, YellowstoneSource};
\ No newline at end of file
@@ -0,0 +1,365 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use log::{info, warn};
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::rpc_config::{RpcTransactionLogsConfig, RpcTransactionLogsFilter};
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use yellowstone_grpc_proto::geyser::SubscribeRequest;
+
+use crate::common::AnyResult;
+use crate::core::client::RPCClient;
+use crate::globals::constants::*;
+use crate::streaming::common::{BackpressureStrategy, ReconnectConfig};
+use crate::streaming::grpc::{
+    capture::{CaptureReplay, ReplayPacing},
+    control::SubscriptionController,
+    streamhandler::StreamHandler,
+    types::{EventPretty, TransactionPretty},
+};
+use crate::streaming::yellowstone::YellowstoneGrpc;
+
+/// getTransaction 在日志通知后可能尚不可查，按固定间隔重试
+const RPC_TX_FETCH_ATTEMPTS: usize = 5;
+const RPC_TX_FETCH_RETRY_MS: u64 = 200;
+/// 同时进行中的 getTransaction 上限（超出时读取日志通知的循环等待）
+const RPC_TX_FETCH_IN_FLIGHT: usize = 64;
+
+/// 事件源：产生 EventPretty 并写入通道，下游（EventProcessor → callback）与来源无关
+#[async_trait]
+pub trait EventSource: Send + Sync {
+    /// 数据源名称（日志 / 指标用）
+    fn name(&self) -> &str;
+
+    /// 启动数据源；返回的任务结束即表示数据源结束（通道随之释放）
+    async fn start(&self, tx: mpsc::Sender<EventPretty>) -> AnyResult<JoinHandle<()>>;
+}
+
+/// Yellowstone gRPC 事件源（含重连、多端点汇聚与运行时过滤器更新）
+pub struct YellowstoneSource {
+    client: YellowstoneGrpc,
+    controller: SubscriptionController,
+}
+
+impl YellowstoneSource {
+    pub fn new(client: YellowstoneGrpc, request: SubscribeRequest) -> Self {
+        Self { client, controller: SubscriptionController::new(request) }
+    }
+
+    /// 运行时订阅控制器
+    pub fn controller(&self) -> &SubscriptionController {
+        &self.controller
+    }
+}
+
+#[async_trait]
+impl EventSource for YellowstoneSource {
+    fn name(&self) -> &str {
+        "yellowstone"
+    }
+
+    async fn start(&self, tx: mpsc::Sender<EventPretty>) -> AnyResult<JoinHandle<()>> {
+        self.client
+            .spawn_stream_reader(&self.controller, tx, self.client.config.backpressure.strategy)
+            .await
+    }
+}
+
+/// 抓包文件回放事件源
+pub struct CaptureSource {
+    path: String,
+    pacing: ReplayPacing,
+    dedup_capacity: usize,
+    backpressure_strategy: BackpressureStrategy,
+}
+
+impl CaptureSource {
+    pub fn new(path: impl Into<String>, pacing: ReplayPacing) -> Self {
+        Self {
+            path: path.into(),
+            pacing,
+            dedup_capacity: DEFDEDUPCAPACITY,
+            backpressure_strategy: BackpressureStrategy::Block,
+        }
+    }
+
+    pub fn with_dedup(mut self, capacity: usize) -> Self {
+        self.dedup_capacity = capacity;
+        self
+    }
+
+    pub fn with_backpressure(mut self, backpressure_strategy: BackpressureStrategy) -> Self {
+        self.backpressure_strategy = backpressure_strategy;
+        self
+    }
+}
+
+#[async_trait]
+impl EventSource for CaptureSource {
+    fn name(&self) -> &str {
+        "capture"
+    }
+
+    async fn start(&self, tx: mpsc::Sender<EventPretty>) -> AnyResult<JoinHandle<()>> {
+        let replay = CaptureReplay::new(self.path.clone(), self.pacing).with_dedup(self.dedup_capacity);
+        let backpressure_strategy = self.backpressure_strategy;
+        let path = self.path.clone();
+        Ok(tokio::spawn(async move {
+            match replay.run(tx, backpressure_strategy).await {
+                Ok(stats) => info!(
+                    "Replayed {} frames ({} duplicates) from {path}",
+                    stats.frames, stats.duplicates
+                ),
+                Err(e) => warn!("Capture replay of {path} failed: {e:?}"),
+            }
+        }))
+    }
+}
+
+/// Solana RPC WebSocket 回退事件源：按程序 logsSubscribe，再用 getTransaction 取完整交易。
+/// 延迟高于 Geyser（至少 confirmed），用于 Geyser 不可用时继续运行或对接本地验证器
+#[derive(Clone)]
+pub struct RpcWebSocketSource {
+    ws_url: String,
+    rpc: Arc<RPCClient>,
+    programs: Vec<Pubkey>,
+    include_failed: bool,
+    reconnect: ReconnectConfig,
+    backpressure_strategy: BackpressureStrategy,
+}
+
+impl RpcWebSocketSource {
+    pub fn new(ws_url: impl Into<String>, rpc: Arc<RPCClient>, programs: Vec<Pubkey>) -> Self {
+        Self {
+            ws_url: ws_url.into(),
+            rpc,
+            programs,
+            include_failed: false,
+            reconnect: ReconnectConfig::default(),
+            backpressure_strategy: BackpressureStrategy::default(),
+        }
+    }
+
+    /// 是否包含执行失败的交易（默认与 Yellowstone 过滤器一致：不包含）
+    pub fn with_failed(mut self, include_failed: bool) -> Self {
+        self.include_failed = include_failed;
+        self
+    }
+
+    pub fn with_reconnect(mut self, reconnect: ReconnectConfig) -> Self {
+        self.reconnect = reconnect;
+        self
+    }
+
+    pub fn with_backpressure(mut self, backpressure_strategy: BackpressureStrategy) -> Self {
+        self.backpressure_strategy = backpressure_strategy;
+        self
+    }
+
+    /// 断线后按退避重连，直到下游通道关闭
+    async fn run(self, tx: mpsc::Sender<EventPretty>) {
+        let mut seen = SignatureWindow::new(self.reconnect.dedup_capacity);
+        let mut backoff_ms = self.reconnect.initial_backoff_ms;
+
+        loop {
+            match self.stream_logs(&tx, &mut seen).await {
+                Ok(true) => {
+                    warn!("RPC log subscription ended, reconnecting");
+                    backoff_ms = self.reconnect.initial_backoff_ms;
+                }
+                Ok(false) => warn!("RPC log subscription ended before any notification"),
+                Err(e) => warn!("RPC log subscription failed: {e:?}"),
+            }
+            if tx.is_closed() || !self.reconnect.enabled {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+            backoff_ms = self.reconnect.next_backoff_ms(backoff_ms);
+        }
+    }
+
+    /// 单次连接：订阅全部程序的日志，直到任一流结束；返回是否收到过通知
+    async fn stream_logs(
+        &self,
+        tx: &mpsc::Sender<EventPretty>,
+        seen: &mut SignatureWindow,
+    ) -> AnyResult<bool> {
+        let client = PubsubClient::new(&self.ws_url).await?;
+        let mut streams = Vec::with_capacity(self.programs.len());
+        for program in &self.programs {
+            // Mentions 过滤器每次只接受一个地址
+            let (stream, _unsubscribe) = client
+                .logs_subscribe(
+                    RpcTransactionLogsFilter::Mentions(vec![program.to_string()]),
+                    RpcTransactionLogsConfig { commitment: Some(CommitmentConfig::confirmed()) },
+                )
+                .await?;
+            streams.push(stream);
+        }
+        info!("RPC log subscription established for {} programs", streams.len());
+
+        // 获取并发进行，由单个转发任务按通知到达顺序依次推送
+        let (fetch_tx, mut fetch_rx) =
+            mpsc::channel::<JoinHandle<Option<TransactionPretty>>>(RPC_TX_FETCH_IN_FLIGHT);
+        let sender = tx.clone();
+        let backpressure_strategy = self.backpressure_strategy;
+        let forwarder = tokio::spawn(async move {
+            while let Some(fetch) = fetch_rx.recv().await {
+                if let Ok(Some(transaction_pretty)) = fetch.await {
+                    let _ = StreamHandler::handle_backpressure(
+                        &sender,
+                        EventPretty::Transaction(transaction_pretty),
+                        backpressure_strategy,
+                    )
+                    .await;
+                }
+            }
+        });
+
+        let mut notifications = futures::stream::select_all(streams);
+        let mut received = false;
+        while let Some(response) = notifications.next().await {
+            received = true;
+            let logs = response.value;
+            if logs.err.is_some() && !self.include_failed {
+                continue;
+            }
+            let Ok(signature) = logs.signature.parse::<Signature>() else {
+                continue;
+            };
+            // 同一交易涉及多个程序时会收到多次通知
+            if !seen.insert(signature) {
+                continue;
+            }
+
+            let rpc = Arc::clone(&self.rpc);
+            let fetch = tokio::spawn(async move { fetch_transaction(&rpc, signature).await });
+            if fetch_tx.send(fetch).await.is_err() || tx.is_closed() {
+                break;
+            }
+        }
+
+        // 推送完已发起的获取后再返回，重连前后的交易保持顺序
+        drop(fetch_tx);
+        let _ = forwarder.await;
+        Ok(received)
+    }
+}
+
+#[async_trait]
+impl EventSource for RpcWebSocketSource {
+    fn name(&self) -> &str {
+        "rpc-websocket"
+    }
+
+    async fn start(&self, tx: mpsc::Sender<EventPretty>) -> AnyResult<JoinHandle<()>> {
+        Ok(tokio::spawn(self.clone().run(tx)))
+    }
+}
+
+/// 通过 getTransaction 获取完整交易；日志通知先于交易可查，失败时短暂重试
+async fn fetch_transaction(rpc: &RPCClient, signature: Signature) -> Option<TransactionPretty> {
+    for attempt in 1..=RPC_TX_FETCH_ATTEMPTS {
+        match rpc.gettransaction(&signature).await {
+            Ok(confirmed) => return Some(TransactionPretty::from_rpc(signature, confirmed)),
+            Err(e) if attempt == RPC_TX_FETCH_ATTEMPTS => {
+                warn!("Giving up on transaction {signature}: {e:?}");
+            }
+            Err(_) => tokio::time::sleep(Duration::from_millis(RPC_TX_FETCH_RETRY_MS)).await,
+        }
+    }
+    None
+}
+
+/// 有界签名去重窗口
+struct SignatureWindow {
+    seen: HashSet<Signature>,
+    order: VecDeque<Signature>,
+    capacity: usize,
+}
+
+impl SignatureWindow {
+    fn new(capacity: usize) -> Self {
+        Self { seen: HashSet::new(), order: VecDeque::new(), capacity: capacity.max(1) }
+    }
+
+    /// 首次出现返回 true
+    fn insert(&mut self, signature: Signature) -> bool {
+        if !self.seen.insert(signature) {
+            return false;
+        }
+        self.order.push_back(signature);
+        if self.order.len() > self.capacity
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.seen.remove(&oldest);
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::grpc::capture::CaptureRecorder;
+    use yellowstone_grpc_proto::geyser::{
+        subscribe_update::UpdateOneof, SlotStatus, SubscribeUpdate, SubscribeUpdateSlot,
+    };
+
+    #[test]
+    fn signature_window_dedups_and_forgets_oldest() {
+        let mut window = SignatureWindow::new(2);
+        let (a, b, c) =
+            (Signature::from([1; 64]), Signature::from([2; 64]), Signature::from([3; 64]));
+
+        assert!(window.insert(a));
+        assert!(!window.insert(a));
+        assert!(window.insert(b));
+        assert!(window.insert(c));
+
+        // 超出容量后最旧的签名被移出窗口
+        assert!(window.insert(a));
+        assert!(!window.insert(c));
+    }
+
+    #[tokio::test]
+    async fn capture_source_feeds_the_channel_until_done() {
+        let path =
+            std::env::temp_dir().join(format!("ghostreaver-source-{}.cap", std::process::id()));
+        let recorder = CaptureRecorder::create(&path, 1, 16).unwrap();
+        for slot in [5, 5, 6] {
+            recorder.record(&SubscribeUpdate {
+                update_oneof: Some(UpdateOneof::Slot(SubscribeUpdateSlot {
+                    slot,
+                    status: SlotStatus::SlotConfirmed as i32,
+                    ..Default::default()
+                })),
+                ..Default::default()
+            });
+        }
+        recorder.finish();
+
+        let source: Box<dyn EventSource> =
+            Box::new(CaptureSource::new(path.to_string_lossy(), ReplayPacing::AsFastAsPossible));
+        assert_eq!(source.name(), "capture");
+
+        let (tx, mut rx) = mpsc::channel(16);
+        source.start(tx).await.unwrap().await.unwrap();
+
+        let mut slots = Vec::new();
+        while let Some(event_pretty) = rx.recv().await {
+            if let EventPretty::Slot(slot) = event_pretty {
+                slots.push(slot.slot);
+            }
+        }
+        assert_eq!(slots, [5, 6]);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
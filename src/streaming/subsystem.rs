@@ -1,36 +1,115 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::str::FromStr;
+
 use crate::{
     common::AnyResult,
+    globals::pubkeys::system_pubkeys,
     streaming::{
-        common::BackpressureStrategy,
-        grpc::{types::EventPretty, streamhandler::StreamHandler},
+        common::{BackpressureStrategy, SubscriptionHandle},
+        grpc::{
+            control::SubscriptionController,
+            types::{EventPretty, TransactionPayload, TransactionPretty},
+        },
         yellowstone::YellowstoneGrpc,
     },
 };
-use futures::StreamExt;
 use log::error;
-use solana_program::pubkey;
-use solana_sdk::{pubkey::Pubkey, transaction::VersionedTransaction};
-use solana_transaction_status::EncodedTransactionWithStatusMeta;
+use solana_sdk::pubkey::Pubkey;
+use solana_transaction_status::{option_serializer::OptionSerializer, UiInstruction};
 use tokio::sync::mpsc;
 
-const SYSTEM_PROGRAM_ID: Pubkey = pubkey!("11111111111111111111111111111111");
 /// 根据实际并发量调整通道大小，避免背压
 const CHANNEL_SIZE: usize = 50_000;
 
+/// System Program 指令编号（bincode 编码的 u32 枚举序号）
+const SYSTEM_CREATE_ACCOUNT: u32 = 0;
+const SYSTEM_TRANSFER: u32 = 2;
+const SYSTEM_CREATE_ACCOUNT_WITH_SEED: u32 = 3;
+const SYSTEM_TRANSFER_WITH_SEED: u32 = 11;
+
+/// SPL Token / Token-2022 指令编号
+const TOKEN_TRANSFER: u8 = 3;
+const TOKEN_TRANSFER_CHECKED: u8 = 12;
+
+/// 订阅请求中的交易过滤器名称（三者为 OR 关系）
+const SYSTEM_FILTER_KEY: &str = "system";
+const TOKEN_FILTER_KEY: &str = "spl_token";
+const TOKEN_2022_FILTER_KEY: &str = "spl_token_2022";
+
 #[derive(Debug)]
 pub enum SystemEvent {
-    NewTransfer(TransferInfo),
+    /// System Program 原生 SOL 转账（Transfer / TransferWithSeed）
+    SolTransfer(SolTransfer),
+    /// SPL Token / Token-2022 转账（Transfer / TransferChecked）
+    TokenTransfer(TokenTransfer),
+    /// System Program 创建账户（CreateAccount / CreateAccountWithSeed）
+    AccountCreated(AccountCreated),
     Error(String),
 }
 
-#[derive(Clone, Debug, Default, PartialEq)]
-pub struct TransferInfo {
+/// 事件所在交易与指令位置
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InstructionContext {
     pub slot: u64,
     pub signature: String,
-    pub tx: Option<VersionedTransaction>,
+    /// 外层指令为 "2"，其内联指令为 "2.0"、"2.1"…
+    pub index: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SolTransfer {
+    pub context: InstructionContext,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub lamports: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TokenTransfer {
+    pub context: InstructionContext,
+    pub token_program: Pubkey,
+    /// 源 / 目标为代币账户；owner 取自交易的代币余额记录
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub source_owner: Option<Pubkey>,
+    pub destination_owner: Option<Pubkey>,
+    pub authority: Pubkey,
+    /// TransferChecked 直接给出；Transfer 由代币余额记录解析，无法解析时为 None
+    pub mint: Option<Pubkey>,
+    pub decimals: Option<u8>,
+    pub amount: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccountCreated {
+    pub context: InstructionContext,
+    pub funder: Pubkey,
+    pub account: Pubkey,
+    pub lamports: u64,
+    pub space: u64,
+    pub owner: Pubkey,
+}
+
+/// 代币账户信息（来自 pre / post token balances）
+#[derive(Clone, Copy)]
+struct TokenAccountInfo {
+    mint: Pubkey,
+    decimals: u8,
+    owner: Option<Pubkey>,
+}
+
+/// 与编码无关的指令视图
+struct RawInstruction<'a> {
+    index: String,
+    program_id_index: usize,
+    accounts: Cow<'a, [u8]>,
+    data: Cow<'a, [u8]>,
 }
 
 impl YellowstoneGrpc {
+    /// 订阅 System Program 与 SPL Token / Token-2022 相关的成功交易，
+    /// 解析外层与内联指令中的 SOL 转账、代币转账与账户创建；订阅在后台运行，通过 stop / shutdown 结束
     pub async fn subscribe_system<F>(
         &self,
         callback: F,
@@ -40,22 +119,31 @@ impl YellowstoneGrpc {
     where
         F: Fn(SystemEvent) + Send + Sync + 'static,
     {
-        let addrs = vec![SYSTEM_PROGRAM_ID.to_string()];
+        self.stop().await;
+
         let account_include = account_include.unwrap_or_default();
         let account_exclude = account_exclude.unwrap_or_default();
 
-        // 仅订阅与 System Program 相关且交易成功的事务
-        let transactions = self.subscription_manager.get_subscribe_request_filter(
-            account_include,
-            account_exclude,
-            addrs,
-            None,
-        );
+        // 每个程序一个命名过滤器：account_required 为该程序，仅推送成功交易
+        let mut transactions = HashMap::new();
+        for (key, program) in [
+            (SYSTEM_FILTER_KEY, system_pubkeys::SYSTEM),
+            (TOKEN_FILTER_KEY, system_pubkeys::TOKEN),
+            (TOKEN_2022_FILTER_KEY, system_pubkeys::TOKEN2022),
+        ] {
+            if let Some(entry) = self.subscription_manager.transaction_filter_entry(
+                account_include.clone(),
+                account_exclude.clone(),
+                vec![program.to_string()],
+                Some(false),
+                None,
+            ) {
+                transactions.insert(key.to_string(), entry);
+            }
+        }
 
-        let (mut subscribe_tx, mut stream) = self
-            .subscription_manager
-            .subscribe_with_request(transactions, None, None, None)
-            .await?;
+        let subscribe_request =
+            self.subscription_manager.build_subscribe_request(Some(transactions), None, None, None);
 
         // Tokio mpsc：更适合 Tokio 运行时；并支持 try_send / send
         let (tx, mut rx) = mpsc::channel::<EventPretty>(CHANNEL_SIZE);
@@ -63,39 +151,31 @@ impl YellowstoneGrpc {
         // 回调装箱，便于 move 进闭包
         let callback = Box::new(callback);
 
-        // 读取 Yellowstone 流并将事件按背压策略写入通道
-        let tx_for_task = tx;
-        tokio::spawn(async move {
-            // 使用 Retry 背压策略，避免在高峰期阻塞读取
-            let bp = BackpressureStrategy::Retry {
+        // 读取 Yellowstone 流并将事件按背压策略写入通道（断线自动重连）
+        // 使用 Retry 背压策略，避免在高峰期阻塞读取
+        let controller = SubscriptionController::new(subscribe_request);
+        let stream_handle = self.spawn_stream_reader(
+            &controller,
+            tx,
+            BackpressureStrategy::Retry {
                 max_attempts: 3,
                 wait_ms: 1,
-            };
+            },
+        )
+        .await?;
 
-            while let Some(message) = stream.next().await {
-                match message {
-                    Ok(msg) => {
-                        if let Err(e) =
-                            StreamHandler::handle_stream_message(msg, &tx_for_task, &mut subscribe_tx, bp).await
-                        {
-                            error!("Error handling message: {e:?}");
-                            break;
-                        }
-                    }
-                    Err(err) => {
-                        error!("Stream error: {err:?}");
-                        break;
-                    }
+        // 消费事件并执行业务处理（与其他 subscribe_* 一致，句柄登记后可 stop / shutdown）
+        let event_handle = tokio::spawn(async move {
+            while let Some(event_pretty) = rx.recv().await {
+                if let Err(e) = Self::process_system_transaction(event_pretty, &*callback).await {
+                    error!("Error processing transaction: {e:?}");
                 }
             }
         });
 
-        // 消费事件并执行业务处理
-        while let Some(event_pretty) = rx.recv().await {
-            if let Err(e) = Self::process_system_transaction(event_pretty, &*callback).await {
-                error!("Error processing transaction: {e:?}");
-            }
-        }
+        let subscription_handle =
+            SubscriptionHandle::new(stream_handle, event_handle, None).with_controller(controller);
+        *self.subscription_handle.lock().await = Some(subscription_handle);
 
         Ok(())
     }
@@ -106,25 +186,279 @@ impl YellowstoneGrpc {
     {
         match event_pretty {
             EventPretty::Transaction(transaction_pretty) => {
-                let trade_raw: EncodedTransactionWithStatusMeta = transaction_pretty.tx;
-                let meta = trade_raw
-                    .meta
-                    .as_ref()
-                    .ok_or_else(|| anyhow::anyhow!("Missing transaction metadata"))?;
+                let trade_raw = &transaction_pretty.tx;
+                if !trade_raw.has_meta() {
+                    return Err(anyhow::anyhow!("Missing transaction metadata"));
+                }
 
                 // 只处理成功交易
-                if meta.err.is_some() {
+                if trade_raw.is_failed() {
                     return Ok(());
                 }
 
-                callback(SystemEvent::NewTransfer(TransferInfo {
-                    slot: transaction_pretty.slot,
-                    signature: transaction_pretty.signature.to_string(),
-                    tx: trade_raw.transaction.decode(),
-                }));
+                for event in parse_system_events(&transaction_pretty) {
+                    callback(event);
+                }
             }
             _ => { /* ignore non-transaction events for this subsystem */ }
         }
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// 按执行顺序（外层指令后紧跟其内联指令）解析交易中的 SOL 转账、代币转账与账户创建
+pub fn parse_system_events(transaction_pretty: &TransactionPretty) -> Vec<SystemEvent> {
+    let signature = transaction_pretty.signature.to_string();
+    let context = |index: &str| InstructionContext {
+        slot: transaction_pretty.slot,
+        signature: signature.clone(),
+        index: index.to_string(),
+    };
+
+    let Some((accounts, instructions, token_accounts)) = decompose(&transaction_pretty.tx) else {
+        return Vec::new();
+    };
+
+    let mut events = Vec::new();
+    for instruction in &instructions {
+        let Some(program_id) = accounts.get(instruction.program_id_index) else {
+            continue;
+        };
+        let keys: Vec<Pubkey> = instruction
+            .accounts
+            .iter()
+            .map(|index| accounts.get(*index as usize).copied().unwrap_or_default())
+            .collect();
+
+        let event = if *program_id == system_pubkeys::SYSTEM {
+            parse_system_instruction(&instruction.data, &keys, context(&instruction.index))
+        } else if *program_id == system_pubkeys::TOKEN || *program_id == system_pubkeys::TOKEN2022 {
+            parse_token_instruction(
+                *program_id,
+                &instruction.data,
+                &instruction.accounts,
+                &keys,
+                &token_accounts,
+                context(&instruction.index),
+            )
+        } else {
+            None
+        };
+        events.extend(event);
+    }
+    events
+}
+
+/// 拆出账户表（静态 + 查找表，写在前只读在后）、按执行顺序排列的指令，以及代币账户信息
+#[allow(clippy::type_complexity)]
+fn decompose(
+    payload: &TransactionPayload,
+) -> Option<(Vec<Pubkey>, Vec<RawInstruction<'_>>, HashMap<usize, TokenAccountInfo>)> {
+    let mut accounts = Vec::new();
+    let mut instructions = Vec::new();
+    let mut token_accounts = HashMap::new();
+
+    match payload {
+        TransactionPayload::Proto(info) => {
+            let message = info.transaction.as_ref()?.message.as_ref()?;
+            let meta = info.meta.as_ref()?;
+            let to_pubkey = |key: &Vec<u8>| Pubkey::try_from(key.as_slice()).unwrap_or_default();
+            accounts.extend(message.account_keys.iter().map(to_pubkey));
+            accounts.extend(meta.loaded_writable_addresses.iter().map(to_pubkey));
+            accounts.extend(meta.loaded_readonly_addresses.iter().map(to_pubkey));
+
+            for (index, instruction) in message.instructions.iter().enumerate() {
+                instructions.push(RawInstruction {
+                    index: index.to_string(),
+                    program_id_index: instruction.program_id_index as usize,
+                    accounts: Cow::Borrowed(&instruction.accounts),
+                    data: Cow::Borrowed(&instruction.data),
+                });
+                for inner in meta.inner_instructions.iter().filter(|inner| inner.index as usize == index) {
+                    for (inner_index, instruction) in inner.instructions.iter().enumerate() {
+                        instructions.push(RawInstruction {
+                            index: format!("{index}.{inner_index}"),
+                            program_id_index: instruction.program_id_index as usize,
+                            accounts: Cow::Borrowed(&instruction.accounts),
+                            data: Cow::Borrowed(&instruction.data),
+                        });
+                    }
+                }
+            }
+
+            // post 覆盖 pre：交易内新建的代币账户只出现在 post 中
+            for balance in meta.pre_token_balances.iter().chain(meta.post_token_balances.iter()) {
+                let Ok(mint) = Pubkey::from_str(&balance.mint) else {
+                    continue;
+                };
+                token_accounts.insert(
+                    balance.account_index as usize,
+                    TokenAccountInfo {
+                        mint,
+                        decimals: balance.ui_token_amount.as_ref().map_or(0, |amount| amount.decimals as u8),
+                        owner: Pubkey::from_str(&balance.owner).ok(),
+                    },
+                );
+            }
+        }
+        TransactionPayload::Encoded(tx) => {
+            let meta = tx.meta.as_ref()?;
+            let versioned = tx.transaction.decode()?;
+            accounts.extend_from_slice(versioned.message.static_account_keys());
+            if let OptionSerializer::Some(loaded) = &meta.loaded_addresses {
+                let parse = |key: &String| Pubkey::from_str(key).unwrap_or_default();
+                accounts.extend(loaded.writable.iter().map(parse));
+                accounts.extend(loaded.readonly.iter().map(parse));
+            }
+
+            let inner_instructions = match &meta.inner_instructions {
+                OptionSerializer::Some(inner) => inner.as_slice(),
+                _ => &[],
+            };
+            for (index, instruction) in versioned.message.instructions().iter().enumerate() {
+                // 外层指令来自本函数内解码出的交易，只能复制
+                instructions.push(RawInstruction {
+                    index: index.to_string(),
+                    program_id_index: instruction.program_id_index as usize,
+                    accounts: Cow::Owned(instruction.accounts.clone()),
+                    data: Cow::Owned(instruction.data.clone()),
+                });
+                for inner in inner_instructions.iter().filter(|inner| inner.index as usize == index) {
+                    for (inner_index, instruction) in inner.instructions.iter().enumerate() {
+                        let UiInstruction::Compiled(compiled) = instruction else {
+                            continue;
+                        };
+                        let Ok(data) = bs58::decode(&compiled.data).into_vec() else {
+                            continue;
+                        };
+                        instructions.push(RawInstruction {
+                            index: format!("{index}.{inner_index}"),
+                            program_id_index: compiled.program_id_index as usize,
+                            accounts: Cow::Borrowed(&compiled.accounts),
+                            data: Cow::Owned(data),
+                        });
+                    }
+                }
+            }
+
+            let balances = [&meta.pre_token_balances, &meta.post_token_balances];
+            for balance in balances.into_iter().flat_map(|balances| match balances {
+                OptionSerializer::Some(balances) => balances.as_slice(),
+                _ => &[],
+            }) {
+                let Ok(mint) = Pubkey::from_str(&balance.mint) else {
+                    continue;
+                };
+                let owner = match &balance.owner {
+                    OptionSerializer::Some(owner) => Pubkey::from_str(owner).ok(),
+                    _ => None,
+                };
+                token_accounts.insert(
+                    balance.account_index as usize,
+                    TokenAccountInfo { mint, decimals: balance.ui_token_amount.decimals, owner },
+                );
+            }
+        }
+    }
+
+    Some((accounts, instructions, token_accounts))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?))
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Option<Pubkey> {
+    Pubkey::try_from(data.get(offset..offset + 32)?).ok()
+}
+
+/// bincode 字符串：u64 长度 + UTF-8 字节，返回字符串之后的偏移
+fn skip_string(data: &[u8], offset: usize) -> Option<usize> {
+    let len = usize::try_from(read_u64(data, offset)?).ok()?;
+    let end = offset.checked_add(8)?.checked_add(len)?;
+    (end <= data.len()).then_some(end)
+}
+
+fn parse_system_instruction(data: &[u8], keys: &[Pubkey], context: InstructionContext) -> Option<SystemEvent> {
+    match read_u32(data, 0)? {
+        SYSTEM_TRANSFER => Some(SystemEvent::SolTransfer(SolTransfer {
+            context,
+            from: *keys.first()?,
+            to: *keys.get(1)?,
+            lamports: read_u64(data, 4)?,
+        })),
+        // TransferWithSeed { lamports, from_seed, from_owner }：账户为 [from, base, to]
+        SYSTEM_TRANSFER_WITH_SEED => Some(SystemEvent::SolTransfer(SolTransfer {
+            context,
+            from: *keys.first()?,
+            to: *keys.get(2)?,
+            lamports: read_u64(data, 4)?,
+        })),
+        SYSTEM_CREATE_ACCOUNT => Some(SystemEvent::AccountCreated(AccountCreated {
+            context,
+            funder: *keys.first()?,
+            account: *keys.get(1)?,
+            lamports: read_u64(data, 4)?,
+            space: read_u64(data, 12)?,
+            owner: read_pubkey(data, 20)?,
+        })),
+        // CreateAccountWithSeed { base, seed, lamports, space, owner }
+        SYSTEM_CREATE_ACCOUNT_WITH_SEED => {
+            let offset = skip_string(data, 4 + 32)?;
+            Some(SystemEvent::AccountCreated(AccountCreated {
+                context,
+                funder: *keys.first()?,
+                account: *keys.get(1)?,
+                lamports: read_u64(data, offset)?,
+                space: read_u64(data, offset + 8)?,
+                owner: read_pubkey(data, offset + 16)?,
+            }))
+        }
+        _ => None,
+    }
+}
+
+fn parse_token_instruction(
+    token_program: Pubkey,
+    data: &[u8],
+    account_indexes: &[u8],
+    keys: &[Pubkey],
+    token_accounts: &HashMap<usize, TokenAccountInfo>,
+    context: InstructionContext,
+) -> Option<SystemEvent> {
+    let token_account = |position: usize| {
+        account_indexes.get(position).and_then(|index| token_accounts.get(&(*index as usize)))
+    };
+
+    let (source, destination, authority, amount, checked) = match *data.first()? {
+        // Transfer { amount }：账户为 [source, destination, authority]
+        TOKEN_TRANSFER => (0, 1, 2, read_u64(data, 1)?, None),
+        // TransferChecked { amount, decimals }：账户为 [source, mint, destination, authority]
+        TOKEN_TRANSFER_CHECKED => (0, 2, 3, read_u64(data, 1)?, Some((*keys.get(1)?, *data.get(9)?))),
+        _ => return None,
+    };
+
+    // 未显式给出 mint 时取源或目标代币账户的余额记录
+    let resolved = token_account(source).or_else(|| token_account(destination));
+    let (mint, decimals) = match checked {
+        Some((mint, decimals)) => (Some(mint), Some(decimals)),
+        None => (resolved.map(|info| info.mint), resolved.map(|info| info.decimals)),
+    };
+
+    Some(SystemEvent::TokenTransfer(TokenTransfer {
+        context,
+        token_program,
+        source: *keys.get(source)?,
+        destination: *keys.get(destination)?,
+        source_owner: token_account(source).and_then(|info| info.owner),
+        destination_owner: token_account(destination).and_then(|info| info.owner),
+        authority: *keys.get(authority)?,
+        mint,
+        decimals,
+        amount,
+    }))
+}
@@ -1,34 +1,183 @@
-use log::error;
+use anyhow::anyhow;
+use futures::future::{BoxFuture, FutureExt};
+use futures::StreamExt;
+use log::{error, warn};
 use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::{mpsc, Mutex, Semaphore};
-use yellowstone_grpc_proto::geyser::CommitmentLevel;
-
-use futures::StreamExt; // for .next()
+use tokio::task::JoinHandle;
+use yellowstone_grpc_proto::geyser::{CommitmentLevel, SubscribeRequest};
 
 use crate::common::AnyResult;
 use crate::streaming::common::{
-    EventBatchProcessor, MetricsManager, PerformanceMetrics, StreamClientConfig, SubscriptionHandle,
+    BackpressureStrategy, EventBatchProcessor, EventStream, MetricsManager, PerformanceMetrics,
+    PrometheusExporter, StreamClientConfig, StreamHealth, SubscriptionHandle,
 };
 use crate::globals::constants::*;
 use crate::streaming::events::common::filter::EventTypeFilter;
+use crate::streaming::events::common::EventType;
+use crate::streaming::events::core::eventparser::AccountEventParser;
+use crate::streaming::events::protocols::raydiumamm::types::AMM_INFO_SIZE;
+use crate::streaming::events::protocols::DynamicIdlParser;
 use crate::streaming::events::{Protocol, UnifiedEvent};
+use crate::streaming::source::EventSource;
 use crate::streaming::grpc::{
+    capture::{CaptureRecorder, CaptureReplay, ReplayPacing, ReplayStats},
+    control::{SubscriptionController, ACCOUNTS_FILTER_KEY, TRANSACTIONS_FILTER_KEY},
+    fanin::{EndpointStats, FanInDeduper},
+    priority::{CriticalMatcher, PriorityGate},
     processor::EventProcessor,
     streamhandler::StreamHandler,
     subscription::SubscriptionManager,
-    types::EventPretty,
+    supervisor::StreamSupervisor,
+    types::{
+        AccountDataFilter, AccountsFilterMap, EventPretty, FailedTransactions, TransactionsFilterMap,
+    },
 };
 
+/// 同步批处理回调产出、等待按背压策略发送的事件批次
+type ReadyBatches = Arc<std::sync::Mutex<Vec<Vec<Box<dyn UnifiedEvent>>>>>;
+
+#[derive(Debug, Clone, Default)]
 pub struct TransactionFilter {
+    /// 过滤器名称，随命中的更新写入事件（UnifiedEvent::filters）；
+    /// 为空时主过滤器使用 TRANSACTIONS_FILTER_KEY，附加过滤器依次编号。
+    /// 运行时控制器只调整名为 TRANSACTIONS_FILTER_KEY 的过滤器
+    pub name: String,
     pub account_include: Vec<String>,
     pub account_exclude: Vec<String>,
     pub account_required: Vec<String>,
+    /// 失败交易的处理方式，默认仅推送成功交易
+    pub failed: FailedTransactions,
+    /// 附加的交易过滤器，与本过滤器为 OR 关系，各自作为独立条目下发
+    pub additional: Vec<TransactionFilter>,
 }
 
+impl TransactionFilter {
+    /// 命名过滤器，例如 `TransactionFilter::named("pumpfun_failed").with_failed(FailedTransactions::Only)`
+    pub fn named(name: impl Into<String>) -> Self {
+        Self { name: name.into(), ..Default::default() }
+    }
+
+    pub fn with_account_include(mut self, accounts: Vec<String>) -> Self {
+        self.account_include = accounts;
+        self
+    }
+
+    pub fn with_account_exclude(mut self, accounts: Vec<String>) -> Self {
+        self.account_exclude = accounts;
+        self
+    }
+
+    pub fn with_account_required(mut self, accounts: Vec<String>) -> Self {
+        self.account_required = accounts;
+        self
+    }
+
+    pub fn with_failed(mut self, failed: FailedTransactions) -> Self {
+        self.failed = failed;
+        self
+    }
+
+    /// 与另一个过滤器组合（OR）；同一笔交易命中多个过滤器时只推送一次，filters 中包含全部名称
+    pub fn or(mut self, other: TransactionFilter) -> Self {
+        self.additional.push(other);
+        self
+    }
+
+    /// 展开为独立的过滤器列表（本过滤器在前）
+    fn flatten(mut self) -> Vec<TransactionFilter> {
+        let additional = std::mem::take(&mut self.additional);
+        let mut out = vec![self];
+        out.extend(additional.into_iter().flat_map(Self::flatten));
+        out
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct AccountFilter {
     pub account: Vec<String>,
     pub owner: Vec<String>,
+    /// memcmp / datasize 条件（AND），为空时推送 owner 下的全部账户
+    pub filters: Vec<AccountDataFilter>,
+    /// Some(true) 时仅推送由交易引起的账户更新（带交易签名）
+    pub nonempty_txn_signature: Option<bool>,
+    /// 附加的账户过滤器，与本过滤器为 OR 关系，各自作为独立条目下发
+    pub additional: Vec<AccountFilter>,
+}
+
+impl AccountFilter {
+    /// 指定程序下数据以 discriminator 开头的账户
+    pub fn discriminated(program_id: &Pubkey, discriminator: &[u8]) -> Self {
+        Self {
+            owner: vec![program_id.to_string()],
+            filters: vec![AccountDataFilter::Memcmp { offset: 0, bytes: discriminator.to_vec() }],
+            ..Default::default()
+        }
+    }
+
+    /// 按账户事件类型构建过滤器（程序 ID 与 discriminator 取自各协议的 discriminators 模块），
+    /// 例如只订阅 PumpSwap Pool：`AccountFilter::for_account_events(&[EventType::AccountPumpSwapPool])`。
+    /// 多个类型以 OR 组合；不含任何账户事件类型时返回 None
+    pub fn for_account_events(event_types: &[EventType]) -> Option<Self> {
+        let configs = AccountEventParser::configs(
+            Protocol::ALL.to_vec(),
+            Some(EventTypeFilter { include: event_types.to_vec() }),
+        );
+        configs
+            .into_iter()
+            .map(|config| match config.event_type {
+                // AmmInfo 没有 discriminator（首字段为 status），按固定账户长度过滤
+                EventType::AccountRaydiumAmmV4AmmInfo => Self {
+                    owner: vec![config.program_id.to_string()],
+                    filters: vec![AccountDataFilter::Datasize(AMM_INFO_SIZE as u64)],
+                    ..Default::default()
+                },
+                _ => Self::discriminated(&config.program_id, config.account_discriminator),
+            })
+            .reduce(Self::or)
+    }
+
+    /// 追加 memcmp 条件（原始字节）
+    pub fn with_memcmp(mut self, offset: u64, bytes: impl Into<Vec<u8>>) -> Self {
+        self.filters.push(AccountDataFilter::Memcmp { offset, bytes: bytes.into() });
+        self
+    }
+
+    /// 追加 memcmp 条件（base58 编码）
+    pub fn with_memcmp_base58(mut self, offset: u64, base58: impl Into<String>) -> Self {
+        self.filters.push(AccountDataFilter::MemcmpBase58 { offset, base58: base58.into() });
+        self
+    }
+
+    /// 追加账户数据长度条件
+    pub fn with_datasize(mut self, size: u64) -> Self {
+        self.filters.push(AccountDataFilter::Datasize(size));
+        self
+    }
+
+    pub fn with_nonempty_txn_signature(mut self, nonempty: bool) -> Self {
+        self.nonempty_txn_signature = Some(nonempty);
+        self
+    }
+
+    /// 与另一个过滤器组合（OR）
+    pub fn or(mut self, other: AccountFilter) -> Self {
+        self.additional.push(other);
+        self
+    }
+
+    /// 展开为独立的过滤器列表（本过滤器在前）
+    fn flatten(mut self) -> Vec<AccountFilter> {
+        let additional = std::mem::take(&mut self.additional);
+        let mut out = vec![self];
+        out.extend(additional.into_iter().flat_map(Self::flatten));
+        out
+    }
 }
 
 pub struct YellowstoneGrpc {
@@ -41,6 +190,23 @@ pub struct YellowstoneGrpc {
     /// Event processor shared across tasks
     pub event_processor: Arc<EventProcessor>,
     pub subscription_handle: Arc<Mutex<Option<SubscriptionHandle>>>,
+    /// Additional Geyser endpoints subscribed with the same filters (fan-in)
+    pub redundant_managers: Vec<SubscriptionManager>,
+    /// First-arrival dedup shared by all endpoints; None when only one endpoint is used
+    pub fanin: Option<Arc<FanInDeduper>>,
+    /// Raw SubscribeUpdate recorder; Some when config.capture.path is set
+    pub recorder: Option<Arc<CaptureRecorder>>,
+    /// Named subscriptions created via `named`, shared by the client and all of its clones
+    pub subscriptions: Arc<Mutex<HashMap<String, YellowstoneGrpc>>>,
+}
+
+/// 命名订阅的状态快照
+#[derive(Debug, Clone)]
+pub struct SubscriptionInfo {
+    pub name: String,
+    /// 订阅任务仍在运行
+    pub active: bool,
+    pub health: StreamHealth,
 }
 
 impl YellowstoneGrpc {
@@ -62,6 +228,7 @@ impl YellowstoneGrpc {
         let metrics_manager =
             MetricsManager::new(metrics.clone(), config_arc.clone(), "YellowstoneGrpc".to_string());
         let event_processor = Arc::new(EventProcessor::new(metrics_manager.clone(), config.clone()));
+        let recorder = CaptureRecorder::from_config(&config.capture)?.map(Arc::new);
 
         Ok(Self {
             endpoint,
@@ -72,9 +239,42 @@ impl YellowstoneGrpc {
             metrics_manager,
             event_processor,
             subscription_handle: Arc::new(Mutex::new(None)),
+            redundant_managers: Vec::new(),
+            fanin: None,
+            recorder,
+            subscriptions: Arc::new(Mutex::new(HashMap::new())),
         })
     }
 
+    /// 多端点冗余订阅：所有端点使用相同过滤器，更新按首达去重后汇入同一通道
+    pub fn new_redundant_with_config(
+        endpoints: Vec<(String, Option<String>)>,
+        config: StreamClientConfig,
+    ) -> AnyResult<Self> {
+        let mut endpoints = endpoints.into_iter();
+        let (endpoint, x_token) =
+            endpoints.next().ok_or_else(|| anyhow!("At least one Geyser endpoint is required"))?;
+        let mut client = Self::new_with_config(endpoint, x_token, config)?;
+
+        client.redundant_managers = endpoints
+            .map(|(endpoint, x_token)| {
+                SubscriptionManager::new(endpoint, x_token, client.config.clone())
+            })
+            .collect();
+
+        if !client.redundant_managers.is_empty() {
+            let names = std::iter::once(&client.subscription_manager)
+                .chain(client.redundant_managers.iter())
+                .map(|manager| manager.endpoint().to_string())
+                .collect();
+            client.fanin = Some(Arc::new(FanInDeduper::new(
+                names,
+                client.config.reconnect.dedup_capacity,
+            )));
+        }
+        Ok(client)
+    }
+
     pub fn new_high_performance(endpoint: String, x_token: Option<String>) -> AnyResult<Self> {
         Self::new_with_config(endpoint, x_token, StreamClientConfig::high_performance())
     }
@@ -103,12 +303,188 @@ impl YellowstoneGrpc {
 
     pub async fn print_metrics(&self) {
         self.metrics_manager.print_metrics().await;
+        if let Some(fanin) = &self.fanin {
+            fanin.print_stats();
+        }
+    }
+
+    /// 各端点的胜出率与延迟统计（仅多端点模式）
+    pub fn get_endpoint_stats(&self) -> Vec<EndpointStats> {
+        self.fanin.as_ref().map(|fanin| fanin.stats()).unwrap_or_default()
+    }
+
+    /// 注册运行时 IDL 解析器：该程序的指令（合并 emit_cpi 事件）与账户更新解码为 IdlEvent，
+    /// 与 protocols 指定的内置协议一并推送。订阅的 account_include / 账户过滤器需包含该程序 ID；
+    /// 须在订阅前调用，之后创建的命名订阅沿用已注册的解析器
+    pub fn with_idl_parser(mut self, parser: DynamicIdlParser) -> Self {
+        let mut idl_parsers = self.event_processor.idl_parsers().to_vec();
+        idl_parsers.push(Arc::new(parser));
+        self.event_processor = Arc::new(
+            EventProcessor::new(self.metrics_manager.clone(), self.config.clone())
+                .with_idl_parsers(idl_parsers),
+        );
+        self
     }
 
     pub fn set_enable_metrics(&mut self, enabled: bool) {
         self.config.enable_metrics = enabled;
     }
 
+    /// Prometheus 导出器（需配置 prometheus.bind 才会采集指标），可追加自定义采集项后 serve
+    pub fn prometheus_exporter(&self) -> PrometheusExporter {
+        PrometheusExporter::new(self.metrics_manager.clone())
+    }
+
+    /// 流健康状态：是否有读取器在线、距最后一条更新的时间、最近一次 pong 往返时间；
+    /// 超过 watchdog.idle_timeout_ms 既未收到更新也未收到 pong 即视为不健康
+    pub fn health(&self) -> StreamHealth {
+        self.metrics_manager.health()
+    }
+
+    /// 录制统计：(已写入帧数, 丢弃帧数)；未开启录制时为 None
+    pub fn capture_stats(&self) -> Option<(u64, u64)> {
+        self.recorder.as_ref().map(|recorder| (recorder.recorded(), recorder.dropped()))
+    }
+
+    /// 结束录制并写完抓包文件（进程退出前调用）
+    pub fn finish_capture(&self) {
+        if let Some(recorder) = &self.recorder {
+            recorder.finish();
+        }
+    }
+
+    /// 为给定请求创建带自动重连的读取器
+    pub fn supervisor(&self, subscribe_request: SubscribeRequest) -> StreamSupervisor {
+        self.supervisor_for(
+            &self.subscription_manager,
+            subscribe_request,
+            self.config.backpressure.strategy,
+        )
+    }
+
+    fn supervisor_for(
+        &self,
+        manager: &SubscriptionManager,
+        subscribe_request: SubscribeRequest,
+        backpressure_strategy: BackpressureStrategy,
+    ) -> StreamSupervisor {
+        StreamSupervisor::new(
+            manager.clone(),
+            subscribe_request,
+            self.config.reconnect.clone(),
+            self.metrics_manager.clone(),
+            backpressure_strategy,
+        )
+        .with_watchdog(self.config.watchdog.clone())
+        .with_recorder(self.recorder.clone())
+    }
+
+    /// 订阅并启动读取任务；多端点模式下所有端点的流在同一任务内汇聚。
+    /// 读取器监听 controller 的请求变更，并通过各自连接的 subscribe_tx 下发
+    pub async fn spawn_stream_reader(
+        &self,
+        controller: &SubscriptionController,
+        tx: mpsc::Sender<EventPretty>,
+        backpressure_strategy: BackpressureStrategy,
+    ) -> AnyResult<JoinHandle<()>> {
+        let control = controller.receiver();
+        let subscribe_request = control.borrow().clone();
+
+        let Some(fanin) = self.fanin.clone() else {
+            let (subscribe_tx, stream) =
+                self.subscription_manager.subscribe(subscribe_request.clone()).await?;
+            let supervisor = self
+                .supervisor_for(&self.subscription_manager, subscribe_request, backpressure_strategy)
+                .with_control(control);
+            return Ok(tokio::spawn(supervisor.run(subscribe_tx, stream, tx)));
+        };
+
+        let managers =
+            std::iter::once(&self.subscription_manager).chain(self.redundant_managers.iter());
+        let mut readers: Vec<BoxFuture<'static, ()>> = Vec::new();
+        let mut connected = 0usize;
+
+        for (index, manager) in managers.enumerate() {
+            let supervisor = self
+                .supervisor_for(manager, subscribe_request.clone(), backpressure_strategy)
+                .with_fanin(Arc::clone(&fanin), index)
+                .with_control(controller.receiver());
+            match manager.subscribe(subscribe_request.clone()).await {
+                Ok((subscribe_tx, stream)) => {
+                    connected += 1;
+                    readers.push(supervisor.run(subscribe_tx, stream, tx.clone()).boxed());
+                }
+                Err(e) => {
+                    // 端点暂不可用时交给重连流程，其余端点照常工作
+                    warn!("Endpoint {} failed to subscribe: {e:?}", manager.endpoint());
+                    readers.push(supervisor.connect_and_run(tx.clone()).boxed());
+                }
+            }
+        }
+
+        if connected == 0 {
+            return Err(anyhow!("All {} Geyser endpoints failed to subscribe", readers.len()));
+        }
+
+        Ok(tokio::spawn(async move {
+            futures::future::join_all(readers).await;
+        }))
+    }
+
+    /// 当前订阅的运行时控制器（未订阅时为 None）
+    pub async fn subscription_controller(&self) -> Option<SubscriptionController> {
+        let handle_guard = self.subscription_handle.lock().await;
+        handle_guard.as_ref().and_then(|handle| handle.controller().cloned())
+    }
+
+    /// 运行时添加账户公钥（不重连）
+    pub async fn add_accounts(&self, accounts: &[String]) -> bool {
+        match self.subscription_controller().await {
+            Some(controller) => controller.add_accounts(accounts),
+            None => false,
+        }
+    }
+
+    /// 运行时移除账户公钥（不重连）
+    pub async fn remove_accounts(&self, accounts: &[String]) -> bool {
+        match self.subscription_controller().await {
+            Some(controller) => controller.remove_accounts(accounts),
+            None => false,
+        }
+    }
+
+    /// 运行时添加账户 owner（不重连）
+    pub async fn add_owners(&self, owners: &[String]) -> bool {
+        match self.subscription_controller().await {
+            Some(controller) => controller.add_owners(owners),
+            None => false,
+        }
+    }
+
+    /// 运行时移除账户 owner（不重连）
+    pub async fn remove_owners(&self, owners: &[String]) -> bool {
+        match self.subscription_controller().await {
+            Some(controller) => controller.remove_owners(owners),
+            None => false,
+        }
+    }
+
+    /// 运行时添加交易 account_required（不重连）
+    pub async fn add_account_required(&self, accounts: &[String]) -> bool {
+        match self.subscription_controller().await {
+            Some(controller) => controller.add_account_required(accounts),
+            None => false,
+        }
+    }
+
+    /// 运行时移除交易 account_required（不重连）
+    pub async fn remove_account_required(&self, accounts: &[String]) -> bool {
+        match self.subscription_controller().await {
+            Some(controller) => controller.remove_account_required(accounts),
+            None => false,
+        }
+    }
+
     /// 停止当前订阅
     pub async fn stop(&self) {
         let mut handle_guard = self.subscription_handle.lock().await;
@@ -117,88 +493,239 @@ impl YellowstoneGrpc {
         }
     }
 
-    /// 简化：即时事件订阅（无批处理），带并发处理
-    pub async fn subscribe_events_immediate<F>(
+    /// 优雅停止当前订阅：关闭 gRPC 流，读取器释放通道发送端后，工作池在 deadline 内处理完
+    /// 通道中剩余的 EventPretty 并 flush 未满的批次。全部处理完成（或没有订阅）返回 true，
+    /// 超时则中止工作池并返回 false
+    pub async fn shutdown(&self, deadline: Duration) -> bool {
+        let handle = self.subscription_handle.lock().await.take();
+        match handle {
+            Some(handle) => handle.shutdown(deadline).await,
+            None => true,
+        }
+    }
+
+    /// 获取（不存在时创建）命名订阅。返回的客户端有独立的事件处理器、指标与订阅句柄，
+    /// 在其上调用任意 subscribe_* 即可与其他命名订阅并发运行，各自使用自己的过滤器、回调与 commitment；
+    /// 端点、配置与（开启 connection.share_connection 时的）gRPC 连接与本客户端共享。
+    /// 命名订阅不参与抓包录制
+    pub async fn named(&self, name: &str) -> YellowstoneGrpc {
+        let mut subscriptions = self.subscriptions.lock().await;
+        subscriptions.entry(name.to_string()).or_insert_with(|| self.child(name)).clone()
+    }
+
+    /// 构建命名子客户端
+    fn child(&self, name: &str) -> YellowstoneGrpc {
+        let metrics = Arc::new(Mutex::new(PerformanceMetrics::new()));
+        let metrics_manager =
+            MetricsManager::new(metrics.clone(), Arc::new(self.config.clone()), name.to_string());
+        let event_processor = Arc::new(
+            EventProcessor::new(metrics_manager.clone(), self.config.clone())
+                .with_idl_parsers(self.event_processor.idl_parsers().to_vec()),
+        );
+        // 去重窗口按订阅独立，否则同一笔交易命中两个订阅时第二个会被当作重复丢弃
+        let fanin = self.fanin.as_ref().map(|_| {
+            let names = std::iter::once(&self.subscription_manager)
+                .chain(self.redundant_managers.iter())
+                .map(|manager| manager.endpoint().to_string())
+                .collect();
+            Arc::new(FanInDeduper::new(names, self.config.reconnect.dedup_capacity))
+        });
+
+        YellowstoneGrpc {
+            endpoint: self.endpoint.clone(),
+            x_token: self.x_token.clone(),
+            config: self.config.clone(),
+            metrics,
+            subscription_manager: self.subscription_manager.clone(),
+            metrics_manager,
+            event_processor,
+            subscription_handle: Arc::new(Mutex::new(None)),
+            redundant_managers: self.redundant_managers.clone(),
+            fanin,
+            recorder: None,
+            subscriptions: Arc::clone(&self.subscriptions),
+        }
+    }
+
+    /// 查看命名订阅（可用于 get_metrics / health / 运行时调整过滤器）
+    pub async fn get_subscription(&self, name: &str) -> Option<YellowstoneGrpc> {
+        self.subscriptions.lock().await.get(name).cloned()
+    }
+
+    /// 已注册的命名订阅名称（按名称排序）
+    pub async fn subscription_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.subscriptions.lock().await.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// 全部命名订阅的状态（按名称排序）
+    pub async fn list_subscriptions(&self) -> Vec<SubscriptionInfo> {
+        let children: Vec<YellowstoneGrpc> =
+            self.subscriptions.lock().await.values().cloned().collect();
+        let mut infos = Vec::with_capacity(children.len());
+        for child in children {
+            infos.push(SubscriptionInfo {
+                name: child.metrics_manager.stream_name().to_string(),
+                active: child.is_active().await,
+                health: child.health(),
+            });
+        }
+        infos.sort_by(|a, b| a.name.cmp(&b.name));
+        infos
+    }
+
+    /// 当前订阅是否仍在运行
+    pub async fn is_active(&self) -> bool {
+        self.subscription_handle.lock().await.as_ref().is_some_and(|handle| handle.is_active())
+    }
+
+    /// 立即停止并注销命名订阅，其他订阅不受影响；不存在时返回 false
+    pub async fn stop_subscription(&self, name: &str) -> bool {
+        let child = self.subscriptions.lock().await.remove(name);
+        match child {
+            Some(child) => {
+                child.stop().await;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 优雅停止并注销命名订阅（见 shutdown）；不存在或已处理完返回 true
+    pub async fn shutdown_subscription(&self, name: &str, deadline: Duration) -> bool {
+        let child = self.subscriptions.lock().await.remove(name);
+        match child {
+            Some(child) => child.shutdown(deadline).await,
+            None => true,
+        }
+    }
+
+    /// 停止并注销全部命名订阅
+    pub async fn stop_all_subscriptions(&self) {
+        let children: Vec<YellowstoneGrpc> =
+            self.subscriptions.lock().await.drain().map(|(_, child)| child).collect();
+        for child in children {
+            child.stop().await;
+        }
+    }
+
+    /// 由交易 / 账户 / 事件类型过滤器构建订阅请求
+    fn build_event_request(
         &self,
-        protocols: Vec<Protocol>,
-        bot_wallet: Option<Pubkey>,
         transaction_filter: TransactionFilter,
         account_filter: AccountFilter,
         event_type_filter: Option<EventTypeFilter>,
         commitment: Option<CommitmentLevel>,
-        callback: F,
-    ) -> AnyResult<()>
-    where
-        F: Fn(Box<dyn UnifiedEvent>) + Send + Sync + 'static,
-    {
-        self.stop().await;
-
-        let mut metrics_handle = None;
-        if self.config.enable_metrics {
-            metrics_handle = self.metrics_manager.start_auto_monitoring().await;
+    ) -> SubscribeRequest {
+        let mut transactions: Option<TransactionsFilterMap> = None;
+        for (index, filter) in transaction_filter.flatten().into_iter().enumerate() {
+            let Some(entry) = self.subscription_manager.transaction_filter_entry(
+                filter.account_include,
+                filter.account_exclude,
+                filter.account_required,
+                filter.failed.as_filter(),
+                event_type_filter.clone(),
+            ) else {
+                continue;
+            };
+            // 未命名时主过滤器沿用 TRANSACTIONS_FILTER_KEY（运行时控制器据此增删账户），附加过滤器依次编号
+            let key = match (filter.name.is_empty(), index) {
+                (false, _) => filter.name,
+                (true, 0) => TRANSACTIONS_FILTER_KEY.to_string(),
+                (true, _) => format!("{TRANSACTIONS_FILTER_KEY}_{index}"),
+            };
+            let map = transactions.get_or_insert_with(HashMap::new);
+            if map.contains_key(&key) {
+                warn!("Duplicate transaction filter name {key:?}; the later filter replaces the earlier one");
+            }
+            map.insert(key, entry);
+        }
+        let mut accounts: Option<AccountsFilterMap> = None;
+        for (index, filter) in account_filter.flatten().into_iter().enumerate() {
+            let Some(entry) = self.subscription_manager.account_filter_entry(
+                filter.account,
+                filter.owner,
+                &filter.filters,
+                filter.nonempty_txn_signature,
+                event_type_filter.clone(),
+            ) else {
+                continue;
+            };
+            // 主过滤器沿用 ACCOUNTS_FILTER_KEY（运行时控制器据此增删账户），附加过滤器依次编号
+            let key = if index == 0 {
+                ACCOUNTS_FILTER_KEY.to_string()
+            } else {
+                format!("accounts_{index}")
+            };
+            accounts.get_or_insert_with(HashMap::new).insert(key, entry);
         }
 
-        let transactions = self.subscription_manager.get_subscribe_request_filter(
-            transaction_filter.account_include,
-            transaction_filter.account_exclude,
-            transaction_filter.account_required,
-            event_type_filter.clone(),
-        );
-        let accounts = self.subscription_manager.subscribe_with_account_request(
-            account_filter.account,
-            account_filter.owner,
-            event_type_filter.clone(),
-        );
+        self.subscription_manager.build_subscribe_request(
+            transactions,
+            accounts,
+            commitment,
+            event_type_filter,
+        )
+    }
 
-        let (mut subscribe_tx, mut stream) = self
-            .subscription_manager
-            .subscribe_with_request(transactions, accounts, commitment, event_type_filter.clone())
-            .await?;
+    /// 读取器 → 处理器通道；Priority 策略下读取器写入 PriorityGate 的入口，
+    /// 由闸门合并账户更新并按优先级丢弃后再转入处理器通道
+    fn event_channel(
+        &self,
+        protocols: &[Protocol],
+    ) -> (mpsc::Sender<EventPretty>, mpsc::Receiver<EventPretty>) {
+        let backpressure = &self.config.backpressure;
+        let (tx, rx) = mpsc::channel::<EventPretty>(backpressure.channel_size);
 
-        // Tokio mpsc 通道
-        let (tx, mut rx) = mpsc::channel::<EventPretty>(self.config.backpressure.channel_size);
-
-        // Yellowstone reader → channel (backpressure inside StreamHandler)
-        let bp = self.config.backpressure.strategy;
-        let stream_handle = tokio::spawn(async move {
-            while let Some(message) = stream.next().await {
-                match message {
-                    Ok(msg) => {
-                        if let Err(e) =
-                            StreamHandler::handle_stream_message(msg, &tx, &mut subscribe_tx, bp)
-                                .await
-                        {
-                            error!("Error handling message: {e:?}");
-                            break;
-                        }
-                    }
-                    Err(error) => {
-                        error!("Stream error: {error:?}");
-                        break;
-                    }
-                }
-            }
-        });
+        // 处理器通道深度探针（供 Prometheus 导出），只持有弱引用，不影响通道关闭
+        let probe_tx = tx.downgrade();
+        self.metrics_manager.set_channel_probe(Arc::new(move || {
+            probe_tx
+                .upgrade()
+                .map(|tx| (tx.max_capacity() - tx.capacity(), tx.max_capacity()))
+                .unwrap_or((0, 0))
+        }));
 
-        // 并发事件处理（工作池）
+        if !matches!(backpressure.strategy, BackpressureStrategy::Priority) {
+            return (tx, rx);
+        }
+        let matcher = CriticalMatcher::new(protocols, &backpressure.critical_event_types);
+        let gate = PriorityGate::new(backpressure.channel_size, matcher, self.metrics_manager.clone());
+        (gate.spawn(tx), rx)
+    }
+
+    /// 工作池并发度（processor_concurrency，默认 CPU 核数，上限 PROCMAXCONCURRENCYCAP）
+    fn processor_concurrency(&self) -> usize {
+        self.config
+            .processor_concurrency
+            .unwrap_or_else(num_cpus::get)
+            .clamp(1, PROCMAXCONCURRENCYCAP)
+    }
+
+    /// 并发事件处理工作池：从通道读取事件并交给 EventProcessor，处理完通道中全部事件后结束
+    fn spawn_event_workers<F>(
+        &self,
+        mut rx: mpsc::Receiver<EventPretty>,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        event_type_filter: Option<EventTypeFilter>,
+        callback: F,
+    ) -> JoinHandle<()>
+    where
+        F: Fn(Box<dyn UnifiedEvent>) + Send + Sync + 'static,
+    {
         let event_processor = Arc::clone(&self.event_processor);
         let cb_arc: Arc<F> = Arc::new(callback); // keep concrete F
         let protocols_arc = Arc::new(protocols);
-        let evt_filter_arc = event_type_filter.clone();
+        let evt_filter_arc = event_type_filter;
         let bot_wallet_arc = bot_wallet;
 
         // 并发度
-        let mut concurrency = self
-            .config
-            .processor_concurrency
-            .unwrap_or_else(num_cpus::get);
-        if concurrency == 0 {
-            concurrency = 1;
-        }
-        concurrency = std::cmp::min(concurrency, PROCMAXCONCURRENCYCAP);
+        let concurrency = self.processor_concurrency();
         let semaphore = Arc::new(Semaphore::new(concurrency));
 
-        let event_handle = tokio::spawn(async move {
+        tokio::spawn(async move {
             while let Some(event_pretty) = rx.recv().await {
                 let permit = match semaphore.clone().acquire_owned().await {
                     Ok(p) => p,
@@ -230,16 +757,273 @@ impl YellowstoneGrpc {
                     }
                 });
             }
-        });
+
+            // 等待仍在处理中的事件完成
+            let _ = semaphore.acquire_many(concurrency as u32).await;
+        })
+    }
+
+    /// 并发解析通道中的事件，按到达顺序产出每条消息解析出的事件列表
+    fn parsed_event_stream(
+        &self,
+        rx: mpsc::Receiver<EventPretty>,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        event_type_filter: Option<EventTypeFilter>,
+    ) -> impl futures::Stream<Item = Vec<Box<dyn UnifiedEvent>>> + Send + 'static {
+        let concurrency = self.processor_concurrency();
+        let event_processor = Arc::clone(&self.event_processor);
+        let protocols = Arc::new(protocols);
+        futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|event_pretty| (event_pretty, rx))
+        })
+        .map(move |event_pretty| {
+            let ep = Arc::clone(&event_processor);
+            let protocols = Arc::clone(&protocols);
+            let evt_filter = event_type_filter.clone();
+            async move {
+                let parsed = std::sync::Mutex::new(Vec::new());
+                let collect = |event: Box<dyn UnifiedEvent>| {
+                    if let Ok(mut parsed) = parsed.lock() {
+                        parsed.push(event);
+                    }
+                };
+                if let Err(e) = ep
+                    .process_event_transaction_with_metrics(
+                        event_pretty,
+                        &collect,
+                        bot_wallet,
+                        (*protocols).clone(),
+                        evt_filter,
+                    )
+                    .await
+                {
+                    error!("Error processing transaction: {e:?}");
+                }
+                parsed.into_inner().unwrap_or_default()
+            }
+        })
+        // 并发解析，按输入顺序产出
+        .buffered(concurrency)
+    }
+
+    /// 按键分片的异步工作池：解析并发进行但保持到达顺序，事件按 shard_key 分派到固定分片，
+    /// 同一分片内的回调依次 await；无键事件按签名分片（同一交易内的事件保持顺序）
+    fn spawn_sharded_workers<F, Fut, K>(
+        &self,
+        rx: mpsc::Receiver<EventPretty>,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        event_type_filter: Option<EventTypeFilter>,
+        shard_key: K,
+        callback: F,
+    ) -> JoinHandle<()>
+    where
+        F: Fn(Box<dyn UnifiedEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+        K: Fn(&dyn UnifiedEvent) -> Option<String> + Send + Sync + 'static,
+    {
+        let concurrency = self.processor_concurrency();
+        let callback = Arc::new(callback);
+
+        let mut shard_txs = Vec::with_capacity(concurrency);
+        let mut shard_handles = Vec::with_capacity(concurrency);
+        for _ in 0..concurrency {
+            let (shard_tx, mut shard_rx) = mpsc::channel::<Box<dyn UnifiedEvent>>(DEFSHARDCHANNELSIZE);
+            let cb = Arc::clone(&callback);
+            shard_handles.push(tokio::spawn(async move {
+                while let Some(event) = shard_rx.recv().await {
+                    cb(event).await;
+                }
+            }));
+            shard_txs.push(shard_tx);
+        }
+
+        let events = self.parsed_event_stream(rx, protocols, bot_wallet, event_type_filter);
+
+        tokio::spawn(async move {
+            futures::pin_mut!(events);
+            'dispatch: while let Some(parsed) = events.next().await {
+                for event in parsed {
+                    let key = shard_key(event.as_ref());
+                    let shard = shard_index(key.as_deref().unwrap_or(event.signature()), concurrency);
+                    // 分片繁忙时在此等待，压力经通道传回 StreamHandler 的背压策略
+                    if shard_txs[shard].send(event).await.is_err() {
+                        error!("Shard worker {shard} stopped");
+                        break 'dispatch;
+                    }
+                }
+            }
+
+            // 关闭分片通道并等待剩余回调完成
+            drop(shard_txs);
+            futures::future::join_all(shard_handles).await;
+        })
+    }
+
+    /// 简化：即时事件订阅（无批处理），带并发处理
+    pub async fn subscribe_events_immediate<F>(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: TransactionFilter,
+        account_filter: AccountFilter,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+        callback: F,
+    ) -> AnyResult<()>
+    where
+        F: Fn(Box<dyn UnifiedEvent>) + Send + Sync + 'static,
+    {
+        self.stop().await;
+
+        let mut metrics_handle = None;
+        if self.config.enable_metrics {
+            metrics_handle = self.metrics_manager.start_auto_monitoring().await;
+        }
+
+        let subscribe_request = self.build_event_request(
+            transaction_filter,
+            account_filter,
+            event_type_filter.clone(),
+            commitment,
+        );
+
+        // Tokio mpsc 通道
+        let (tx, rx) = self.event_channel(&protocols);
+
+        // Yellowstone reader → channel (backpressure inside StreamHandler, reconnect inside supervisor)
+        let controller = SubscriptionController::new(subscribe_request);
+        let stream_handle = self
+            .spawn_stream_reader(&controller, tx, self.config.backpressure.strategy)
+            .await?;
+
+        // 并发事件处理（工作池）
+        let event_handle = self.spawn_event_workers(rx, protocols, bot_wallet, event_type_filter, callback);
+
+        let subscription_handle =
+            SubscriptionHandle::new(stream_handle, event_handle, metrics_handle)
+                .with_controller(controller);
+        let mut handle_guard = self.subscription_handle.lock().await;
+        *handle_guard = Some(subscription_handle);
+
+        Ok(())
+    }
+
+    /// 即时事件订阅（异步回调）：同一 shard_key（如 mint / pool）的事件按到达顺序依次 await，
+    /// 不同键并发处理
+    #[allow(clippy::too_many_arguments)]
+    pub async fn subscribe_events_immediate_async<F, Fut, K>(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: TransactionFilter,
+        account_filter: AccountFilter,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+        shard_key: K,
+        callback: F,
+    ) -> AnyResult<()>
+    where
+        F: Fn(Box<dyn UnifiedEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+        K: Fn(&dyn UnifiedEvent) -> Option<String> + Send + Sync + 'static,
+    {
+        self.stop().await;
+
+        let mut metrics_handle = None;
+        if self.config.enable_metrics {
+            metrics_handle = self.metrics_manager.start_auto_monitoring().await;
+        }
+
+        let subscribe_request = self.build_event_request(
+            transaction_filter,
+            account_filter,
+            event_type_filter.clone(),
+            commitment,
+        );
+
+        let (tx, rx) = self.event_channel(&protocols);
+
+        let controller = SubscriptionController::new(subscribe_request);
+        let stream_handle = self
+            .spawn_stream_reader(&controller, tx, self.config.backpressure.strategy)
+            .await?;
+
+        let event_handle = self.spawn_sharded_workers(
+            rx,
+            protocols,
+            bot_wallet,
+            event_type_filter,
+            shard_key,
+            callback,
+        );
 
         let subscription_handle =
-            SubscriptionHandle::new(stream_handle, event_handle, metrics_handle);
+            SubscriptionHandle::new(stream_handle, event_handle, metrics_handle)
+                .with_controller(controller);
         let mut handle_guard = self.subscription_handle.lock().await;
         *handle_guard = Some(subscription_handle);
 
         Ok(())
     }
 
+    /// 在任意事件源（Yellowstone / 抓包回放 / RPC WebSocket）上运行同一事件处理工作池
+    pub async fn subscribe_events_from_source<S, F>(
+        &self,
+        source: &S,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        event_type_filter: Option<EventTypeFilter>,
+        callback: F,
+    ) -> AnyResult<()>
+    where
+        S: EventSource + ?Sized,
+        F: Fn(Box<dyn UnifiedEvent>) + Send + Sync + 'static,
+    {
+        self.stop().await;
+
+        let mut metrics_handle = None;
+        if self.config.enable_metrics {
+            metrics_handle = self.metrics_manager.start_auto_monitoring().await;
+        }
+
+        let (tx, rx) = self.event_channel(&protocols);
+        let stream_handle = source.start(tx).await?;
+        log::info!("Event source '{}' started", source.name());
+
+        let event_handle = self.spawn_event_workers(rx, protocols, bot_wallet, event_type_filter, callback);
+
+        let subscription_handle = SubscriptionHandle::new(stream_handle, event_handle, metrics_handle);
+        let mut handle_guard = self.subscription_handle.lock().await;
+        *handle_guard = Some(subscription_handle);
+
+        Ok(())
+    }
+
+    /// 回放抓包文件：更新经 StreamHandler → EventProcessor → callback，与实时订阅走相同路径。
+    /// 回放结束且全部事件处理完成后返回
+    pub async fn replay_capture<F>(
+        &self,
+        path: &str,
+        pacing: ReplayPacing,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        event_type_filter: Option<EventTypeFilter>,
+        callback: F,
+    ) -> AnyResult<ReplayStats>
+    where
+        F: Fn(Box<dyn UnifiedEvent>) + Send + Sync + 'static,
+    {
+        let (tx, rx) = self.event_channel(&protocols);
+        let event_handle = self.spawn_event_workers(rx, protocols, bot_wallet, event_type_filter, callback);
+
+        let replay = CaptureReplay::new(path, pacing).with_dedup(self.config.reconnect.dedup_capacity);
+        let result = replay.run(tx, self.config.backpressure.strategy).await;
+        let _ = event_handle.await;
+        result
+    }
+
     /// 高级订阅：批处理 + 背压（保持批次顺序，因此单消费者）
     pub async fn subscribe_events_advanced<F>(
         &self,
@@ -261,24 +1045,14 @@ impl YellowstoneGrpc {
             metrics_handle = self.metrics_manager.start_auto_monitoring().await;
         }
 
-        let transactions = self.subscription_manager.get_subscribe_request_filter(
-            transaction_filter.account_include,
-            transaction_filter.account_exclude,
-            transaction_filter.account_required,
+        let subscribe_request = self.build_event_request(
+            transaction_filter,
+            account_filter,
             event_type_filter.clone(),
+            commitment,
         );
-        let accounts = self.subscription_manager.subscribe_with_account_request(
-            account_filter.account,
-            account_filter.owner,
-            event_type_filter.clone(),
-        );
-
-        let (mut subscribe_tx, mut stream) = self
-            .subscription_manager
-            .subscribe_with_request(transactions, accounts, commitment, event_type_filter.clone())
-            .await?;
 
-        let (tx, mut rx) = mpsc::channel::<EventPretty>(self.config.backpressure.channel_size);
+        let (tx, mut rx) = self.event_channel(&protocols);
 
         // keep the concrete callback type F
         let outer_callback: Arc<F> = Arc::new(callback);
@@ -296,29 +1070,14 @@ impl YellowstoneGrpc {
             batch_callback,
             self.config.batch.batch_size,
             self.config.batch.batch_timeout_ms,
-        );
+        )
+        .with_metrics(self.metrics_manager.clone());
 
         // Yellowstone reader → channel
-        let bp = self.config.backpressure.strategy;
-        let stream_handle = tokio::spawn(async move {
-            while let Some(message) = stream.next().await {
-                match message {
-                    Ok(msg) => {
-                        if let Err(e) =
-                            StreamHandler::handle_stream_message(msg, &tx, &mut subscribe_tx, bp)
-                                .await
-                        {
-                            error!("Error handling message: {e:?}");
-                            break;
-                        }
-                    }
-                    Err(error) => {
-                        error!("Stream error: {error:?}");
-                        break;
-                    }
-                }
-            }
-        });
+        let controller = SubscriptionController::new(subscribe_request);
+        let stream_handle = self
+            .spawn_stream_reader(&controller, tx, self.config.backpressure.strategy)
+            .await?;
 
         // 单消费者批处理
         let event_processor = Arc::clone(&self.event_processor);
@@ -342,12 +1101,184 @@ impl YellowstoneGrpc {
         });
 
         let subscription_handle =
-            SubscriptionHandle::new(stream_handle, event_handle, metrics_handle);
+            SubscriptionHandle::new(stream_handle, event_handle, metrics_handle)
+                .with_controller(controller);
         let mut handle_guard = self.subscription_handle.lock().await;
         *handle_guard = Some(subscription_handle);
 
         Ok(())
     }
+
+    /// 流式订阅：返回逐条产出事件的 Stream。消费者跟不上时，输出通道按
+    /// config.backpressure.strategy 处理；丢弃返回的 EventStream 即停止读取与解析任务
+    pub async fn subscribe_events_stream(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: TransactionFilter,
+        account_filter: AccountFilter,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+    ) -> AnyResult<EventStream<Box<dyn UnifiedEvent>>> {
+        let mut metrics_handle = None;
+        if self.config.enable_metrics {
+            metrics_handle = self.metrics_manager.start_auto_monitoring().await;
+        }
+
+        let subscribe_request = self.build_event_request(
+            transaction_filter,
+            account_filter,
+            event_type_filter.clone(),
+            commitment,
+        );
+
+        let backpressure_strategy = self.config.backpressure.strategy;
+        let (tx, rx) = self.event_channel(&protocols);
+        let (out_tx, out_rx) =
+            mpsc::channel::<Box<dyn UnifiedEvent>>(self.config.backpressure.channel_size);
+
+        let controller = SubscriptionController::new(subscribe_request);
+        let stream_handle = self.spawn_stream_reader(&controller, tx, backpressure_strategy).await?;
+
+        let events = self.parsed_event_stream(rx, protocols, bot_wallet, event_type_filter);
+        let event_handle = tokio::spawn(async move {
+            futures::pin_mut!(events);
+            'forward: while let Some(parsed) = events.next().await {
+                for event in parsed {
+                    // 消费者已丢弃 Stream
+                    if StreamHandler::handle_backpressure(&out_tx, event, backpressure_strategy)
+                        .await
+                        .is_err()
+                    {
+                        break 'forward;
+                    }
+                }
+            }
+        });
+
+        let subscription_handle =
+            SubscriptionHandle::new(stream_handle, event_handle, metrics_handle)
+                .with_controller(controller);
+        Ok(EventStream::new(out_rx, subscription_handle))
+    }
+
+    /// 流式批量订阅：经 EventBatchProcessor 聚合后按批产出（单消费者，保持顺序）。
+    /// 批次在达到 batch_size 或空闲超过 batch_timeout_ms 时产出
+    pub async fn subscribe_events_stream_batched(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: TransactionFilter,
+        account_filter: AccountFilter,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+    ) -> AnyResult<EventStream<Vec<Box<dyn UnifiedEvent>>>> {
+        let mut metrics_handle = None;
+        if self.config.enable_metrics {
+            metrics_handle = self.metrics_manager.start_auto_monitoring().await;
+        }
+
+        let subscribe_request = self.build_event_request(
+            transaction_filter,
+            account_filter,
+            event_type_filter.clone(),
+            commitment,
+        );
+
+        let backpressure_strategy = self.config.backpressure.strategy;
+        let (tx, mut rx) = self.event_channel(&protocols);
+        let (out_tx, out_rx) =
+            mpsc::channel::<Vec<Box<dyn UnifiedEvent>>>(self.config.backpressure.channel_size);
+
+        // 批处理回调是同步的，先暂存已完成的批次，再在异步上下文中按背压策略发送
+        let ready: ReadyBatches = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let batch_callback = {
+            let ready = Arc::clone(&ready);
+            move |events: Vec<Box<dyn UnifiedEvent>>| {
+                if let Ok(mut ready) = ready.lock() {
+                    ready.push(events);
+                }
+            }
+        };
+        let batch_timeout_ms = self.config.batch.batch_timeout_ms;
+        let mut batch_processor =
+            EventBatchProcessor::new(batch_callback, self.config.batch.batch_size, batch_timeout_ms)
+                .with_metrics(self.metrics_manager.clone());
+
+        let controller = SubscriptionController::new(subscribe_request);
+        let stream_handle = self.spawn_stream_reader(&controller, tx, backpressure_strategy).await?;
+
+        let event_processor = Arc::clone(&self.event_processor);
+        let event_handle = tokio::spawn(async move {
+            // 没有新消息时也要按超时产出未满的批次
+            let mut ticker = tokio::time::interval(Duration::from_millis(batch_timeout_ms.max(1)));
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    event_pretty = rx.recv() => {
+                        let Some(event_pretty) = event_pretty else {
+                            break;
+                        };
+                        if let Err(e) = event_processor
+                            .process_event_transaction_with_batch(
+                                event_pretty,
+                                &mut batch_processor,
+                                bot_wallet,
+                                protocols.clone(),
+                                event_type_filter.clone(),
+                            )
+                            .await
+                        {
+                            error!("Error processing transaction: {e:?}");
+                        }
+                    }
+                    _ = ticker.tick() => {
+                        if batch_processor.should_flush() {
+                            batch_processor.flush();
+                        }
+                    }
+                }
+                if !forward_batches(&ready, &out_tx, backpressure_strategy).await {
+                    return;
+                }
+            }
+
+            // flush 剩余事件
+            batch_processor.flush();
+            forward_batches(&ready, &out_tx, backpressure_strategy).await;
+        });
+
+        let subscription_handle =
+            SubscriptionHandle::new(stream_handle, event_handle, metrics_handle)
+                .with_controller(controller);
+        Ok(EventStream::new(out_rx, subscription_handle))
+    }
+}
+
+/// 将已完成的批次按背压策略送入输出通道；消费者已丢弃 Stream 时返回 false
+async fn forward_batches(
+    ready: &std::sync::Mutex<Vec<Vec<Box<dyn UnifiedEvent>>>>,
+    tx: &mpsc::Sender<Vec<Box<dyn UnifiedEvent>>>,
+    backpressure_strategy: BackpressureStrategy,
+) -> bool {
+    let batches = match ready.lock() {
+        Ok(mut ready) => std::mem::take(&mut *ready),
+        Err(_) => return true,
+    };
+    for batch in batches {
+        if StreamHandler::handle_backpressure(tx, batch, backpressure_strategy).await.is_err() {
+            return false;
+        }
+    }
+    true
+}
+
+/// 键到分片序号的稳定映射
+fn shard_index(key: &str, shards: usize) -> usize {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    (hasher.finish() % shards.max(1) as u64) as usize
 }
 
 // 实现 Clone 以支持共享
@@ -362,6 +1293,10 @@ impl Clone for YellowstoneGrpc {
             metrics_manager: self.metrics_manager.clone(),
             event_processor: Arc::clone(&self.event_processor),
             subscription_handle: self.subscription_handle.clone(),
+            redundant_managers: self.redundant_managers.clone(),
+            fanin: self.fanin.clone(),
+            recorder: self.recorder.clone(),
+            subscriptions: Arc::clone(&self.subscriptions),
         }
     }
 }
\ No newline at end of file
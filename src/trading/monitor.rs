@@ -15,6 +15,7 @@ use crate::globals::constants::*;
 use crate::globals::pubkeys::*;
 use crate::globals::statics::*;
 use crate::schema::trade::TradeInfo;
+use crate::streaming::grpc::control::SubscriptionController;
 use crate::trading::jupiter::SwapClient;
 use crate::utils::loader::{ServerConfig, TradeConfig, WalletConfig};
 use crate::utils::storage::Storage;
@@ -155,6 +156,77 @@ impl TradeMonitor {
         });
     }
 
+    // ─── fn 'registercontrol' ───
+    /// fn description
+    pub fn registercontrol(controller: SubscriptionController) {
+
+        // ─── callback 'MONITORCONTROL.set()' ───
+        let _ = MONITORCONTROL.set(controller);
+    }
+
+    // ─── fn 'watchtrade' ───
+    /// fn description
+    fn watchtrade(tradeuuid: &str, mintaddr: &Pubkey, programaddr: &Pubkey, opentrades: u64) {
+
+        // ─── define 'keys' ───
+        let mut keys = vec![mintaddr.to_string()];
+
+        // ─── compare 'POSTGRESTOKENSCACHE.get()' ───
+        if let Some(row) = POSTGRESTOKENSCACHE.get(&(mintaddr.to_string(), programaddr.to_string())) {
+
+            // ─── compare 'row.pool' ───
+            if !row.pool.is_empty() {
+                keys.push(row.pool.clone());
+            }
+        }
+
+        // ─── callback 'MONITORWATCH.insert()' ───
+        MONITORWATCH.insert(tradeuuid.to_string(), keys);
+
+        // ─── callback 'Self::narrowwatch()' ───
+        Self::narrowwatch(opentrades);
+    }
+
+    // ─── fn 'unwatchtrade' ───
+    /// fn description
+    fn unwatchtrade(tradeuuid: &str, opentrades: u64) {
+
+        // ─── compare 'MONITORWATCH.remove()' ───
+        if MONITORWATCH.remove(tradeuuid).is_some() {
+
+            // ─── callback 'Self::narrowwatch()' ───
+            Self::narrowwatch(opentrades);
+        }
+    }
+
+    // ─── fn 'narrowwatch' ───
+    /// fn description
+    fn narrowwatch(opentrades: u64) {
+
+        // ─── compare 'MONITORCONTROL.get()' ───
+        let Some(controller) = MONITORCONTROL.get() else {
+            return;
+        };
+
+        // ─── compare 'opentrades' ───
+        // At capacity no new trade can open, so only the watched keys matter;
+        // below it the baseline must come back so new mints are discovered.
+        if opentrades > 0 && MONITORWATCH.len() as u64 >= opentrades {
+
+            // ─── define 'accounts' ───
+            let mut accounts: Vec<String> = MONITORWATCH.iter().flat_map(|e| e.value().clone()).collect();
+            accounts.sort();
+            accounts.dedup();
+
+            // ─── callback 'controller.narrow()' ───
+            controller.narrow(accounts);
+        } else {
+
+            // ─── callback 'controller.narrow()' ───
+            controller.narrow(Vec::new());
+        }
+    }
+
     // ─── fn 'endpointrpc' ───
     /// fn description
     fn endpointrpc(&self) -> &str {
@@ -301,7 +373,8 @@ impl TradeMonitor {
 
         // ─── callback 'Self::handlerclose()' ───
         Self::handlerclose(rpc, storage, mintaddr, tradeuuid, exitreason, totalunits, bot.main.debug, bot.orders.sellslippage,
-            bot.priority.outputfees, decimals, bot.orders.attempts, bot.main.sandbox, wallet).await;
+            bot.priority.outputfees, decimals, bot.orders.attempts, bot.main.sandbox,
+            bot.main.opentrades as u64, wallet).await;
     }
 
     // ─── fn 'exitbundler' ───
@@ -859,6 +932,9 @@ impl TradeMonitor {
             return None;
         }
 
+        // ─── callback 'Self::watchtrade()' ───
+        Self::watchtrade(&tradeuuid, &mintaddr, &programaddr, cfg.main.opentrades as u64);
+
         // ─── define 'tradeopen' ───
         let tradeopen = tradecost / tradeunits;
 
@@ -1506,7 +1582,7 @@ impl TradeMonitor {
     /// fn description
     pub async fn handlerclose(rpc: &str, storage: &Storage, mintaddr: Pubkey, tradeuuid: &str, exitreason: &str,
         tradeunits: f64, debug: bool, sellslippage: f64, outputfees: f64, decimals: u8, maxretries: u64,
-        sandbox: bool, wallet: Arc<WalletConfig>) {
+        sandbox: bool, opentrades: u64, wallet: Arc<WalletConfig>) {
 
         // ─── define 'tokenclose' ───
         let tokenclose = Self::fetchprice(storage, &mintaddr).await;
@@ -1541,6 +1617,9 @@ impl TradeMonitor {
                         // ─── callback 'storage.tradeclose()' ───
                         let _ = storage.tradeclose(tradeuuid, cumulative, exitreason).await;
 
+                        // ─── callback 'Self::unwatchtrade()' ───
+                        Self::unwatchtrade(tradeuuid, opentrades);
+
                         // ─── callback 'storage.signaturelog()' ───
                         let _ = storage.signaturelog(tradeuuid, &mintaddr, &tradehash).await;
 
@@ -1593,6 +1672,9 @@ impl TradeMonitor {
             // ─── callback 'storage.tradeclose()' ───
             let _ = storage.tradeclose(tradeuuid, 0.00, exitreason).await;
 
+            // ─── callback 'Self::unwatchtrade()' ───
+            Self::unwatchtrade(tradeuuid, opentrades);
+
             // ─── compare 'tokenclose' ───
             if let Some(tp) = tokenclose {
 
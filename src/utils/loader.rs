@@ -2,6 +2,17 @@
 use std::{fs, error::Error};
 use serde::Deserialize;
 
+// ─── import crates ───
+use crate::streaming::common::{ConnectionConfig, GrpcCompression, ProxyConfig};
+
+// ─── struct 'GeyserConfig' ───
+/// struct description
+#[derive(Deserialize, Debug, Clone)]
+pub struct GeyserConfig {
+    pub geyser: String,
+    pub xtoken: Option<String>,
+}
+
 // ─── struct 'EndpointConfig' ───
 /// struct description
 #[derive(Deserialize, Debug)]
@@ -9,6 +20,112 @@ pub struct EndpointConfig {
     pub geyser: String,
     pub rpc: String,
     pub xtoken: String,
+    #[serde(default)]
+    pub redundant: Vec<GeyserConfig>,
+    #[serde(default)]
+    pub websocket: Option<String>,
+    #[serde(default)]
+    pub transport: TransportConfig,
+}
+
+// ─── struct 'TransportConfig' ───
+/// struct description
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct TransportConfig {
+    pub nativeroots: Option<bool>,
+    pub cacert: Option<String>,
+    pub clientcert: Option<String>,
+    pub clientkey: Option<String>,
+    pub domain: Option<String>,
+    pub proxy: Option<String>,
+    pub proxyuser: Option<String>,
+    pub proxypass: Option<String>,
+    pub compression: Option<String>,
+}
+
+// ─── impl 'TransportConfig' ───
+/// impl description
+impl TransportConfig {
+
+    // ─── fn 'applyconnection' ───
+    /// fn description
+    pub fn applyconnection(&self, connection: &mut ConnectionConfig) -> Result<(), Box<dyn Error>> {
+
+        // ─── compare 'self.nativeroots' ───
+        if let Some(nativeroots) = self.nativeroots {
+            connection.tls.native_roots = nativeroots;
+        }
+
+        // ─── define 'connection.tls' ───
+        connection.tls.ca_cert_path = self.cacert.clone().or(connection.tls.ca_cert_path.take());
+        connection.tls.client_cert_path = self.clientcert.clone().or(connection.tls.client_cert_path.take());
+        connection.tls.client_key_path = self.clientkey.clone().or(connection.tls.client_key_path.take());
+        connection.tls.domain_name = self.domain.clone().or(connection.tls.domain_name.take());
+
+        // ─── compare 'self.proxy' ───
+        if let Some(url) = self.proxy.as_ref().filter(|url| !url.is_empty()) {
+
+            // ─── define 'proxy' ───
+            let mut proxy = ProxyConfig::new(url.clone());
+
+            // ─── compare 'self.proxyuser' ───
+            if let (Some(user), Some(pass)) = (&self.proxyuser, &self.proxypass) {
+                proxy = proxy.with_auth(user.clone(), pass.clone());
+            }
+            connection.proxy = Some(proxy);
+        }
+
+        // ─── compare 'self.compression' ───
+        if let Some(compression) = &self.compression {
+            connection.compression = compression.parse::<GrpcCompression>()?;
+        }
+
+        // ─── return 'Result' ───
+        Ok(())
+    }
+}
+
+// ─── impl 'EndpointConfig' ───
+/// impl description
+impl EndpointConfig {
+
+    // ─── fn 'geyserendpoints' ───
+    /// fn description
+    pub fn geyserendpoints(&self) -> Vec<(String, Option<String>)> {
+
+        // ─── define 'endpoints' ───
+        let mut endpoints = vec![(self.geyser.clone(), Some(self.xtoken.clone()))];
+        endpoints.extend(self.redundant.iter().map(|g| (g.geyser.clone(), g.xtoken.clone())));
+
+        // ─── return 'endpoints' ───
+        endpoints
+    }
+
+    // ─── fn 'websocketurl' ───
+    /// fn description
+    pub fn websocketurl(&self) -> String {
+
+        // ─── compare 'self.websocket' ───
+        if let Some(websocket) = &self.websocket {
+            return websocket.clone();
+        }
+
+        // ─── return 'String' ───
+        if let Some(rest) = self.rpc.strip_prefix("https://") {
+            format!("wss://{rest}")
+        } else if let Some(rest) = self.rpc.strip_prefix("http://") {
+            // Local validators serve pubsub on the RPC port + 1
+            match rest.rsplit_once(':') {
+                Some((host, port)) => match port.trim_end_matches('/').parse::<u16>() {
+                    Ok(port) => format!("ws://{host}:{}", port.saturating_add(1)),
+                    Err(_) => format!("ws://{rest}"),
+                },
+                None => format!("ws://{rest}"),
+            }
+        } else {
+            self.rpc.clone()
+        }
+    }
 }
 
 // ─── struct 'ServerConfig' ───
@@ -88,7 +205,11 @@ pub struct MainConfig {
 #[derive(Deserialize, Debug)]
 pub struct MonitoringConfig {
     pub programs: String,
-    pub retries: u32
+    pub retries: u32,
+    #[serde(default)]
+    pub backfill: u64,
+    #[serde(default)]
+    pub prometheus: Option<String>
 }
 
 // ─── struct 'OrdersConfig' ───
@@ -1926,6 +1926,22 @@ impl Storage {
         Ok(())
     }
 
+    // ─── fn 'tickqueue' ───
+    /// fn description
+    pub fn tickqueue(&self) -> (usize, usize) {
+
+        // ─── return 'Tuple' ───
+        (self.ticktx.max_capacity() - self.ticktx.capacity(), self.ticktx.max_capacity())
+    }
+
+    // ─── fn 'tokenqueue' ───
+    /// fn description
+    pub fn tokenqueue(&self) -> (usize, usize) {
+
+        // ─── return 'Tuple' ───
+        (self.tokentx.max_capacity() - self.tokentx.capacity(), self.tokentx.max_capacity())
+    }
+
     // ─── fn 'tradescount' ───
     /// fn description
     pub async fn tradescount(&self) -> sqlx::Result<i64> {
@@ -2068,6 +2084,19 @@ impl Storage {
         Ok(cnt)
     }
 
+    // ─── fn 'tradespnl' ───
+    /// fn description
+    pub async fn tradespnl(&self) -> sqlx::Result<f64> {
+
+        // ─── define 'pnl' ───
+        let (pnl,): (f64,) = sqlx::query_as("SELECT COALESCE(SUM(total - amount), 0)::DOUBLE PRECISION FROM trades WHERE total IS NOT NULL")
+            .fetch_one(&self.readpool)
+            .await?;
+
+        // ─── return 'Result' ───
+        Ok(pnl)
+    }
+
     // ─── fn 'tradeupdate' ───
     /// fn description
     pub async fn tradeupdate(&self, uuid: &str, poolsize: f64, decimals: u8, txs: i64, spread: f64, supply: f64, latency: i64) -> sqlx::Result<()> {